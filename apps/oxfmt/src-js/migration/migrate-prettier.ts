@@ -74,17 +74,6 @@ export async function runMigratePrettier() {
       console.error(`  - "plugins" is not supported yet, skipping...`);
       continue;
     }
-    // Oxfmt does not support this, fallback to default
-    if (key === "endOfLine" && value === "auto") {
-      console.error(`  - "endOfLine: auto" is not supported, skipping...`);
-      continue;
-    }
-    // Oxfmt does not support these experimental options yet
-    if (key === "experimentalTernaries" || key === "experimentalOperatorPosition") {
-      console.error(`  - "${key}" is not supported in JS/TS files yet`);
-      continue;
-    }
-
     // Otherwise, copy the value.
     // This may include options that do not affect Oxfmt, like `vueIndentScriptAndStyle`.
     oxfmtrc[key] = value;
@@ -4,7 +4,7 @@ use std::path::Path;
 
 use oxc_allocator::AllocatorPool;
 use oxc_diagnostics::OxcDiagnostic;
-use oxc_formatter::{FormatOptions, Formatter, enable_jsx_source_type, get_parse_options};
+use oxc_formatter::{FormatOptions, Formatter, enable_jsx_source_type, get_parse_options_with};
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 
@@ -86,7 +86,7 @@ impl SourceFormatter {
         let allocator = self.allocator_pool.get();
 
         let ret = Parser::new(&allocator, source_text, source_type)
-            .with_options(get_parse_options())
+            .with_options(get_parse_options_with(&self.format_options))
             .parse();
         if !ret.errors.is_empty() {
             // Return the first error for simplicity
@@ -9,13 +9,14 @@
 //! ```bash
 //! cargo run -p oxc_formatter --example formatter [filename]
 //! cargo run -p oxc_formatter --example formatter -- --no-semi [filename]
+//! cargo run -p oxc_formatter --example formatter -- --preserve-parens [filename]
 //! ```
 
 use std::{fs, path::Path};
 
 use oxc_allocator::Allocator;
 use oxc_formatter::{
-    BracketSameLine, FormatOptions, Formatter, LineWidth, Semicolons, get_parse_options,
+    BracketSameLine, FormatOptions, Formatter, LineWidth, Semicolons, get_parse_options_with,
 };
 use oxc_parser::Parser;
 use oxc_span::SourceType;
@@ -26,6 +27,7 @@ fn main() -> Result<(), String> {
     let mut args = Arguments::from_env();
     let no_semi = args.contains("--no-semi");
     let show_ir = args.contains("--ir");
+    let preserve_parens = args.contains("--preserve-parens");
     let print_width = args.opt_value_from_str::<&'static str, u16>("--print-width").unwrap_or(None);
     let name = args.free_from_str().unwrap_or_else(|_| "test.js".to_string());
 
@@ -35,19 +37,6 @@ fn main() -> Result<(), String> {
     let source_type = SourceType::from_path(path).unwrap();
     let allocator = Allocator::new();
 
-    // Parse the source code
-    let ret = Parser::new(&allocator, &source_text, source_type)
-        .with_options(get_parse_options())
-        .parse();
-
-    // Report any parsing errors
-    for error in ret.errors {
-        let error = error.with_source_code(source_text.clone());
-        println!("{error:?}");
-        println!("Parsed with Errors.");
-    }
-
-    // Format the parsed code
     let semicolons = if no_semi { Semicolons::AsNeeded } else { Semicolons::Always };
     let line_width = match print_width {
         Some(width) => LineWidth::try_from(width).unwrap(),
@@ -57,9 +46,23 @@ fn main() -> Result<(), String> {
         bracket_same_line: BracketSameLine::from(true),
         semicolons,
         line_width,
+        preserve_parens,
         ..Default::default()
     };
 
+    // Parse the source code
+    let ret = Parser::new(&allocator, &source_text, source_type)
+        .with_options(get_parse_options_with(&options))
+        .parse();
+
+    // Report any parsing errors
+    for error in ret.errors {
+        let error = error.with_source_code(source_text.clone());
+        println!("{error:?}");
+        println!("Parsed with Errors.");
+    }
+
+    // Format the parsed code
     let formatter = Formatter::new(&allocator, options);
     let formatted = formatter.format(&ret.program);
     if show_ir {
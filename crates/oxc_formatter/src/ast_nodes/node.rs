@@ -54,6 +54,22 @@ impl<'a, T> AstNode<'a, Option<T>> {
     }
 }
 
+impl<'a, T> AstNode<'a, T> {
+    /// Returns this node with `following_span` as the span of the node following it.
+    ///
+    /// This is needed for the last node of a list that is followed by a node of another field,
+    /// e.g. the last property of `{ a, /* comment */ ...rest }`, so that comments between them
+    /// become leading comments of the following node instead of trailing comments of this one.
+    pub fn with_following_span(&self, following_span: Span) -> &'a Self {
+        self.allocator.alloc(AstNode {
+            inner: self.inner,
+            parent: self.parent,
+            allocator: self.allocator,
+            following_span: Some(following_span),
+        })
+    }
+}
+
 impl<T: GetSpan> GetSpan for AstNode<'_, T> {
     fn span(&self) -> Span {
         self.inner.span()
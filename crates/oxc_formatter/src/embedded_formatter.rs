@@ -1,5 +1,28 @@
 use std::sync::Arc;
 
+/// Formats the code of a language embedded in a template literal, e.g. CSS or GraphQL.
+///
+/// Embedded code is only formatted if [`crate::FormatOptions::embedded_language_formatting`] is `Auto`.
+/// Without an embedded formatter, the formatter then only fixes the indentation of CSS and GraphQL
+/// templates, see [`crate::Formatter::with_embedded_language_formatter`].
+///
+/// - The CSS of styled-components, e.g. `styled.div\`...\``, is passed with the tag name `css`
+///   and its `${}` expressions replaced with `@prettier-placeholder-N` placeholders, `N` being the
+///   index of the expression. The formatted code must keep every placeholder once and in order,
+///   otherwise the template is only re-indented.
+/// - GraphQL in `gql\`...\``, `graphql\`...\`` or `/* GraphQL */ \`...\`` is passed with the tag name
+///   `graphql`, unless it contains `${}` expressions. These are printed verbatim.
+/// - Other supported tags, see [`EmbeddedFormatter::is_supported_tag`], are passed as they are
+///   if the template has no `${}` expressions.
+pub trait EmbeddedLanguageFormatter: Send + Sync {
+    /// Formats the `code` of the template tagged `tag_name`.
+    ///
+    /// # Errors
+    /// Returns an error message if `code` can't be formatted, the template is then left as it is
+    /// or only re-indented.
+    fn format(&self, tag_name: &str, code: &str) -> Result<String, String>;
+}
+
 /// Callback function type for formatting embedded code.
 /// Takes (tag_name, code) and returns formatted code or an error.
 pub type EmbeddedFormatterCallback =
@@ -11,6 +34,8 @@ pub type EmbeddedFormatterCallback =
 /// - CSS in `css\`...\``
 /// - GraphQL in `gql\`...\``
 /// - HTML in `html\`...\``
+///
+/// See [`EmbeddedLanguageFormatter`] for the code passed to the callback.
 #[derive(Clone)]
 pub struct EmbeddedFormatter {
     callback: EmbeddedFormatterCallback,
@@ -46,3 +71,9 @@ impl EmbeddedFormatter {
         (self.callback)(tag_name, code)
     }
 }
+
+impl EmbeddedLanguageFormatter for EmbeddedFormatter {
+    fn format(&self, tag_name: &str, code: &str) -> Result<String, String> {
+        (self.callback)(tag_name, code)
+    }
+}
@@ -0,0 +1,231 @@
+//! Formatting of a part of a program, see [`crate::Formatter::format_range`].
+//!
+//! The whole program is formatted as usual, but the innermost statement (or class member) list
+//! that covers the requested range labels the entries overlapping it with
+//! [`JsLabels::FormatRange`]. Printing then only returns the code of that labelled content,
+//! and [`FormatRange::formatted_span`] tells which part of the source text it replaces.
+
+use oxc_span::Span;
+
+use crate::{
+    IndentWidth, JsLabels,
+    formatter::{
+        Buffer, Format, FormatElement, Formatter,
+        prelude::tag::{LabelId, Tag},
+    },
+};
+
+/// Tracks the range requested by [`crate::Formatter::format_range`] while formatting.
+#[derive(Debug, Clone)]
+pub struct FormatRange {
+    range: Span,
+    /// The source start of every labelled entry run that hasn't been finished yet.
+    starts: Vec<u32>,
+    /// The source span of the innermost labelled entries.
+    formatted_span: Option<Span>,
+    /// Whether the range starts or ends in the text of a template literal,
+    /// which can't be formatted without changing the program.
+    in_template_element: bool,
+}
+
+impl FormatRange {
+    pub fn new(range: Span) -> Self {
+        Self { range, starts: Vec::new(), formatted_span: None, in_template_element: false }
+    }
+
+    /// Returns the span of the source text replaced by the labelled content,
+    /// or `None` if the range must be left untouched.
+    pub fn formatted_span(&self) -> Option<Span> {
+        if self.in_template_element { None } else { self.formatted_span }
+    }
+
+    /// Marks the range as untouchable if it starts or ends in the given template element.
+    pub fn check_template_element(&mut self, element: Span) {
+        let contains = |position| element.start <= position && position <= element.end;
+        if contains(self.range.start) || contains(self.range.end) {
+            self.in_template_element = true;
+        }
+    }
+
+    fn start(&mut self, start: u32) {
+        self.starts.push(start);
+    }
+
+    fn end(&mut self, end: u32) {
+        let Some(start) = self.starts.pop() else {
+            return;
+        };
+
+        // Nested entries start after the entries containing them, but end before them.
+        if self.formatted_span.is_none_or(|span| start > span.start) {
+            self.formatted_span = Some(Span::new(start, end));
+        }
+    }
+}
+
+/// The entries of a statement or class member list that overlap the requested range.
+///
+/// Use [`Self::entry`] to wrap every entry of the list, it labels the overlapping entries.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FormatRangeEntries {
+    /// The indices of the first and last overlapping entries.
+    entries: Option<(usize, usize)>,
+}
+
+impl FormatRangeEntries {
+    /// Finds the entries overlapping the requested range, if there is one and these `entries` cover it.
+    ///
+    /// `bounds` is the span that must contain the range for this list to claim it,
+    /// it defaults to the span from the first to the last entry.
+    pub fn new(
+        entries: impl Iterator<Item = Span>,
+        bounds: Option<Span>,
+        f: &Formatter<'_, '_>,
+    ) -> Self {
+        let Some(format_range) = f.context().format_range() else {
+            return Self::default();
+        };
+        let range = format_range.range;
+
+        let spans = entries.collect::<Vec<_>>();
+        let (Some(first), Some(last)) = (spans.first(), spans.last()) else {
+            return Self::default();
+        };
+        let bounds = bounds.unwrap_or_else(|| Span::new(first.start, last.end));
+        if range.start < bounds.start || range.end > bounds.end {
+            return Self::default();
+        }
+
+        let overlaps = |span: &Span| {
+            if range.is_empty() {
+                span.start <= range.start && range.end <= span.end
+            } else {
+                span.start < range.end && range.start < span.end
+            }
+        };
+        let Some(mut first) = spans.iter().position(overlaps) else {
+            return Self::default();
+        };
+        let mut last = if range.is_empty() {
+            first
+        } else {
+            spans.iter().rposition(overlaps).unwrap_or(first)
+        };
+
+        // Entries sharing a line with the overlapping entries are formatted as well, so that
+        // the formatted code always starts and ends at the same lines as the source text.
+        let source_text = f.source_text();
+        while first > 0
+            && !source_text.contains_newline_between(spans[first - 1].end, spans[first].start)
+        {
+            first -= 1;
+        }
+        while last + 1 < spans.len()
+            && !source_text.contains_newline_between(spans[last].end, spans[last + 1].start)
+        {
+            last += 1;
+        }
+
+        Self { entries: Some((first, last)) }
+    }
+
+    /// Wraps the entry at `index` of the list, which covers `span` in the source text.
+    pub fn entry<'a, 'ast>(
+        self,
+        index: usize,
+        span: Span,
+        content: &'a dyn Format<'ast>,
+    ) -> FormatRangeEntry<'a, 'ast> {
+        self.entries(index, index, span, content)
+    }
+
+    /// Wraps `content` formatting the entries from `first` to `last` of the list at once,
+    /// which cover `span` in the source text.
+    pub fn entries<'a, 'ast>(
+        self,
+        first: usize,
+        last: usize,
+        span: Span,
+        content: &'a dyn Format<'ast>,
+    ) -> FormatRangeEntry<'a, 'ast> {
+        let (is_first, is_last) = match self.entries {
+            Some((first_entry, last_entry)) => {
+                ((first..=last).contains(&first_entry), (first..=last).contains(&last_entry))
+            }
+            None => (false, false),
+        };
+        FormatRangeEntry { span, content, is_first, is_last }
+    }
+}
+
+pub struct FormatRangeEntry<'a, 'ast> {
+    span: Span,
+    content: &'a dyn Format<'ast>,
+    is_first: bool,
+    is_last: bool,
+}
+
+impl<'ast> Format<'ast> for FormatRangeEntry<'_, 'ast> {
+    fn fmt(&self, f: &mut Formatter<'_, 'ast>) {
+        if !self.is_first && !self.is_last {
+            self.content.fmt(f);
+            return;
+        }
+
+        if self.is_first {
+            f.write_element(FormatElement::Tag(Tag::StartLabelled(LabelId::of(
+                JsLabels::FormatRange,
+            ))));
+        }
+
+        let printed_before = f.comments().printed_comments().len();
+        self.content.fmt(f);
+        // The comments printed by the entry are part of the labelled content,
+        // so the source text they're in is replaced as well.
+        let printed = f.comments().printed_comments().get(printed_before..).unwrap_or_default();
+        let start = printed
+            .first()
+            .filter(|comment| comment.span.end <= self.span.start)
+            .map_or(self.span.start, |comment| comment.span.start);
+        let end = printed
+            .last()
+            .filter(|comment| comment.span.start >= self.span.end)
+            .map_or(self.span.end, |comment| comment.span.end);
+
+        if let Some(format_range) = f.context_mut().format_range_mut() {
+            if self.is_first {
+                format_range.start(start);
+            }
+            if self.is_last {
+                format_range.end(end);
+            }
+        }
+
+        if self.is_last {
+            f.write_element(FormatElement::Tag(Tag::EndLabelled));
+        }
+    }
+}
+
+/// Returns the indentation level of the line `start` is on,
+/// or `None` if `start` isn't preceded by indentation only.
+pub fn source_indent_level(
+    source_text: &str,
+    start: u32,
+    indent_width: IndentWidth,
+) -> Option<u16> {
+    let before = &source_text[..start as usize];
+    let indentation = &before[before.rfind('\n').map_or(0, |index| index + 1)..];
+
+    let mut tabs = 0;
+    let mut spaces = 0;
+    for byte in indentation.bytes() {
+        match byte {
+            b'\t' => tabs += 1,
+            b' ' => spaces += 1,
+            _ => return None,
+        }
+    }
+
+    u16::try_from(tabs + spaces / usize::from(indent_width.value())).ok()
+}
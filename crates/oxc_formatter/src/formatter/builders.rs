@@ -146,6 +146,19 @@ pub const fn empty_line() -> Line {
     Line::new(LineMode::Empty)
 }
 
+/// Inserts enough line breaks in the output for the previous and next element to be separated
+/// by `count` empty lines. Zero empty lines is a [hard_line_break], one an [empty_line].
+///
+/// Used to preserve consecutive empty lines of the source, see [`Formatter::source_empty_lines`].
+#[inline]
+pub const fn empty_lines(count: u8) -> Line {
+    match count {
+        0 => hard_line_break(),
+        1 => empty_line(),
+        _ => Line::new(LineMode::EmptyLines(count)),
+    }
+}
+
 /// A line break if the enclosing `Group` doesn't fit on a single line, a space otherwise.
 ///
 /// # Examples
@@ -310,6 +323,37 @@ pub fn text_without_whitespace(text: &str) -> Text<'_> {
     Text { text, width: Some(TextWidth::from_non_whitespace_str(text)) }
 }
 
+/// Creates a text from a source slice whose line breaks are printed as they are, e.g. the raw
+/// content of a template literal. Unlike [text], the content may contain `\r\n` and `\r` line
+/// breaks and `\n` isn't replaced with the configured line ending.
+pub fn verbatim_text(text: &str) -> VerbatimText<'_> {
+    VerbatimText { text }
+}
+
+#[derive(Eq, PartialEq)]
+pub struct VerbatimText<'a> {
+    text: &'a str,
+}
+
+impl<'a> Format<'a> for VerbatimText<'a> {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) {
+        let indent_width = f.options().indent_width;
+        let width = match self.text.find(['\n', '\r']) {
+            Some(line_break) => TextWidth::multiline(
+                TextWidth::from_text(&self.text[..line_break], indent_width).value(),
+            ),
+            None => TextWidth::from_text(self.text, indent_width),
+        };
+        f.write_element(FormatElement::VerbatimText { text: self.text, width });
+    }
+}
+
+impl std::fmt::Debug for VerbatimText<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "VerbatimText({})", self.text)
+    }
+}
+
 #[derive(Eq, PartialEq)]
 pub struct Text<'a> {
     text: &'a str,
@@ -434,6 +478,25 @@ impl Format<'_> for LineSuffixBoundary {
     }
 }
 
+/// Marks the start of the text printed for the source text at `position`.
+///
+/// The printer records the position of the next printed text together with the source `position`,
+/// see [`crate::Formatter::with_source_map`]. Nothing is written if the source map isn't enabled.
+pub const fn source_position(position: u32) -> SourcePosition {
+    SourcePosition(position)
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct SourcePosition(u32);
+
+impl Format<'_> for SourcePosition {
+    fn fmt(&self, f: &mut Formatter) {
+        if f.context().source_map() {
+            f.write_element(FormatElement::SourcePosition(self.0));
+        }
+    }
+}
+
 /// Marks some content with a label.
 ///
 /// This does not directly influence how this content will be printed, but some
@@ -1135,6 +1198,7 @@ pub fn block_indent<'ast>(content: &impl Format<'ast>) -> BlockIndent<'_, 'ast>
 
 /// Indents the content by inserting a line break before and after the content and increasing
 /// the indention level for the content by one if the enclosing group doesn't fit on a single line.
+///
 /// Doesn't change the formatting if the enclosing group fits on a single line.
 ///
 /// # Examples
@@ -1203,6 +1267,7 @@ pub fn soft_block_indent<'ast>(content: &impl Format<'ast>) -> BlockIndent<'_, '
 
 /// Conditionally adds spaces around the content if its enclosing group fits
 /// within a single line and the caller suggests that the space should be added.
+///
 /// Otherwise indents the content and separates it by line breaks.
 ///
 /// # Examples
@@ -1686,6 +1751,7 @@ pub struct Group<'fmt, 'ast> {
 }
 
 impl Group<'_, '_> {
+    #[must_use]
     pub fn with_group_id(mut self, group_id: Option<GroupId>) -> Self {
         self.group_id = group_id;
         self
@@ -1697,6 +1763,7 @@ impl Group<'_, '_> {
     /// This is useful for content rendered inside of a [FormatElement::BestFitting] that prints each variant
     /// in [PrintMode::Flat] to change some content to be printed in [`Expanded`](PrintMode::Expanded) regardless.
     /// See the documentation of the [`best_fitting`] macro for an example.
+    #[must_use]
     pub fn should_expand(mut self, should_expand: bool) -> Self {
         self.should_expand = should_expand;
         self
@@ -1777,7 +1844,9 @@ impl Format<'_> for ExpandParent {
 }
 
 /// Adds a conditional content that is emitted only if it isn't inside an enclosing `Group` that
-/// is printed on a single line. The element allows, for example, to insert a trailing comma after the last
+/// is printed on a single line.
+///
+/// The element allows, for example, to insert a trailing comma after the last
 /// array element only if the array doesn't fit on a single line.
 ///
 /// The element has no special meaning if used outside of a `Group`. In that case, the content is always emitted.
@@ -1995,6 +2064,7 @@ impl IfGroupBreaks<'_, '_> {
     /// # Ok(())
     /// # }
     /// ```
+    #[must_use]
     pub fn with_group_id(mut self, group_id: Option<GroupId>) -> Self {
         self.group_id = group_id;
         self
@@ -2395,10 +2465,10 @@ where
     /// that appear before the node in the input source.
     pub fn entry(&mut self, span: Span, content: &dyn Format<'ast>) {
         if self.has_elements {
-            if self.has_lines_before(span) {
-                write!(self.fmt, empty_line());
-            } else {
-                self.separator.fmt(self.fmt);
+            let line_breaks = self.fmt.source_text().get_lines_before(span, self.fmt.comments());
+            match self.fmt.source_empty_lines(line_breaks) {
+                0 => self.separator.fmt(self.fmt),
+                count => write!(self.fmt, empty_lines(count)),
             }
         }
         self.has_elements = true;
@@ -2441,11 +2511,6 @@ where
         }
         self
     }
-
-    /// Get the number of line breaks between two consecutive SyntaxNodes in the tree
-    pub fn has_lines_before(&self, span: Span) -> bool {
-        self.fmt.source_text().get_lines_before(span, self.fmt.comments()) > 1
-    }
 }
 
 /// Builder to fill as many elements as possible on a single line.
@@ -97,6 +97,7 @@
 //! ## References
 //! - [Prettier handles special comments](https://github.com/prettier/prettier/blob/7584432401a47a26943dd7a9ca9a8e032ead7285/src/language-js/comments/handle-comments.js)
 //! - [Prettier pre-processes comments](https://github.com/prettier/prettier/blob/7584432401a47a26943dd7a9ca9a8e032ead7285/src/main/comments/attach.js)
+use oxc_allocator::{Allocator, Vec as ArenaVec};
 use oxc_ast::{Comment, CommentContent};
 use oxc_span::{GetSpan, Span};
 
@@ -118,7 +119,8 @@ pub struct Comments<'a> {
     /// The index of the type cast comment that has been printed already.
     /// Used to prevent duplicate processing of special TypeScript type cast comments.
     last_handled_type_cast_comment: usize,
-    type_cast_node_span: Span,
+    /// The span of the node wrapped in the parentheses of the last handled type cast comment.
+    type_cast_node_span: Option<Span>,
     /// Optional limit for the unprinted_comments view.
     ///
     /// When set, [`Self::unprinted_comments()`] will only return comments up to this index,
@@ -133,7 +135,7 @@ impl<'a> Comments<'a> {
             inner: comments,
             printed_count: 0,
             last_handled_type_cast_comment: 0,
-            type_cast_node_span: Span::default(),
+            type_cast_node_span: None,
             view_limit: None,
         }
     }
@@ -153,13 +155,17 @@ impl<'a> Comments<'a> {
 
     /// Returns an iterator over comments that end before or at the given position.
     pub fn comments_before_iter(&self, pos: u32) -> impl Iterator<Item = &Comment> {
-        self.unprinted_comments().iter().take_while(move |c| c.span.end <= pos)
+        self.comments_before(pos).iter()
     }
 
     /// Returns all comments that end before or at the given position.
+    ///
+    /// Comments are sorted and don't overlap, so their ends are sorted as well,
+    /// which allows a binary search instead of scanning all comments before `pos`.
     pub fn comments_before(&self, pos: u32) -> &'a [Comment] {
-        let index = self.comments_before_iter(pos).count();
-        &self.unprinted_comments()[..index]
+        let comments = self.unprinted_comments();
+        let index = comments.partition_point(|c| c.span.end <= pos);
+        &comments[..index]
     }
 
     /// Returns all block comments that end before or at the given position.
@@ -201,14 +207,14 @@ impl<'a> Comments<'a> {
     /// Returns comments that start after the given position (excluding printed ones).
     pub fn comments_after(&self, pos: u32) -> &'a [Comment] {
         let comments = self.unprinted_comments();
-        let start_index = comments.iter().take_while(|c| c.span.end < pos).count();
+        let start_index = comments.partition_point(|c| c.span.end < pos);
         &comments[start_index..]
     }
 
     /// Returns comments that fall between the given start and end positions.
     pub fn comments_in_range(&self, start: u32, end: u32) -> &'a [Comment] {
         let comments = self.comments_after(start);
-        let end_index = comments.iter().take_while(|c| c.span.end <= end).count();
+        let end_index = comments.partition_point(|c| c.span.end <= end);
         &comments[..end_index]
     }
 
@@ -228,7 +234,8 @@ impl<'a> Comments<'a> {
 
     /// Checks if there are any comments between the given positions.
     pub fn has_comment_in_range(&self, start: u32, end: u32) -> bool {
-        self.comments_before_iter(end).any(|comment| comment.span.end > start)
+        // The last comment ending before `end` ends the latest
+        self.comments_before(end).last().is_some_and(|comment| comment.span.end > start)
     }
 
     /// Checks if there are any comments within the given span.
@@ -240,7 +247,7 @@ impl<'a> Comments<'a> {
     /// Checks if there are any comments before the given position.
     #[inline]
     pub fn has_comment_before(&self, start: u32) -> bool {
-        self.comments_before_iter(start).next().is_some()
+        !self.comments_before(start).is_empty()
     }
 
     /// Checks if there are any leading own-line comments before the given position.
@@ -365,14 +372,51 @@ impl<'a> Comments<'a> {
         &[]
     }
 
-    /// Checks if the node has a suppression comment (prettier-ignore).
+    /// Checks if the node has a suppression comment (`prettier-ignore` or `oxc-format-ignore`).
     pub fn is_suppressed(&self, start: u32) -> bool {
         self.comments_before(start).iter().any(|comment| self.is_suppression_comment(comment))
     }
 
     pub fn is_suppression_comment(&self, comment: &Comment) -> bool {
-        // TODO: Consider using `oxfmt-ignore` instead of `prettier-ignore`
-        self.source_text.text_for(&comment.content_span()).trim() == "prettier-ignore"
+        matches!(
+            self.source_text.text_for(&comment.content_span()).trim(),
+            "prettier-ignore" | "oxc-format-ignore"
+        )
+    }
+
+    /// Returns the spans between `prettier-ignore-start` and `prettier-ignore-end` comments
+    /// (or `oxc-format-ignore-start` and `oxc-format-ignore-end`).
+    ///
+    /// Nested regions are part of the outermost region, and a region without an end comment
+    /// extends to the end of the file.
+    pub fn ignored_regions(&self, allocator: &'a Allocator) -> &'a [Span] {
+        let mut regions = ArenaVec::new_in(allocator);
+        let mut depth = 0;
+        let mut start = 0;
+
+        for comment in self.inner {
+            match self.source_text.text_for(&comment.content_span()).trim() {
+                "prettier-ignore-start" | "oxc-format-ignore-start" => {
+                    if depth == 0 {
+                        start = comment.span.end;
+                    }
+                    depth += 1;
+                }
+                "prettier-ignore-end" | "oxc-format-ignore-end" if depth > 0 => {
+                    depth -= 1;
+                    if depth == 0 {
+                        regions.push(Span::new(start, comment.span.start));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if depth > 0 {
+            regions.push(Span::new(start, u32::MAX));
+        }
+
+        regions.into_bump_slice()
     }
 
     /// Checks if a comment is a type cast comment containing `@type` or `@satisfies`.
@@ -409,17 +453,15 @@ impl<'a> Comments<'a> {
     /// Searches for a JSDoc comment containing @type or @satisfies that is followed
     /// by an opening parenthesis, which indicates a type cast pattern.
     pub fn get_type_cast_comment_index(&self, span: Span) -> Option<usize> {
-        self.unprinted_comments().iter().take_while(|c| c.span.end <= span.start).position(
-            |comment| {
-                self.source_text.next_non_whitespace_byte_is(comment.span.end, b'(')
-                    && self.is_type_cast_comment(comment)
-            },
-        )
+        self.comments_before(span.start).iter().position(|comment| {
+            self.source_text.next_non_whitespace_byte_is(comment.span.end, b'(')
+                && self.is_type_cast_comment(comment)
+        })
     }
 
     /// Marks the given span as a type cast node.
     pub fn mark_as_type_cast_node(&mut self, node: &impl GetSpan) {
-        self.type_cast_node_span = node.span();
+        self.type_cast_node_span = Some(node.span());
         self.last_handled_type_cast_comment = self.printed_count;
     }
 
@@ -430,7 +472,7 @@ impl<'a> Comments<'a> {
 
     #[inline]
     pub fn is_type_cast_node(&self, node: &impl GetSpan) -> bool {
-        self.type_cast_node_span == node.span()
+        self.type_cast_node_span == Some(node.span())
     }
 
     /// Temporarily limits the unprinted comments view to only those before the given position.
@@ -440,11 +482,8 @@ impl<'a> Comments<'a> {
         let original_limit = self.view_limit;
 
         // Find the index of the first comment that starts at or after end_pos
-        // Using binary search would be more efficient for large comment arrays
-        let limit_index = self.inner[self.printed_count..]
-            .iter()
-            .position(|c| c.span.start >= end_pos)
-            .map_or(self.inner.len(), |idx| self.printed_count + idx);
+        let limit_index = self.printed_count
+            + self.inner[self.printed_count..].partition_point(|c| c.span.start < end_pos);
 
         // Only update if we're actually limiting the view
         if limit_index < self.inner.len() {
@@ -461,3 +500,97 @@ impl<'a> Comments<'a> {
         self.view_limit = limit;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    use super::*;
+
+    /// A xorshift generator, to get the same "random" programs and positions on every run.
+    struct Random(u64);
+
+    impl Random {
+        fn next(&mut self, bound: u32) -> u32 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            u32::try_from(self.0 % u64::from(bound)).unwrap()
+        }
+    }
+
+    /// Returns a program with statements and line, block and JSDoc comments in random order.
+    fn random_source_text(random: &mut Random) -> String {
+        let mut source_text = String::new();
+        for _ in 0..200 {
+            source_text.push_str(match random.next(6) {
+                0 => "// line\n",
+                1 => "/* block */ ",
+                2 => "/** @type {A} */ (a);\n",
+                3 => "\n\n",
+                _ => "a = b;\n",
+            });
+        }
+        source_text
+    }
+
+    /// Checks the binary searches against linear scans of the unprinted comments.
+    #[test]
+    fn binary_search_matches_linear_scan() {
+        let mut random = Random(0x2545_f491_4f6c_dd1d);
+        for _ in 0..20 {
+            let allocator = Allocator::default();
+            let source_text = random_source_text(&mut random);
+            let ret = Parser::new(&allocator, &source_text, SourceType::mjs()).parse();
+            assert!(ret.errors.is_empty());
+            let len = u32::try_from(source_text.len()).unwrap() + 1;
+
+            let mut comments = Comments::new(SourceText::new(&source_text), &ret.program.comments);
+            let count = ret.program.comments.len();
+            comments
+                .increase_printed_count_by(random.next(u32::try_from(count).unwrap() + 1) as usize);
+            let unprinted = comments.unprinted_comments();
+
+            for _ in 0..200 {
+                let start = random.next(len);
+                let end = start + random.next(len - start);
+
+                let before =
+                    unprinted.iter().take_while(|c| c.span.end <= end).copied().collect::<Vec<_>>();
+                assert_eq!(comments.comments_before(end), before);
+                assert_eq!(comments.comments_before_iter(end).count(), before.len());
+                assert_eq!(comments.has_comment_before(end), !before.is_empty());
+                assert_eq!(
+                    comments.has_comment_in_range(start, end),
+                    before.iter().any(|c| c.span.end > start)
+                );
+
+                let after = unprinted
+                    .iter()
+                    .skip_while(|c| c.span.end < start)
+                    .copied()
+                    .collect::<Vec<_>>();
+                assert_eq!(comments.comments_after(start), after);
+                let in_range =
+                    after.iter().take_while(|c| c.span.end <= end).copied().collect::<Vec<_>>();
+                assert_eq!(comments.comments_in_range(start, end), in_range);
+
+                let span = Span::new(start, end);
+                assert_eq!(
+                    comments.get_type_cast_comment_index(span),
+                    before.iter().take_while(|c| c.span.end <= start).position(|comment| {
+                        comments.source_text.next_non_whitespace_byte_is(comment.span.end, b'(')
+                            && comments.is_type_cast_comment(comment)
+                    })
+                );
+
+                let mut limited = comments.clone();
+                limited.limit_comments_up_to(end);
+                let limit = unprinted.iter().take_while(|c| c.span.start < end).count();
+                assert_eq!(limited.unprinted_comments().len(), limit);
+            }
+        }
+    }
+}
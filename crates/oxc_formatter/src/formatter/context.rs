@@ -1,10 +1,15 @@
+use std::sync::Arc;
+
 use oxc_allocator::Allocator;
 use oxc_ast::Comment;
 use oxc_span::{GetSpan, SourceType, Span};
 use rustc_hash::FxHashMap;
 
 use crate::{
-    embedded_formatter::EmbeddedFormatter, formatter::FormatElement, options::FormatOptions,
+    embedded_formatter::EmbeddedLanguageFormatter,
+    format_range::FormatRange,
+    formatter::{FormatElement, FormatError},
+    options::FormatOptions,
 };
 
 use super::{Comments, SourceText};
@@ -29,7 +34,22 @@ pub struct FormatContext<'ast> {
     /// structures (e.g., `{ a: { "b-c": 1 } }` where only the inner object needs quoted keys).
     quote_needed_stack: Vec<bool>,
 
-    embedded_formatter: Option<EmbeddedFormatter>,
+    embedded_formatter: Option<Arc<dyn EmbeddedLanguageFormatter>>,
+
+    /// The spans of `prettier-ignore-start` / `prettier-ignore-end` regions, see [`Comments::ignored_regions`].
+    ignored_regions: &'ast [Span],
+
+    /// The range to format when formatting only a part of the program.
+    format_range: Option<FormatRange>,
+
+    /// Whether to emit [`FormatElement::SourcePosition`]s for the printer to build a source map.
+    source_map: bool,
+
+    /// Whether the program is a JSON value wrapped in parentheses, see [`crate::Formatter::format_json`].
+    json: bool,
+
+    /// The first node the formatter doesn't support, see [`FormatError::UnsupportedSyntax`].
+    unsupported_syntax: Option<FormatError>,
 
     allocator: &'ast Allocator,
 }
@@ -53,17 +73,23 @@ impl<'ast> FormatContext<'ast> {
         comments: &'ast [Comment],
         allocator: &'ast Allocator,
         options: FormatOptions,
-        embedded_formatter: Option<EmbeddedFormatter>,
+        embedded_formatter: Option<Arc<dyn EmbeddedLanguageFormatter>>,
     ) -> Self {
         let source_text = SourceText::new(source_text);
+        let comments = Comments::new(source_text, comments);
         Self {
             options,
             source_text,
             source_type,
-            comments: Comments::new(source_text, comments),
+            ignored_regions: comments.ignored_regions(allocator),
+            comments,
             cached_elements: FxHashMap::default(),
             quote_needed_stack: Vec::new(),
             embedded_formatter,
+            format_range: None,
+            source_map: false,
+            json: false,
+            unsupported_syntax: None,
             allocator,
         }
     }
@@ -74,16 +100,80 @@ impl<'ast> FormatContext<'ast> {
             source_text: SourceText::new(""),
             source_type: SourceType::default(),
             comments: Comments::new(SourceText::new(""), &[]),
+            ignored_regions: &[],
             cached_elements: FxHashMap::default(),
             quote_needed_stack: Vec::new(),
             embedded_formatter: None,
+            format_range: None,
+            source_map: false,
+            json: false,
+            unsupported_syntax: None,
             allocator,
         }
     }
 
+    /// Restricts the formatted output to the statements overlapping `range`,
+    /// see [`crate::Formatter::format_range`].
+    #[must_use]
+    pub fn with_format_range(mut self, range: Span) -> Self {
+        self.format_range = Some(FormatRange::new(range));
+        self
+    }
+
+    /// Records the source positions of the printed code, see [`crate::Formatter::with_source_map`].
+    #[must_use]
+    pub fn with_source_map(mut self) -> Self {
+        self.source_map = true;
+        self
+    }
+
+    /// Formats the program as a JSON value, see [`crate::Formatter::format_json`].
+    #[must_use]
+    pub fn with_json(mut self) -> Self {
+        self.json = true;
+        self
+    }
+
+    /// Returns `true` if the program is a JSON value, which is printed without parentheses or
+    /// a semicolon, and whose strings and numbers are printed verbatim.
+    pub fn is_json(&self) -> bool {
+        self.json
+    }
+
+    /// Returns `true` if the source positions of the printed code are recorded.
+    pub fn source_map(&self) -> bool {
+        self.source_map
+    }
+
+    /// Records that the node at `span` isn't supported and is printed verbatim.
+    /// Only the first unsupported node is kept.
+    pub fn report_unsupported_syntax(&mut self, span: Span, kind: &'static str) {
+        self.unsupported_syntax.get_or_insert(FormatError::UnsupportedSyntax { span, kind });
+    }
+
+    /// Returns the error for the first node the formatter doesn't support, if any.
+    pub fn unsupported_syntax(&self) -> Option<FormatError> {
+        self.unsupported_syntax
+    }
+
+    /// Returns the spans of `prettier-ignore-start` / `prettier-ignore-end` regions,
+    /// whose statements are printed verbatim.
+    pub fn ignored_regions(&self) -> &'ast [Span] {
+        self.ignored_regions
+    }
+
+    /// Returns the range to format, if only a part of the program is formatted.
+    pub fn format_range(&self) -> Option<&FormatRange> {
+        self.format_range.as_ref()
+    }
+
+    pub(crate) fn format_range_mut(&mut self) -> Option<&mut FormatRange> {
+        self.format_range.as_mut()
+    }
+
     /// Get the embedded formatter if one is set
-    pub fn embedded_formatter(&self) -> Option<&EmbeddedFormatter> {
-        self.embedded_formatter.as_ref()
+    pub fn embedded_formatter(&self) -> Option<&dyn EmbeddedLanguageFormatter> {
+        self.embedded_formatter.as_deref()
     }
 
     /// Returns the formatting options
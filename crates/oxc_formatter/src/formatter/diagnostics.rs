@@ -4,6 +4,8 @@
 // use biome_rowan::{SyntaxError, TextRange};
 use std::error::Error;
 
+use oxc_span::Span;
+
 use super::{TextRange, prelude::TagKind};
 
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
@@ -26,6 +28,10 @@ pub enum FormatError {
     /// This error should not be raised if there's no outer [crate::Format] handling the poor layout error,
     /// avoiding that formatting of the whole document fails.
     PoorLayout,
+
+    /// The program contains a node the formatter doesn't support, e.g. a `ParenthesizedExpression`
+    /// of a program parsed with `preserve_parens`. The node is printed verbatim instead.
+    UnsupportedSyntax { span: Span, kind: &'static str },
 }
 
 impl std::fmt::Display for FormatError {
@@ -44,6 +50,12 @@ impl std::fmt::Display for FormatError {
             FormatError::PoorLayout => fmt.write_str(
                 "Poor layout: The formatter wasn't able to pick a good layout for your document. This is an internal Biome error. Please report if necessary.",
             ),
+            FormatError::UnsupportedSyntax { span, kind } => std::write!(
+                fmt,
+                "Can't format `{kind}` at {}..{}, it is printed verbatim",
+                span.start,
+                span.end
+            ),
         }
     }
 }
@@ -21,7 +21,7 @@ pub struct Document<'a> {
 impl Document<'_> {
     /// Sets [`expand`](tag::Group::expand) to [`GroupMode::Propagated`] if the group contains any of:
     /// * a group with [`expand`](tag::Group::expand) set to [GroupMode::Propagated] or [GroupMode::Expand].
-    /// * a non-soft [line break](FormatElement::Line) with mode [LineMode::Hard], [LineMode::Empty], or [LineMode::Literal].
+    /// * a non-soft [line break](FormatElement::Line) with mode [LineMode::Hard], [LineMode::Empty], [LineMode::EmptyLines], or [LineMode::Literal].
     /// * a [FormatElement::ExpandParent]
     ///
     /// [`BestFitting`] elements act as expand boundaries, meaning that the fact that a
@@ -113,9 +113,12 @@ impl Document<'_> {
                         false
                     }
                     // `FormatElement::Token` cannot contain line breaks
-                    FormatElement::Text { text: _, width } => width.is_multiline(),
+                    FormatElement::Text { text: _, width }
+                    | FormatElement::VerbatimText { text: _, width } => width.is_multiline(),
                     FormatElement::ExpandParent
-                    | FormatElement::Line(LineMode::Hard | LineMode::Empty) => true,
+                    | FormatElement::Line(
+                        LineMode::Hard | LineMode::Empty | LineMode::EmptyLines(_),
+                    ) => true,
                     _ => false,
                 };
 
@@ -187,7 +190,8 @@ impl<'a> Format<'a> for &[FormatElement<'a>] {
                 element @ (FormatElement::Space
                 | FormatElement::HardSpace
                 | FormatElement::Token { .. }
-                | FormatElement::Text { .. }) => {
+                | FormatElement::Text { .. }
+                | FormatElement::VerbatimText { .. }) => {
                     if !in_text {
                         write!(f, [token("\"")]);
                     }
@@ -203,7 +207,8 @@ impl<'a> Format<'a> for &[FormatElement<'a>] {
                             let new_element = match element {
                                 // except for static text because source_position is unknown
                                 FormatElement::Token { .. } => element.clone(),
-                                FormatElement::Text { text, width: _ } => {
+                                FormatElement::Text { text, width: _ }
+                                | FormatElement::VerbatimText { text, width: _ } => {
                                     let text = text.cow_replace('"', "\\\"");
                                     FormatElement::Text {
                                         text: f.context().allocator().alloc_str(&text),
@@ -241,6 +246,16 @@ impl<'a> Format<'a> for &[FormatElement<'a>] {
                     LineMode::Empty => {
                         write!(f, [token("empty_line")]);
                     }
+                    LineMode::EmptyLines(count) => {
+                        write!(
+                            f,
+                            [text(
+                                f.context()
+                                    .allocator()
+                                    .alloc_str(&std::format!("empty_lines({count})"))
+                            )]
+                        );
+                    }
                 },
                 FormatElement::ExpandParent => {
                     write!(f, [token("expand_parent")]);
@@ -250,6 +265,17 @@ impl<'a> Format<'a> for &[FormatElement<'a>] {
                     write!(f, [token("line_suffix_boundary")]);
                 }
 
+                FormatElement::SourcePosition(position) => {
+                    write!(
+                        f,
+                        [text(
+                            f.context()
+                                .allocator()
+                                .alloc_str(&std::format!("source_position({position})"))
+                        )]
+                    );
+                }
+
                 FormatElement::BestFitting(best_fitting) => {
                     write!(f, [token("best_fitting([")]);
                     f.write_elements([
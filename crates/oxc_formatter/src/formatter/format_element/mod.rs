@@ -81,6 +81,13 @@ pub enum FormatElement<'a> {
         width: TextWidth,
     },
 
+    /// A text whose line breaks are printed as they are instead of with the configured line ending,
+    /// see [crate::builders::verbatim_text] for documentation.
+    VerbatimText {
+        text: &'a str,
+        width: TextWidth,
+    },
+
     /// Prevents that line suffixes move past this boundary. Forces the printer to print any pending
     /// line suffixes, potentially by inserting a hard line break.
     LineSuffixBoundary,
@@ -95,6 +102,10 @@ pub enum FormatElement<'a> {
 
     /// A [Tag] that marks the start/end of some content to which some special formatting is applied.
     Tag(Tag),
+
+    /// Marks that the following text is printed for the source text at this position,
+    /// see [crate::builders::source_position] for documentation.
+    SourcePosition(u32),
 }
 
 impl std::fmt::Debug for FormatElement<'_> {
@@ -105,12 +116,18 @@ impl std::fmt::Debug for FormatElement<'_> {
             FormatElement::ExpandParent => fmt.write_str("ExpandParent"),
             FormatElement::Token { text } => fmt.debug_tuple("Token").field(text).finish(),
             FormatElement::Text { text, .. } => fmt.debug_tuple("Text").field(text).finish(),
+            FormatElement::VerbatimText { text, .. } => {
+                fmt.debug_tuple("VerbatimText").field(text).finish()
+            }
             FormatElement::LineSuffixBoundary => fmt.write_str("LineSuffixBoundary"),
             FormatElement::BestFitting(best_fitting) => {
                 fmt.debug_tuple("BestFitting").field(&best_fitting).finish()
             }
             FormatElement::Interned(interned) => fmt.debug_list().entries(&**interned).finish(),
             FormatElement::Tag(tag) => fmt.debug_tuple("Tag").field(tag).finish(),
+            FormatElement::SourcePosition(position) => {
+                fmt.debug_tuple("SourcePosition").field(position).finish()
+            }
         }
     }
 }
@@ -125,6 +142,8 @@ pub enum LineMode {
     Hard,
     /// See [crate::builders::empty_line] for documentation.
     Empty,
+    /// See [crate::builders::empty_lines] for documentation.
+    EmptyLines(u8),
 }
 
 impl LineMode {
@@ -133,7 +152,7 @@ impl LineMode {
     }
 
     pub const fn will_break(self) -> bool {
-        matches!(self, LineMode::Hard | LineMode::Empty)
+        matches!(self, LineMode::Hard | LineMode::Empty | LineMode::EmptyLines(_))
     }
 }
 
@@ -251,7 +270,12 @@ impl FormatElement<'_> {
     }
 
     pub const fn is_text(&self) -> bool {
-        matches!(self, FormatElement::Text { .. } | FormatElement::Token { .. })
+        matches!(
+            self,
+            FormatElement::Text { .. }
+                | FormatElement::VerbatimText { .. }
+                | FormatElement::Token { .. }
+        )
     }
 
     pub const fn is_space(&self) -> bool {
@@ -269,7 +293,8 @@ impl FormatElements for FormatElement<'_> {
             FormatElement::ExpandParent => true,
             FormatElement::Tag(Tag::StartGroup(group)) => !group.mode().is_flat(),
             FormatElement::Line(line_mode) => line_mode.will_break(),
-            FormatElement::Text { text: _, width } => width.is_multiline(),
+            FormatElement::Text { text: _, width }
+            | FormatElement::VerbatimText { text: _, width } => width.is_multiline(),
             FormatElement::Interned(interned) => interned.will_break(),
             // Traverse into the most flat version because the content is guaranteed to expand when even
             // the most flat version contains some content that forces a break.
@@ -279,6 +304,7 @@ impl FormatElements for FormatElement<'_> {
             | FormatElement::LineSuffixBoundary
             | FormatElement::Space
             | FormatElement::Tag(_)
+            | FormatElement::SourcePosition(_)
             | FormatElement::HardSpace => false,
         }
     }
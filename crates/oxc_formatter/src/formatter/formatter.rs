@@ -34,6 +34,15 @@ impl<'buf, 'ast> Formatter<'buf, 'ast> {
         self.context().options()
     }
 
+    /// Returns how many of the empty lines between two nodes to keep, given the `line_breaks`
+    /// between them in the source. At most [`FormatOptions::max_empty_lines`] are kept.
+    #[inline]
+    pub fn source_empty_lines(&self, line_breaks: usize) -> u8 {
+        let max_empty_lines = self.options().max_empty_lines;
+        u8::try_from(line_breaks.saturating_sub(1))
+            .map_or(max_empty_lines, |empty_lines| empty_lines.min(max_empty_lines))
+    }
+
     /// Returns the Context specifying how to format the current CST
     #[inline]
     pub fn context(&self) -> &FormatContext<'ast> {
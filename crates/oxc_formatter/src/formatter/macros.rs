@@ -29,9 +29,9 @@
 #[macro_export]
 macro_rules! format_args {
     ($($value:expr),+ $(,)?) => {
-        $crate::formatter::Arguments::new(&[
+        $crate::prelude::Arguments::new(&[
             $(
-                $crate::formatter::Argument::new(&$value)
+                $crate::prelude::Argument::new(&$value)
             ),+
         ])
     }
@@ -144,7 +144,7 @@ macro_rules! dbg_write {
 #[macro_export]
 macro_rules! format {
     ($context:expr, [$($arg:expr),+ $(,)?]) => {{
-        ($crate::formatter::format($context, $crate::format_args!($($arg),+)))
+        ($crate::prelude::format($context, $crate::format_args!($($arg),+)))
     }}
 }
 
@@ -334,6 +334,6 @@ macro_rules! format {
 #[macro_export]
 macro_rules! best_fitting {
     ($least_expanded:expr, $($tail:expr),+ $(,)?) => {
-        BestFitting::from_arguments_unchecked($crate::format_args!($least_expanded, $($tail),+))
+        $crate::prelude::BestFitting::from_arguments_unchecked($crate::format_args!($least_expanded, $($tail),+))
     };
 }
@@ -54,7 +54,11 @@ pub use self::{
     state::FormatState,
     text_range::TextRange,
 };
-use self::{format_element::document::Document, group_id::UniqueGroupIdBuilder, prelude::TagKind};
+use self::{
+    format_element::{document::Document, tag::LabelId},
+    group_id::UniqueGroupIdBuilder,
+    prelude::TagKind,
+};
 
 #[derive(Debug, Clone)]
 pub struct Formatted<'a> {
@@ -90,6 +94,10 @@ impl<'a> Formatted<'a> {
 }
 
 impl Formatted<'_> {
+    /// Prints the document and returns the printed code.
+    ///
+    /// # Errors
+    /// Returns a [`PrintError`] if the document is invalid, e.g. has unbalanced tags.
     pub fn print(&self) -> PrintResult<Printed> {
         let print_options = self.context.options().as_print_options();
 
@@ -98,29 +106,89 @@ impl Formatted<'_> {
         Ok(printed)
     }
 
+    /// Prints the document like [`Self::print`], but starting at the given indent level.
+    ///
+    /// # Errors
+    /// Returns a [`PrintError`] if the document is invalid, e.g. has unbalanced tags.
     pub fn print_with_indent(&self, indent: u16) -> PrintResult<Printed> {
         let print_options = self.context.options().as_print_options();
         let printed = Printer::new(print_options).print_with_indent(&self.document, indent)?;
 
         Ok(printed)
     }
+
+    /// Prints the document into `writer`. See [`Printer::print_into`].
+    ///
+    /// # Errors
+    /// Returns a [`PrintError`] if the document is invalid, e.g. has unbalanced tags.
+    pub fn print_into(&self, writer: &mut impl std::fmt::Write) -> PrintResult<std::fmt::Result> {
+        let print_options = self.context.options().as_print_options();
+        Printer::new(print_options).print_into(&self.document, writer)
+    }
+
+    /// Prints the document while comparing it against `expected`.
+    /// See [`Printer::check`].
+    ///
+    /// # Errors
+    /// Returns a [`PrintError`] if the document is invalid, e.g. has unbalanced tags.
+    pub fn check(&self, expected: &str) -> PrintResult<Option<usize>> {
+        let print_options = self.context.options().as_print_options();
+        Printer::new(print_options).check(&self.document, expected)
+    }
+
+    /// Prints the document, but only returns the code printed for the innermost content
+    /// labelled with `label`. See [`Printer::print_labelled`].
+    ///
+    /// # Errors
+    /// Returns a [`PrintError`] if the document is invalid, e.g. has unbalanced tags.
+    pub fn print_labelled(
+        &self,
+        label: LabelId,
+        indent: Option<u16>,
+    ) -> PrintResult<Option<String>> {
+        let print_options = self.context.options().as_print_options();
+        Printer::new(print_options).print_labelled(&self.document, label, indent)
+    }
 }
 pub type PrintResult<T> = Result<T, PrintError>;
 
+/// Maps a byte offset of the printed code to the byte offset of the source text it was printed for.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SourceMarker {
+    /// The offset in the source text.
+    pub source: u32,
+    /// The offset in the printed code.
+    pub dest: u32,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Printed {
     code: String,
     range: Option<TextRange>,
+    source_markers: Vec<SourceMarker>,
 }
 
 impl Printed {
     pub fn new(code: String, range: Option<TextRange>) -> Self {
-        Self { code, range }
+        Self { code, range, source_markers: Vec::new() }
     }
 
     /// Construct an empty formatter result
     pub fn new_empty() -> Self {
-        Self { code: String::new(), range: None }
+        Self { code: String::new(), range: None, source_markers: Vec::new() }
+    }
+
+    #[must_use]
+    pub fn with_source_markers(mut self, source_markers: Vec<SourceMarker>) -> Self {
+        self.source_markers = source_markers;
+        self
+    }
+
+    /// The positions of the printed code that map to a position in the source text,
+    /// sorted by [`SourceMarker::dest`]. Empty unless the source map is enabled,
+    /// see [`crate::Formatter::with_source_map`].
+    pub fn source_markers(&self) -> &[SourceMarker] {
+        &self.source_markers
     }
 
     /// Range of the input source file covered by this formatted code,
@@ -4,7 +4,7 @@ mod printer_options;
 mod queue;
 mod stack;
 
-use std::num::NonZeroU8;
+use std::{fmt, num::NonZeroU8, ops::ControlFlow};
 
 use oxc_data_structures::code_buffer::{self, CodeBuffer};
 pub use printer_options::*;
@@ -13,11 +13,12 @@ use unicode_width::UnicodeWidthChar;
 use self::call_stack::PrintIndentStack;
 use super::{
     ActualStart, FormatElement, GroupId, InvalidDocumentError, PrintError, PrintResult, Printed,
+    SourceMarker,
     format_element::{BestFittingElement, LineMode, PrintMode, document::Document, tag::Condition},
     prelude::{
         Tag::EndFill,
         TextWidth,
-        tag::{DedentMode, Tag, TagKind},
+        tag::{DedentMode, LabelId, Tag, TagKind},
     },
     printer::{
         call_stack::{
@@ -39,16 +40,23 @@ pub struct Printer<'a> {
     state: PrinterState<'a>,
 }
 
+/// The size of the printed code [`Printer::print_into`] keeps in memory before writing it.
+const WRITE_CHUNK_SIZE: usize = 64 * 1024;
+
 impl<'a> Printer<'a> {
     pub fn new(options: PrinterOptions) -> Self {
+        let buffer = Self::new_buffer(&options);
+        Self { options, state: PrinterState::new(buffer) }
+    }
+
+    fn new_buffer(options: &PrinterOptions) -> CodeBuffer {
         let (indent_char, indent_width) = match options.indent_style() {
             IndentStyle::Tab => (code_buffer::IndentChar::Tab, 1),
             IndentStyle::Space => {
                 (code_buffer::IndentChar::Space, options.indent_width().value() as usize)
             }
         };
-        let buffer = CodeBuffer::with_indent(indent_char, indent_width);
-        Self { options, state: PrinterState::new(buffer) }
+        CodeBuffer::with_indent(indent_char, indent_width)
     }
 
     /// Prints the passed in element as well as all its content
@@ -63,6 +71,118 @@ impl<'a> Printer<'a> {
         document: &'a Document<'a>,
         indent: u16,
     ) -> PrintResult<Printed> {
+        self.print_document(document, indent, |_| ControlFlow::Continue(()))?;
+
+        Ok(Printed::new(self.state.buffer.into_string(), None)
+            .with_source_markers(self.state.source_markers))
+    }
+
+    /// Prints the passed in element as well as all its content into `writer`.
+    ///
+    /// The printed code is written in chunks while printing, so it's never kept in memory as a whole.
+    /// Returns the error of `writer` if writing failed, printing stops at the first failed write.
+    /// Source positions aren't recorded.
+    pub fn print_into(
+        mut self,
+        document: &'a Document<'a>,
+        writer: &mut impl fmt::Write,
+    ) -> PrintResult<fmt::Result> {
+        let empty_buffer = Self::new_buffer(&self.options);
+        let mut result = Ok(());
+
+        self.print_document(document, 0, |buffer| {
+            if buffer.len() < WRITE_CHUNK_SIZE {
+                return ControlFlow::Continue(());
+            }
+            let chunk = std::mem::replace(buffer, empty_buffer.clone()).into_string();
+            result = writer.write_str(&chunk);
+            if result.is_err() { ControlFlow::Break(()) } else { ControlFlow::Continue(()) }
+        })?;
+
+        Ok(result.and_then(|()| writer.write_str(&self.state.buffer.into_string())))
+    }
+
+    /// Prints the passed in element as well as all its content, but only returns the code
+    /// printed for the innermost content labelled with `label`, up to the end of its last line.
+    ///
+    /// The leading indentation of the first line is not part of the returned code. If `indent`
+    /// is set, the following lines are indented relative to that level instead of the level
+    /// the labelled content is printed at.
+    /// Returns `None` if no content with `label` has been printed.
+    pub fn print_labelled(
+        mut self,
+        document: &'a Document<'a>,
+        label: LabelId,
+        indent: Option<u16>,
+    ) -> PrintResult<Option<String>> {
+        self.state.labelled_range.label = Some(label);
+        self.state.labelled_range.indent = indent;
+        self.print_document(document, 0, |_| ControlFlow::Continue(()))?;
+
+        let Some((start, end)) = self.state.labelled_range.range else {
+            return Ok(None);
+        };
+        let end = end.unwrap_or_else(|| self.state.buffer.len());
+        let code = self.state.buffer.into_string();
+        let code = code[start..end].trim_start_matches([' ', '\t']).trim_end_matches([' ', '\t']);
+
+        Ok(Some(code.to_string()))
+    }
+
+    /// Prints the passed in element as well as all its content, comparing the printed code
+    /// against `expected` while printing and stopping at the first difference.
+    ///
+    /// The printed code is compared and dropped in chunks, so it's never kept in memory as a whole.
+    /// Returns the byte offset of the first difference,
+    /// or `None` if the printed code is identical to `expected`.
+    pub fn check(
+        mut self,
+        document: &'a Document<'a>,
+        expected: &str,
+    ) -> PrintResult<Option<usize>> {
+        let empty_buffer = Self::new_buffer(&self.options);
+        let expected = expected.as_bytes();
+        // The offset in `expected` of the start of the buffer, and the compared length of the buffer.
+        let mut buffer_start = 0;
+        let mut compared = 0;
+        let mut difference = None;
+
+        self.print_document(document, 0, |buffer| {
+            let checked = buffer_start + compared;
+            let printed = &buffer.as_bytes()[compared..];
+            let remaining = expected.get(checked..).unwrap_or_default();
+
+            if let Some(index) = printed.iter().zip(remaining).position(|(a, b)| a != b) {
+                difference = Some(checked + index);
+            } else if printed.len() > remaining.len() {
+                difference = Some(checked + remaining.len());
+            }
+
+            compared = buffer.len();
+            if compared >= WRITE_CHUNK_SIZE {
+                *buffer = empty_buffer.clone();
+                buffer_start += compared;
+                compared = 0;
+            }
+            if difference.is_some() { ControlFlow::Break(()) } else { ControlFlow::Continue(()) }
+        })?;
+
+        let checked = buffer_start + compared;
+        if difference.is_none() && checked < expected.len() {
+            difference = Some(checked);
+        }
+
+        Ok(difference)
+    }
+
+    /// Prints the document, calling `after_element` with the printed code after every element
+    /// until it breaks.
+    fn print_document(
+        &mut self,
+        document: &'a Document<'a>,
+        indent: u16,
+        mut after_element: impl FnMut(&mut CodeBuffer) -> ControlFlow<()>,
+    ) -> PrintResult<()> {
         let mut stack = PrintCallStack::new(PrintElementArgs::new());
         let mut queue: PrintQueue<'a> = PrintQueue::new(document.as_ref());
         let mut indent_stack = PrintIndentStack::new(Indention::Level(indent));
@@ -73,9 +193,13 @@ impl<'a> Printer<'a> {
             if queue.is_empty() {
                 self.flush_line_suffixes(&mut queue, &mut stack, &mut indent_stack, None);
             }
+
+            if after_element(&mut self.state.buffer).is_break() {
+                break;
+            }
         }
 
-        Ok(Printed::new(self.state.buffer.into_string(), None))
+        Ok(())
     }
 
     /// Prints a single element and push the following elements to queue
@@ -103,7 +227,10 @@ impl<'a> Printer<'a> {
 
             FormatElement::Token { text } => self.print_text(Text::Token(text)),
             FormatElement::Text { text, width } => {
-                self.print_text(Text::Text { text, width: *width });
+                self.print_text(Text::Text { text, width: *width, verbatim: false });
+            }
+            FormatElement::VerbatimText { text, width } => {
+                self.print_text(Text::Text { text, width: *width, verbatim: true });
             }
             FormatElement::Line(line_mode) => {
                 if args.mode().is_flat() {
@@ -114,7 +241,7 @@ impl<'a> Printer<'a> {
                             }
                             return Ok(());
                         }
-                        LineMode::Hard | LineMode::Empty => {
+                        LineMode::Hard | LineMode::Empty | LineMode::EmptyLines(_) => {
                             self.state.measured_group_fits = false;
                         }
                     }
@@ -128,13 +255,18 @@ impl<'a> Printer<'a> {
                 // Only print a newline if the current line isn't already empty
                 if self.state.line_width > 0 {
                     self.print_char('\n');
-                    self.state.has_empty_line = false;
+                    self.state.empty_lines = 0;
                 }
 
-                // Print a second line break if this is an empty line
-                if line_mode == &LineMode::Empty && !self.state.has_empty_line {
+                // Print additional line breaks until there are as many empty lines as requested
+                let empty_lines = match line_mode {
+                    LineMode::Empty => 1,
+                    LineMode::EmptyLines(count) => *count,
+                    _ => 0,
+                };
+                while self.state.empty_lines < empty_lines {
                     self.print_char('\n');
-                    self.state.has_empty_line = true;
+                    self.state.empty_lines += 1;
                 }
 
                 self.state.pending_space = false;
@@ -145,6 +277,10 @@ impl<'a> Printer<'a> {
                 // Handled in `Document::propagate_expands()
             }
 
+            FormatElement::SourcePosition(position) => {
+                self.state.pending_source_position = Some(*position);
+            }
+
             FormatElement::LineSuffixBoundary => {
                 const HARD_BREAK: &FormatElement = &FormatElement::Line(LineMode::Hard);
                 self.flush_line_suffixes(queue, stack, indent_stack, Some(HARD_BREAK));
@@ -244,12 +380,22 @@ impl<'a> Printer<'a> {
                 indent_stack.push_suffix(indent_stack.indention());
                 self.state.line_suffixes.extend(args, queue.iter_content(TagKind::LineSuffix));
             }
-            FormatElement::Tag(tag @ (StartLabelled(_) | StartEntry)) => {
+            FormatElement::Tag(tag @ StartLabelled(label_id)) => {
+                self.state.labelled_range.start(
+                    *label_id,
+                    self.state.buffer.len(),
+                    indent_stack.indention().level(),
+                );
                 stack.push(tag.kind(), args);
             }
-            FormatElement::Tag(
-                tag @ (EndLabelled | EndEntry | EndGroup | EndConditionalContent | EndFill),
-            ) => {
+            FormatElement::Tag(tag @ StartEntry) => {
+                stack.push(tag.kind(), args);
+            }
+            FormatElement::Tag(tag @ EndLabelled) => {
+                self.state.labelled_range.end();
+                stack.pop(tag.kind())?;
+            }
+            FormatElement::Tag(tag @ (EndEntry | EndGroup | EndConditionalContent | EndFill)) => {
                 stack.pop(tag.kind())?;
             }
             FormatElement::Tag(tag @ EndIndentIfGroupBreaks(group_id)) => {
@@ -593,7 +739,7 @@ impl<'a> Printer<'a> {
         if !self.state.pending_indent.is_empty() {
             let indent = std::mem::take(&mut self.state.pending_indent);
 
-            let level = indent.level() as usize;
+            let level = self.state.labelled_range.adjust_level(indent.level()) as usize;
             self.state.buffer.print_indent(level);
             self.state.line_width += level * self.options.indent_width().value() as usize;
 
@@ -617,6 +763,12 @@ impl<'a> Printer<'a> {
             self.state.line_width += 1;
         }
 
+        if let Some(source) = self.state.pending_source_position.take() {
+            #[expect(clippy::cast_possible_truncation)]
+            let dest = self.state.buffer.len() as u32;
+            self.state.source_markers.push(SourceMarker { source, dest });
+        }
+
         match text {
             Text::Token(text) => {
                 // SAFETY: `text` is a ASCII-only string
@@ -625,7 +777,7 @@ impl<'a> Printer<'a> {
                 }
                 self.state.line_width += text.len();
             }
-            Text::Text { text, width } => {
+            Text::Text { text, width, verbatim: false } => {
                 if width.is_multiline() {
                     let line_break_position = text.find('\n').unwrap_or(text.len());
                     let (first_line, remaining) = text.split_at(line_break_position);
@@ -640,13 +792,33 @@ impl<'a> Printer<'a> {
                     self.state.line_width += width.value() as usize;
                 }
             }
+            Text::Text { text, width, verbatim: true } => {
+                let line_break_position = text.find(['\n', '\r']).unwrap_or(text.len());
+                let (first_line, remaining) = text.split_at(line_break_position);
+                self.state.buffer.print_str(first_line);
+                self.state.line_width += width.value() as usize;
+                // Keep the line breaks of the remaining lines as they are
+                for char in remaining.chars() {
+                    match char {
+                        '\n' | '\r' => {
+                            if char == '\n' {
+                                self.state.labelled_range.line_end(self.state.buffer.len());
+                            }
+                            self.state.buffer.print_char(char);
+                            self.state.line_width = 0;
+                        }
+                        char => self.print_char(char),
+                    }
+                }
+            }
         }
 
-        self.state.has_empty_line = false;
+        self.state.empty_lines = 0;
     }
 
     fn print_char(&mut self, char: char) {
         if char == '\n' {
+            self.state.labelled_range.line_end(self.state.buffer.len());
             // SAFETY: `line_ending` is one of `\n`, `\r\n` or `\r`, all valid ASCII sequences
             unsafe {
                 self.state.buffer.print_bytes_unchecked(self.options.line_ending.as_bytes());
@@ -697,7 +869,8 @@ struct PrinterState<'a> {
     pending_space: bool,
     measured_group_fits: bool,
     line_width: usize,
-    has_empty_line: bool,
+    /// The number of empty lines printed since the last text.
+    empty_lines: u8,
     line_suffixes: LineSuffixes<'a>,
     group_modes: GroupModes,
     // Re-used queue to measure if a group fits. Optimisation to avoid re-allocating a new
@@ -706,6 +879,10 @@ struct PrinterState<'a> {
     fits_indent_stack: Vec<Indention>,
     fits_stack_tem_indent: Vec<Indention>,
     fits_queue: Vec<&'a [FormatElement<'a>]>,
+    labelled_range: LabelledRange,
+    /// The source position of the next printed text, see [`FormatElement::SourcePosition`].
+    pending_source_position: Option<u32>,
+    source_markers: Vec<SourceMarker>,
 }
 
 impl PrinterState<'_> {
@@ -718,6 +895,64 @@ impl PrinterState<'_> {
         }
     }
 }
+/// Tracks where the innermost content labelled with the label passed to [`Printer::print_labelled`]
+/// is printed.
+#[derive(Debug, Default)]
+struct LabelledRange {
+    label: Option<LabelId>,
+    /// The output position of every open labelled content, `None` for other labels.
+    starts: Vec<Option<usize>>,
+    /// The start of the innermost labelled content and the end of its last line, if printed yet.
+    range: Option<(usize, Option<usize>)>,
+    /// The indentation level to print the labelled content at.
+    indent: Option<u16>,
+    /// The difference between `indent` and the level the labelled content is printed at.
+    level_delta: i32,
+}
+
+impl LabelledRange {
+    fn start(&mut self, label: LabelId, position: usize, level: u16) {
+        let Some(expected) = self.label else {
+            return;
+        };
+
+        if expected == label {
+            if let Some(indent) = self.indent {
+                self.level_delta = i32::from(indent) - i32::from(level);
+            }
+            self.starts.push(Some(position));
+        } else {
+            self.starts.push(None);
+        }
+    }
+
+    fn adjust_level(&self, level: u16) -> u16 {
+        if self.level_delta == 0 || !self.starts.iter().any(Option::is_some) {
+            return level;
+        }
+        u16::try_from(i32::from(level) + self.level_delta).unwrap_or(0)
+    }
+
+    fn end(&mut self) {
+        if self.label.is_none() {
+            return;
+        }
+
+        // Nested content starts after the content containing it, but ends before it.
+        if let Some(Some(start)) = self.starts.pop()
+            && self.range.is_none_or(|(current, _)| start > current)
+        {
+            self.range = Some((start, None));
+        }
+    }
+
+    fn line_end(&mut self, position: usize) {
+        if let Some((_, end @ None)) = &mut self.range {
+            *end = Some(position);
+        }
+    }
+}
+
 /// Tracks the mode in which groups with ids are printed. Stores the groups at `group.id()` index.
 /// This is based on the assumption that the group ids for a single document are dense.
 #[derive(Debug, Default)]
@@ -976,7 +1211,7 @@ impl<'a, 'print> FitsMeasurer<'a, 'print> {
                             self.state.pending_space = true;
                         }
                         LineMode::Soft => {}
-                        LineMode::Hard | LineMode::Empty => {
+                        LineMode::Hard | LineMode::Empty | LineMode::EmptyLines(_) => {
                             // Even in flat mode, content that _directly_ contains a hard or empty
                             // line is considered to fit when a hard break is reached, since that
                             // break is always going to exist, regardless of the print mode.
@@ -1034,9 +1269,14 @@ impl<'a, 'print> FitsMeasurer<'a, 'print> {
                 return Ok(self.fits_text(Text::Token(text)));
             }
             FormatElement::Text { text, width } => {
-                return Ok(self.fits_text(Text::Text { text, width: *width }));
+                return Ok(self.fits_text(Text::Text { text, width: *width, verbatim: false }));
+            }
+            FormatElement::VerbatimText { text, width } => {
+                return Ok(self.fits_text(Text::Text { text, width: *width, verbatim: true }));
             }
 
+            FormatElement::SourcePosition(_) => {}
+
             FormatElement::LineSuffixBoundary => {
                 if self.state.has_line_suffix {
                     return Ok(Fits::No);
@@ -1181,7 +1421,7 @@ impl<'a, 'print> FitsMeasurer<'a, 'print> {
             Text::Token(text) => {
                 self.state.line_width += text.len();
             }
-            Text::Text { text: _, width } => {
+            Text::Text { width, .. } => {
                 if width.is_multiline() {
                     return if self.must_be_flat
                         || self.state.line_width + width.value() as usize
@@ -1295,7 +1535,8 @@ enum Text<'a> {
     /// ASCII only text that contains no line breaks or tab characters.
     Token(&'a str),
     /// Arbitrary text. May contain `\n` line breaks, tab characters, or unicode characters.
-    Text { text: &'a str, width: TextWidth },
+    /// The `\n`, `\r\n`, or `\r` line breaks of `verbatim` text are printed as they are.
+    Text { text: &'a str, width: TextWidth, verbatim: bool },
 }
 
 #[cfg(test)]
@@ -1563,6 +1804,24 @@ two lines`,
         assert_eq!("a\n\nb", result.as_code());
     }
 
+    #[test]
+    fn it_prints_the_most_empty_lines_of_consecutive_lines() {
+        let allocator = Allocator::default();
+        let result = format(
+            &allocator,
+            &format_args!(
+                token("a"),
+                empty_line(),
+                empty_lines(3),
+                hard_line_break(),
+                empty_lines(2),
+                token("b"),
+            ),
+        );
+
+        assert_eq!("a\n\n\n\nb", result.as_code());
+    }
+
     #[test]
     fn test_fill_breaks() {
         let allocator = Allocator::default();
@@ -5,6 +5,7 @@ use oxc_syntax::{
     identifier::is_white_space_single_line,
     line_terminator::{CR, LF, is_line_terminator},
 };
+use unicode_width::UnicodeWidthStr;
 
 use super::Comments;
 
@@ -128,9 +129,10 @@ impl<'a> SourceText<'a> {
     }
 
     // Utility methods
-    /// Get character count of span
+    /// Get the width of the span's text in columns, measured the same way as the printer does,
+    /// so that wide characters (e.g. CJK) count as two columns.
     pub fn span_width(&self, span: Span) -> usize {
-        self.text_for(&span).chars().count()
+        self.text_for(&span).width()
     }
 
     /// Count consecutive line breaks after position, returning `0` if only whitespace follows
@@ -34,6 +34,13 @@ pub struct CleanedNumberLiteralText<'a> {
     options: NumberFormatOptions,
 }
 
+impl<'a> CleanedNumberLiteralText<'a> {
+    /// Returns the text as it is printed, e.g. `1.5` for `1.50`.
+    pub fn cleaned_text(&self) -> Cow<'a, str> {
+        format_trimmed_number(self.text, self.options)
+    }
+}
+
 impl<'a> Format<'a> for CleanedNumberLiteralText<'a> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) {
         let text = format_trimmed_number(self.text, self.options);
@@ -50,6 +57,8 @@ enum FormatNumberLiteralState {
 struct FormatNumberLiteralDecimalPart {
     dot_index: usize,
     last_non_zero_index: Option<NonZeroUsize>,
+    /// Trailing zeroes are only removed from decimal parts without numeric separators, like prettier does.
+    has_separator: bool,
 }
 
 struct FormatNumberLiteralExponent {
@@ -64,7 +73,7 @@ struct FormatNumberLiteralExponent {
 fn format_trimmed_number(text: &str, options: NumberFormatOptions) -> Cow<'_, str> {
     use FormatNumberLiteralState::{DecimalPart, Exponent, IntegerPart};
 
-    let text = text.cow_to_ascii_lowercase();
+    let text = lowercase_number_letters(text);
     let mut copied_or_ignored_chars = 0usize;
     let mut iter = text.bytes().enumerate();
     let mut curr = iter.next();
@@ -73,6 +82,9 @@ fn format_trimmed_number(text: &str, options: NumberFormatOptions) -> Cow<'_, st
     // Will be filled only if and when the first place that needs reformatting is detected.
     let mut cleaned_text = String::new();
 
+    // The exponent is only cleaned if there are no numeric separators, e.g. `1_0e+01` stays as is.
+    let mut has_separator = false;
+
     // Look at only the start of the text, ignore any sign, and make sure numbers always start with a digit. Add 0 if missing.
     if let Some((_, b'+' | b'-')) = curr {
         curr = iter.next();
@@ -96,6 +108,7 @@ fn format_trimmed_number(text: &str, options: NumberFormatOptions) -> Cow<'_, st
                 DecimalPart(FormatNumberLiteralDecimalPart {
                     dot_index,
                     last_non_zero_index: None,
+                    has_separator: false,
                 }),
                 (curr_index, Some(b'e') | None),
             ) => {
@@ -111,6 +124,7 @@ fn format_trimmed_number(text: &str, options: NumberFormatOptions) -> Cow<'_, st
             (
                 DecimalPart(FormatNumberLiteralDecimalPart {
                     last_non_zero_index: Some(last_non_zero_index),
+                    has_separator: false,
                     ..
                 }),
                 (curr_index, Some(b'e') | None),
@@ -124,7 +138,7 @@ fn format_trimmed_number(text: &str, options: NumberFormatOptions) -> Cow<'_, st
                     e_index, first_non_zero_index: None, ..
                 }),
                 (curr_index, None),
-            ) => {
+            ) if !has_separator => {
                 // The exponent equals zero, ignore it completely.
                 cleaned_text.push_str(&text[copied_or_ignored_chars..*e_index]);
                 copied_or_ignored_chars = curr_index;
@@ -137,8 +151,9 @@ fn format_trimmed_number(text: &str, options: NumberFormatOptions) -> Cow<'_, st
                     first_non_zero_index: Some(first_non_zero_index),
                 }),
                 (curr_index, None),
-            ) if (first_digit_index.get() > e_index + 1 && !is_negative)
-                || (first_non_zero_index.get() > first_digit_index.get()) =>
+            ) if !has_separator
+                && ((first_digit_index.get() > e_index + 1 && !is_negative)
+                    || (first_non_zero_index.get() > first_digit_index.get())) =>
             {
                 // The exponent begins with a plus or at least one zero, ignore them but copy the part from the first non-zero until the end.
                 cleaned_text.push_str(&text[copied_or_ignored_chars..=*e_index]);
@@ -158,8 +173,19 @@ fn format_trimmed_number(text: &str, options: NumberFormatOptions) -> Cow<'_, st
                 state = DecimalPart(FormatNumberLiteralDecimalPart {
                     dot_index: curr_index,
                     last_non_zero_index: None,
+                    has_separator: false,
                 });
             }
+            (DecimalPart(decimal_part), Some((_, b'_'))) => {
+                has_separator = true;
+                state = DecimalPart(FormatNumberLiteralDecimalPart {
+                    has_separator: true,
+                    ..*decimal_part
+                });
+            }
+            (_, Some((_, b'_'))) => {
+                has_separator = true;
+            }
             (DecimalPart(decimal_part), Some((curr_index, b'1'..=b'9'))) => {
                 state = DecimalPart(FormatNumberLiteralDecimalPart {
                     // SAFETY: We've already entered InDecimalPart, so curr_index must be >0
@@ -223,3 +249,83 @@ fn format_trimmed_number(text: &str, options: NumberFormatOptions) -> Cow<'_, st
         Cow::Owned(cleaned_text)
     }
 }
+
+/// Lowercases the prefix of a hexadecimal number like `0X`, keeping the case of its digits,
+/// or every letter of other numbers, i.e. the `0B` and `0O` prefixes and the exponent `E`.
+pub fn lowercase_number_letters(text: &str) -> Cow<'_, str> {
+    let digits = text.trim_start_matches(['+', '-']);
+    let prefix_index = text.len() - digits.len() + 1;
+    match digits.as_bytes() {
+        [b'0', b'x', ..] => Cow::Borrowed(text),
+        [b'0', b'X', ..] => {
+            let mut text = text.to_string();
+            text.replace_range(prefix_index..=prefix_index, "x");
+            Cow::Owned(text)
+        }
+        _ => text.cow_to_ascii_lowercase(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NumberFormatOptions, format_trimmed_number};
+
+    fn js(text: &str) -> String {
+        format_trimmed_number(text, NumberFormatOptions::keep_one_trailing_decimal_zero())
+            .into_owned()
+    }
+
+    #[test]
+    fn lowercase() {
+        assert_eq!(js("1E5"), "1e5");
+        assert_eq!(js("0XAB"), "0xAB");
+        assert_eq!(js("0xaB"), "0xaB");
+        assert_eq!(js("0B11"), "0b11");
+        assert_eq!(js("0O17"), "0o17");
+        assert_eq!(js("0XABn"), "0xABn");
+        assert_eq!(js("0XE5"), "0xE5");
+    }
+
+    #[test]
+    fn leading_and_trailing_dot() {
+        assert_eq!(js(".5"), "0.5");
+        assert_eq!(js("-.5"), "-0.5");
+        assert_eq!(js("1."), "1");
+        assert_eq!(js("5.e10"), "5e10");
+    }
+
+    #[test]
+    fn trailing_decimal_zeroes() {
+        assert_eq!(js("1.50"), "1.5");
+        assert_eq!(js("1.0"), "1.0");
+        assert_eq!(js("1.000"), "1.0");
+        assert_eq!(js("0.50e10"), "0.5e10");
+        assert_eq!(js("1.00e10"), "1.0e10");
+        assert_eq!(js("10"), "10");
+        assert_eq!(js("100.001"), "100.001");
+
+        assert_eq!(format_trimmed_number("1.000", NumberFormatOptions::default()), "1");
+    }
+
+    #[test]
+    fn exponent() {
+        assert_eq!(js("1e+5"), "1e5");
+        assert_eq!(js("1e02"), "1e2");
+        assert_eq!(js("1e-05"), "1e-5");
+        assert_eq!(js("1e+0"), "1");
+        assert_eq!(js("1e-00"), "1");
+        assert_eq!(js("1.5e-5"), "1.5e-5");
+        assert_eq!(js("0.50e+010"), "0.5e10");
+    }
+
+    #[test]
+    fn numeric_separators() {
+        assert_eq!(js("1_000_000"), "1_000_000");
+        assert_eq!(js("0xAB_CD"), "0xAB_CD");
+        assert_eq!(js("1_0.50"), "1_0.5");
+        assert_eq!(js("1_0.0_0"), "1_0.0_0");
+        assert_eq!(js("1.5_0"), "1.5_0");
+        assert_eq!(js("1_0e+01"), "1_0e+01");
+        assert_eq!(js("1_0."), "1_0");
+    }
+}
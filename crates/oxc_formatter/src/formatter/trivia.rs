@@ -129,13 +129,15 @@ impl<'a> Format<'a> for FormatLeadingComments<'a> {
                                     write!(f, [hard_line_break()]);
                                 }
                             }
-                            _ => write!(f, [empty_line()]),
+                            line_breaks => {
+                                write!(f, [empty_lines(f.source_empty_lines(line_breaks))]);
+                            }
                         }
                     }
-                    CommentKind::Line => match f.source_text().lines_after(comment.span.end) {
-                        0 | 1 => write!(f, [hard_line_break()]),
-                        _ => write!(f, [empty_line()]),
-                    },
+                    CommentKind::Line => {
+                        let line_breaks = f.source_text().lines_after(comment.span.end);
+                        write!(f, [empty_lines(f.source_empty_lines(line_breaks))]);
+                    }
                 }
             }
         }
@@ -229,8 +231,9 @@ impl<'a> Format<'a> for FormatTrailingComments<'a> {
                                         write!(f, [space()]);
                                     }
                                 }
-                                1 => write!(f, [hard_line_break()]),
-                                _ => write!(f, [empty_line()]),
+                                line_breaks => {
+                                    write!(f, [empty_lines(f.source_empty_lines(line_breaks))]);
+                                }
                             }
 
                             write!(f, [comment]);
@@ -421,7 +424,10 @@ impl<'a> Format<'a> for Comment {
         let content = f.source_text().text_for(&self.span);
         if self.is_multiline_block() {
             let mut lines = LineTerminatorSplitter::new(content);
-            if is_alignable_comment(content) {
+            // JSDoc comments are only re-indented with the `format_doc_comments` option.
+            let is_doc_comment = content.starts_with("/**");
+            if is_alignable_comment(content) && (!is_doc_comment || f.options().format_doc_comments)
+            {
                 // `unwrap` is safe because `content` contains at least one line.
                 let first_line = lines.next().unwrap();
                 write!(f, [text(first_line.trim_end())]);
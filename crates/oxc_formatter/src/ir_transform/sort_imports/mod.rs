@@ -70,7 +70,7 @@ impl SortImportsTransform {
         let mut current_line_start = 0;
         for (idx, el) in prev_elements.iter().enumerate() {
             if let FormatElement::Line(mode) = el
-                && matches!(mode, LineMode::Empty | LineMode::Hard)
+                && mode.will_break()
             {
                 // Flush current line
                 if current_line_start < idx {
@@ -83,7 +83,7 @@ impl SortImportsTransform {
                 current_line_start = idx + 1;
 
                 // We need this explicitly to detect boundaries later.
-                if matches!(mode, LineMode::Empty) {
+                if matches!(mode, LineMode::Empty | LineMode::EmptyLines(_)) {
                     lines.push(SourceLine::Empty);
                 }
             }
@@ -1,7 +1,13 @@
 use std::fmt;
 use std::str::FromStr;
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg(feature = "json_schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", default)]
 pub struct SortImportsOptions {
     /// Partition imports by newlines.
     /// Default is `false`.
@@ -49,7 +55,9 @@ impl Default for SortImportsOptions {
 
 // ---
 
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
 pub enum SortOrder {
     /// Sort in ascending order (A-Z).
     #[default]
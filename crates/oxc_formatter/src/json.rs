@@ -0,0 +1,71 @@
+use oxc_ast::ast::*;
+
+use crate::{FormatOptions, QuoteProperties, QuoteStyle, TrailingCommas};
+
+/// The syntax of a file formatted with [`crate::Formatter::format_json`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonKind {
+    /// Strict JSON like `package.json`, trailing commas are removed.
+    Json,
+    /// JSON with comments and trailing commas like `tsconfig.json`,
+    /// trailing commas are printed according to [`FormatOptions::trailing_commas`].
+    Jsonc,
+}
+
+/// Returns `options` adjusted to print valid JSON.
+pub fn json_options(mut options: FormatOptions, kind: JsonKind) -> FormatOptions {
+    options.quote_style = QuoteStyle::Double;
+    options.quote_properties = QuoteProperties::Preserve;
+    if kind == JsonKind::Json {
+        options.trailing_commas = TrailingCommas::None;
+    }
+    options.experimental_sort_imports = None;
+    options
+}
+
+/// Wraps a JSON value in parentheses, so it's parsed as an expression
+/// and objects aren't mistaken for blocks.
+pub fn wrap_json(source_text: &str) -> String {
+    // The line break ends a trailing line comment
+    format!("({source_text}\n)")
+}
+
+/// Returns the JSON value of a program parsed from [`wrap_json`], if it is one.
+pub fn json_value<'a, 'b>(program: &'b Program<'a>) -> Option<&'b Expression<'a>> {
+    match program.body.as_slice() {
+        [Statement::ExpressionStatement(statement)] if program.directives.is_empty() => {
+            is_json_value(&statement.expression).then_some(&statement.expression)
+        }
+        _ => None,
+    }
+}
+
+fn is_json_value(expression: &Expression) -> bool {
+    match expression {
+        Expression::ObjectExpression(object) => object.properties.iter().all(|property| {
+            matches!(property, ObjectPropertyKind::ObjectProperty(property)
+                if property.kind == PropertyKind::Init
+                    && !property.method
+                    && !property.shorthand
+                    && !property.computed
+                    && matches!(&property.key, PropertyKey::StringLiteral(key) if is_json_string(key))
+                    && is_json_value(&property.value))
+        }),
+        Expression::ArrayExpression(array) => array.elements.iter().all(|element| {
+            element.as_expression().is_some_and(is_json_value)
+        }),
+        Expression::StringLiteral(string) => is_json_string(string),
+        Expression::UnaryExpression(unary) => {
+            unary.operator == UnaryOperator::UnaryNegation
+                && matches!(unary.argument, Expression::NumericLiteral(_))
+        }
+        Expression::NumericLiteral(_)
+        | Expression::BooleanLiteral(_)
+        | Expression::NullLiteral(_) => true,
+        _ => false,
+    }
+}
+
+fn is_json_string(string: &StringLiteral) -> bool {
+    string.raw.is_some_and(|raw| raw.starts_with('"'))
+}
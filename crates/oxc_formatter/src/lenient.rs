@@ -0,0 +1,203 @@
+//! Best-effort formatting of source texts with syntax errors, see [`crate::Formatter::format_lenient`].
+//!
+//! The lines around every syntax error are masked until the remaining source text parses.
+//! The masked lines are then replaced with placeholder comments, which end up on their own
+//! lines in the formatted code, and the placeholders are replaced with the original lines.
+
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_span::{SourceType, Span};
+
+use crate::get_parse_options;
+
+/// The source text of a line, without its line terminator.
+#[derive(Debug, Clone, Copy)]
+struct Line {
+    start: usize,
+    end: usize,
+}
+
+/// An inclusive range of lines that can't be parsed.
+#[derive(Debug, Clone, Copy)]
+struct Skipped {
+    first: usize,
+    last: usize,
+}
+
+/// Returns the spans of the lines that have to be skipped for the rest of `source_text` to parse,
+/// sorted and not overlapping. Returns an empty list if `source_text` has no syntax errors.
+pub fn skipped_spans(source_text: &str, source_type: SourceType) -> Vec<Span> {
+    // Every attempt parses the whole source text, so the allocator is reset after each of them.
+    let allocator = &mut Allocator::default();
+    let lines = lines(source_text);
+    let mut skipped: Vec<Skipped> = Vec::new();
+
+    while let Some(error) =
+        first_error(allocator, &mask(source_text, &lines, &skipped, None), source_type)
+    {
+        let error_line = lines.partition_point(|line| line.end < error).min(lines.len() - 1);
+        let mut range =
+            find_skipped(allocator, source_text, source_type, &lines, &skipped, error_line);
+        skipped.retain(|other| {
+            let overlaps = other.last >= range.first && other.first <= range.last;
+            if overlaps {
+                range.first = range.first.min(other.first);
+                range.last = range.last.max(other.last);
+            }
+            !overlaps
+        });
+        skipped.push(range);
+        skipped.sort_unstable_by_key(|range| range.first);
+    }
+
+    skipped
+        .iter()
+        .map(|range| {
+            #[expect(clippy::cast_possible_truncation)]
+            Span::new(lines[range.first].start as u32, lines[range.last].end as u32)
+        })
+        .collect()
+}
+
+/// The number of ranges [`find_skipped`] tries before giving up on localizing an error.
+/// Every attempt parses the whole source text.
+const MAX_ATTEMPTS: usize = 128;
+
+/// Finds the smallest range of lines around `error_line` that makes the error disappear:
+/// grows both ends of the range together, preferring to grow down.
+///
+/// Falls back to skipping from `error_line` to the end of the file, or the whole file,
+/// after [`MAX_ATTEMPTS`] ranges.
+fn find_skipped(
+    allocator: &mut Allocator,
+    source_text: &str,
+    source_type: SourceType,
+    lines: &[Line],
+    skipped: &[Skipped],
+    error_line: usize,
+) -> Skipped {
+    let mut fixes_error = |range: Skipped| {
+        let masked = mask(source_text, lines, skipped, Some(range));
+        first_error(allocator, &masked, source_type)
+            .is_none_or(|error| error > lines[range.last].end)
+    };
+
+    let ranges = (0..lines.len()).flat_map(|size| {
+        (0..=size.min(error_line))
+            .map(move |up| Skipped { first: error_line - up, last: error_line + size - up })
+            .filter(|range| range.last < lines.len())
+    });
+    for range in ranges.take(MAX_ATTEMPTS) {
+        if fixes_error(range) {
+            return range;
+        }
+    }
+
+    let to_end = Skipped { first: error_line, last: lines.len() - 1 };
+    if fixes_error(to_end) {
+        return to_end;
+    }
+    Skipped { first: 0, last: lines.len() - 1 }
+}
+
+/// Returns the offset of the first syntax error in `source_text`.
+fn first_error(
+    allocator: &mut Allocator,
+    source_text: &str,
+    source_type: SourceType,
+) -> Option<usize> {
+    let ret =
+        Parser::new(allocator, source_text, source_type).with_options(get_parse_options()).parse();
+    let first_error = ret
+        .errors
+        .iter()
+        .map(|error| {
+            error
+                .labels
+                .as_ref()
+                .and_then(|labels| labels.iter().map(|label| label.inner().offset()).min())
+                .unwrap_or(source_text.len())
+        })
+        .min();
+    allocator.reset();
+    first_error
+}
+
+/// Replaces the `skipped` lines and `extra` with whitespace, keeping all offsets and line breaks.
+fn mask(source_text: &str, lines: &[Line], skipped: &[Skipped], extra: Option<Skipped>) -> String {
+    let mut bytes = source_text.as_bytes().to_vec();
+    for range in skipped.iter().chain(extra.as_ref()) {
+        for byte in &mut bytes[lines[range.first].start..lines[range.last].end] {
+            if !matches!(byte, b'\n' | b'\r') {
+                *byte = b' ';
+            }
+        }
+    }
+    // Whole lines are replaced, so multi-byte characters are either kept or replaced entirely.
+    String::from_utf8(bytes).unwrap()
+}
+
+fn lines(source_text: &str) -> Vec<Line> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    for (index, _) in source_text.match_indices('\n') {
+        let end = if source_text[..index].ends_with('\r') { index - 1 } else { index };
+        lines.push(Line { start, end });
+        start = index + 1;
+    }
+    lines.push(Line { start, end: source_text.len() });
+    lines
+}
+
+/// Returns the placeholder comment replacing the skipped span at `index`.
+pub fn placeholder(index: usize) -> String {
+    format!("/*oxfmt-skipped-{index}*/")
+}
+
+/// Replaces the skipped spans of `source_text` with [`placeholder`] comments.
+pub fn replace_with_placeholders(source_text: &str, skipped: &[Span]) -> String {
+    let mut masked = String::with_capacity(source_text.len());
+    let mut end = 0;
+    for (index, span) in skipped.iter().enumerate() {
+        masked.push_str(&source_text[end..span.start as usize]);
+        masked.push_str(&placeholder(index));
+        end = span.end as usize;
+    }
+    masked.push_str(&source_text[end..]);
+    masked
+}
+
+/// Replaces the [`placeholder`] comments in the `formatted` code with the skipped source text.
+///
+/// Returns `None` if a placeholder isn't printed exactly once and on its own line.
+pub fn restore_placeholders(
+    formatted: &str,
+    source_text: &str,
+    skipped: &[Span],
+) -> Option<String> {
+    let mut restored = String::with_capacity(formatted.len());
+    let mut end = 0;
+    for (index, span) in skipped.iter().enumerate() {
+        let placeholder = placeholder(index);
+        let mut matches = formatted.match_indices(&placeholder);
+        let (start, _) = matches.next()?;
+        if matches.next().is_some() || start < end {
+            return None;
+        }
+
+        let line_start = formatted[..start].rfind('\n').map_or(0, |index| index + 1);
+        let placeholder_end = start + placeholder.len();
+        let after = &formatted[placeholder_end..];
+        if !formatted[line_start..start].trim().is_empty()
+            || !(after.is_empty() || after.starts_with('\n') || after.starts_with("\r\n"))
+        {
+            return None;
+        }
+
+        restored.push_str(&formatted[end..line_start]);
+        restored.push_str(&source_text[span.start as usize..span.end as usize]);
+        end = placeholder_end;
+    }
+    restored.push_str(&formatted[end..]);
+    Some(restored)
+}
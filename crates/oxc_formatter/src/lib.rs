@@ -4,24 +4,54 @@ mod ast_nodes;
 #[cfg(feature = "detect_code_removal")]
 mod detect_code_removal;
 mod embedded_formatter;
+mod format_range;
 mod formatter;
 mod ir_transform;
+mod json;
+mod lenient;
 mod options;
 mod parentheses;
+mod pragma;
+pub mod prelude;
 mod service;
+pub mod testing;
+mod text_edit;
 mod utils;
 mod write;
 
-use oxc_allocator::Allocator;
+use std::{fmt, sync::Arc};
+
+use oxc_allocator::{Allocator, Vec as ArenaVec};
 use oxc_ast::ast::*;
+use oxc_parser::Parser;
+use oxc_span::{SourceType, Span};
 
-pub use crate::embedded_formatter::{EmbeddedFormatter, EmbeddedFormatterCallback};
+pub use crate::embedded_formatter::{
+    EmbeddedFormatter, EmbeddedFormatterCallback, EmbeddedLanguageFormatter,
+};
+pub use crate::formatter::{FormatError, InvalidDocumentError, SourceMarker};
 pub use crate::ir_transform::options::*;
+pub use crate::json::JsonKind;
 pub use crate::options::*;
-pub use crate::service::{oxfmtrc::OxfmtOptions, oxfmtrc::Oxfmtrc, parse_utils::*};
+#[cfg(feature = "rayon")]
+pub use crate::service::format_files::*;
+pub use crate::service::{
+    editorconfig::*,
+    format_config::*,
+    format_source::{FormatSourceResult, format_source, format_source_with_allocator},
+    oxfmtrc::OxfmtOptions,
+    oxfmtrc::Oxfmtrc,
+    parse_utils::*,
+};
+pub use crate::text_edit::TextEdit;
 use crate::{
     ast_nodes::{AstNode, AstNodes},
-    formatter::{FormatContext, Formatted},
+    format_range::source_indent_level,
+    formatter::{
+        FormatContext, FormatElement, Formatted, Printed,
+        format_element::{TextWidth, document::Document},
+        prelude::tag::LabelId,
+    },
     ir_transform::SortImportsTransform,
 };
 #[cfg(feature = "detect_code_removal")]
@@ -33,19 +63,68 @@ pub struct Formatter<'a> {
     allocator: &'a Allocator,
     source_text: &'a str,
     options: FormatOptions,
+    source_map: bool,
+    embedded_languages: Option<Arc<dyn EmbeddedLanguageFormatter>>,
 }
 
 impl<'a> Formatter<'a> {
     pub fn new(allocator: &'a Allocator, options: FormatOptions) -> Self {
-        Self { allocator, source_text: "", options }
+        Self { allocator, source_text: "", options, source_map: false, embedded_languages: None }
+    }
+
+    /// Records where identifiers, literals and other tokens of the source text are printed.
+    ///
+    /// The mappings are returned by [`Printed::source_markers`] when printing the
+    /// [`Formatted`] result, each maps an offset of the printed code to an offset of the source text.
+    #[must_use]
+    pub fn with_source_map(mut self) -> Self {
+        self.source_map = true;
+        self
+    }
+
+    /// Formats the code of languages embedded in template literals, like CSS or GraphQL,
+    /// with `embedded_formatter`. Without one, only their indentation is fixed.
+    #[must_use]
+    pub fn with_embedded_language_formatter(
+        mut self,
+        embedded_formatter: Arc<dyn EmbeddedLanguageFormatter>,
+    ) -> Self {
+        self.embedded_languages = Some(embedded_formatter);
+        self
     }
 
     /// Formats the given AST `Program` and returns the formatted string.
+    ///
+    /// Nodes the formatter doesn't support are printed verbatim, see [`Formatter::try_build`].
     pub fn build(self, program: &Program<'a>) -> String {
         let formatted = self.format(program);
         formatted.print().unwrap().into_code()
     }
 
+    /// Formats the given AST `Program` like [`Formatter::build`],
+    /// but fails instead of printing unsupported nodes verbatim.
+    ///
+    /// # Errors
+    /// Returns [`FormatError::UnsupportedSyntax`] for the first node the formatter doesn't support,
+    /// e.g. a `ParenthesizedExpression` of a program parsed with `preserve_parens`,
+    /// or [`FormatError::InvalidDocument`] if the formatted document can't be printed.
+    pub fn try_build(self, program: &Program<'a>) -> Result<String, FormatError> {
+        let formatted = self.format(program);
+        if let Some(error) = formatted.context().unsupported_syntax() {
+            return Err(error);
+        }
+        Ok(formatted.print()?.into_code())
+    }
+
+    /// Formats the given AST `Program` and returns its intermediate representation instead of the code,
+    /// e.g. to investigate layout bugs.
+    ///
+    /// The elements are printed as nested builder calls, with the ids of groups and whether they are
+    /// expanded, like `group(expand: propagated, ["a", hard_line_break])`.
+    pub fn format_ir(self, program: &'a Program<'a>) -> String {
+        self.format(program).document().to_string()
+    }
+
     #[inline]
     pub fn format(self, program: &'a Program<'a>) -> Formatted<'a> {
         self.format_impl(program, None)
@@ -60,41 +139,250 @@ impl<'a> Formatter<'a> {
         self.format_impl(program, Some(embedded_formatter))
     }
 
+    /// Checks whether the given AST `Program` is already formatted, without building the formatted string.
+    ///
+    /// Printing stops at the first difference to the source text.
+    ///
+    /// # Errors
+    /// Returns [`FormatError::InvalidDocument`] if the formatted document can't be printed.
+    pub fn check(self, program: &'a Program<'a>) -> Result<CheckResult, FormatError> {
+        let formatted = self.format(program);
+        let source_text = program.source_text;
+
+        let Some(mut offset) = formatted.check(source_text)? else {
+            return Ok(CheckResult::Formatted);
+        };
+        while !source_text.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        Ok(CheckResult::Unformatted { offset: u32::try_from(offset).unwrap() })
+    }
+
+    /// Formats the given AST `Program` into `writer`, without building the formatted string.
+    ///
+    /// The formatted code is written in chunks while printing, which keeps the memory usage low
+    /// for large files and allows e.g. hashing the formatted code without allocating it.
+    ///
+    /// # Errors
+    /// Returns the error of `writer` if writing failed.
+    pub fn format_into(
+        self,
+        program: &'a Program<'a>,
+        writer: &mut impl fmt::Write,
+    ) -> Result<FormatIntoResult, fmt::Error> {
+        let formatted = self.format(program);
+        let mut writer =
+            CompareWriter { writer, expected: program.source_text, written: 0, changed: false };
+        formatted.print_into(&mut writer).unwrap()?;
+
+        Ok(FormatIntoResult {
+            written: writer.written,
+            changed: writer.changed || writer.written != program.source_text.len(),
+        })
+    }
+
+    /// Formats the given AST `Program` and returns the edits that turn its source text into the formatted code.
+    ///
+    /// Unlike replacing the whole source text with [`Self::build`], only the changed parts are replaced,
+    /// which e.g. keeps the cursor position and folded ranges of an editor intact.
+    /// The edits are sorted by span and don't overlap, applying them all reproduces the formatted code.
+    pub fn format_to_edits(self, program: &'a Program<'a>) -> Vec<TextEdit> {
+        let formatted = self.build(program);
+        text_edit::text_edits(program.source_text, &formatted)
+    }
+
+    /// Formats `source_text` even if it has syntax errors.
+    ///
+    /// The lines around every syntax error are copied verbatim and the rest of the source text is
+    /// formatted as usual, as long as it parses without these lines.
+    /// Returns the whole source text verbatim if the formatted code can't be stitched together.
+    pub fn format_lenient(self, source_text: &'a str, source_type: SourceType) -> LenientResult {
+        let allocator = self.allocator;
+        let skipped = lenient::skipped_spans(source_text, source_type);
+        let masked =
+            allocator.alloc_str(&lenient::replace_with_placeholders(source_text, &skipped));
+
+        let ret = Parser::new(allocator, masked, source_type)
+            .with_options(get_parse_options_with(&self.options))
+            .parse();
+        if ret.errors.is_empty() {
+            let program = allocator.alloc(ret.program);
+            let formatted = self.build(program);
+            if let Some(code) = lenient::restore_placeholders(&formatted, source_text, &skipped) {
+                return LenientResult { code, skipped };
+            }
+        }
+
+        #[expect(clippy::cast_possible_truncation)]
+        let span = Span::new(0, source_text.len() as u32);
+        LenientResult { code: source_text.to_string(), skipped: vec![span] }
+    }
+
+    /// Formats `source_text` as a JSON value, with the same rules as objects and arrays in JavaScript.
+    ///
+    /// Keys and strings are always double-quoted and printed verbatim like numbers, and the order of
+    /// keys is kept. Comments are kept as well, trailing commas are removed from [`JsonKind::Json`].
+    ///
+    /// # Errors
+    /// Returns the syntax errors if `source_text` isn't a single JSON value.
+    pub fn format_json(mut self, source_text: &str, kind: JsonKind) -> Result<String, String> {
+        let allocator = self.allocator;
+        let wrapped = allocator.alloc_str(&json::wrap_json(source_text));
+        let ret = Parser::new(allocator, wrapped, SourceType::mjs())
+            .with_options(get_parse_options())
+            .parse();
+        if !ret.errors.is_empty() {
+            let errors = ret.errors.iter().map(ToString::to_string).collect::<Vec<_>>();
+            return Err(errors.join("\n"));
+        }
+        let program = allocator.alloc(ret.program);
+        if json::json_value(program).is_none() {
+            return Err("Expected a single JSON value".to_string());
+        }
+
+        self.options = json::json_options(self.options, kind);
+        let context = self.create_context(program).with_json();
+        let formatted = Self::format_program(program, context, allocator);
+        Ok(formatted.print().map_err(|err| err.to_string())?.into_code())
+    }
+
+    /// Formats only the statements (or class members) overlapping `range`.
+    ///
+    /// The range is expanded to the innermost list of statements covering it, and to whole lines.
+    /// Returns the formatted code without the indentation of its first line, and the span of the
+    /// source text it replaces as [`Printed::range`]. The following lines are indented relative
+    /// to the indentation of the first line in the source text, so the code outside the span
+    /// stays untouched.
+    /// Returns `None` if there is nothing to format, e.g. when the range is inside a template literal.
+    pub fn format_range(self, program: &'a Program<'a>, range: Span) -> Option<Printed> {
+        let allocator = self.allocator;
+        let indent_width = self.options.indent_width;
+        let context = self.create_context(program).with_format_range(range);
+        let formatted = Self::format_program(program, context, allocator);
+
+        let span = formatted.context().format_range()?.formatted_span()?;
+        let indent = source_indent_level(program.source_text, span.start, indent_width);
+        let code = formatted.print_labelled(LabelId::of(JsLabels::FormatRange), indent).ok()??;
+
+        Some(Printed::new(code, Some(span)))
+    }
+
     pub fn format_impl(
         mut self,
         program: &'a Program<'a>,
         embedded_formatter: Option<EmbeddedFormatter>,
     ) -> Formatted<'a> {
-        let parent = self.allocator.alloc(AstNodes::Dummy());
-        let program_node = AstNode::new(program, parent, self.allocator);
-
-        let source_text = program.source_text;
-        self.source_text = source_text;
+        self.source_text = program.source_text;
+        if self.options.require_pragma && !pragma::has_pragma(program) {
+            return self.format_unchanged(program);
+        }
+        if let Some(embedded_formatter) = embedded_formatter {
+            self.embedded_languages = Some(Arc::new(embedded_formatter));
+        }
 
+        let allocator = self.allocator;
         let experimental_sort_imports = self.options.experimental_sort_imports.clone();
+        let context = self.create_context(program);
+        let mut formatted = Self::format_program(program, context, allocator);
+
+        // Basic formatting and `document.propagate_expand()` are already done here.
+        // Now apply additional transforms if enabled.
+        if let Some(sort_imports_options) = experimental_sort_imports {
+            let sort_imports = SortImportsTransform::new(sort_imports_options);
+            formatted.apply_transform(|doc| sort_imports.transform(doc, allocator));
+        }
+
+        formatted
+    }
+
+    fn create_context(self, program: &'a Program<'a>) -> FormatContext<'a> {
+        let mut options = self.options;
+        options.line_ending = options.line_ending.resolve(program.source_text);
 
         let context = FormatContext::new(
             program.source_text,
             program.source_type,
             &program.comments,
             self.allocator,
-            self.options,
-            embedded_formatter,
+            options,
+            self.embedded_languages,
         );
+        if self.source_map { context.with_source_map() } else { context }
+    }
+
+    /// Returns a document printing the source text of `program` as it is.
+    fn format_unchanged(mut self, program: &'a Program<'a>) -> Formatted<'a> {
+        // `\r` is printed like any other character, so line feeds mustn't be replaced.
+        self.options.line_ending = LineEnding::Lf;
+        let indent_width = self.options.indent_width;
+        let allocator = self.allocator;
+        let context = self.create_context(program);
+
+        let text = program.source_text;
+        let width = TextWidth::from_text(text, indent_width);
+        let elements = ArenaVec::from_iter_in([FormatElement::Text { text, width }], allocator);
+        Formatted::new(Document::from(elements), context)
+    }
 
-        let mut formatted = formatter::format(
+    fn format_program(
+        program: &'a Program<'a>,
+        context: FormatContext<'a>,
+        allocator: &'a Allocator,
+    ) -> Formatted<'a> {
+        let parent = allocator.alloc(AstNodes::Dummy());
+        let program_node = AstNode::new(program, parent, allocator);
+
+        formatter::format(
             context,
             formatter::Arguments::new(&[formatter::Argument::new(&program_node)]),
-        );
+        )
+    }
+}
 
-        // Basic formatting and `document.propagate_expand()` are already done here.
-        // Now apply additional transforms if enabled.
-        if let Some(sort_imports_options) = experimental_sort_imports {
-            let sort_imports = SortImportsTransform::new(sort_imports_options);
-            formatted.apply_transform(|doc| sort_imports.transform(doc, self.allocator));
-        }
+/// The result of [`Formatter::check`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckResult {
+    /// The source text is already formatted.
+    Formatted,
+    /// The source text isn't formatted, the formatted code first differs from it at byte `offset`.
+    Unformatted { offset: u32 },
+}
 
-        formatted
+/// The result of [`Formatter::format_lenient`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LenientResult {
+    /// The formatted code.
+    pub code: String,
+    /// The spans of the source text that couldn't be formatted and are copied verbatim,
+    /// sorted and not overlapping.
+    pub skipped: Vec<Span>,
+}
+
+/// The result of [`Formatter::format_into`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormatIntoResult {
+    /// The number of bytes written.
+    pub written: usize,
+    /// Whether the written code differs from the source text.
+    pub changed: bool,
+}
+
+/// Forwards the written code to `writer` while comparing it against `expected`.
+struct CompareWriter<'w, W> {
+    writer: &'w mut W,
+    expected: &'w str,
+    written: usize,
+    changed: bool,
+}
+
+impl<W: fmt::Write> fmt::Write for CompareWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.written + s.len();
+        if !self.changed && self.expected.as_bytes().get(self.written..end) != Some(s.as_bytes()) {
+            self.changed = true;
+        }
+        self.written = end;
+        self.writer.write_str(s)
     }
 }
 
@@ -103,6 +391,8 @@ pub(crate) enum JsLabels {
     MemberChain,
     /// For `ir_transform/sort_imports`
     ImportDeclaration,
+    /// For [`Formatter::format_range`]
+    FormatRange,
 }
 
 impl Label for JsLabels {
@@ -114,6 +404,7 @@ impl Label for JsLabels {
         match self {
             Self::MemberChain => "MemberChain",
             Self::ImportDeclaration => "ImportDeclaration",
+            Self::FormatRange => "FormatRange",
         }
     }
 }
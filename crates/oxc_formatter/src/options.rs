@@ -1,5 +1,9 @@
 use std::{fmt, num::ParseIntError, str::FromStr};
 
+#[cfg(feature = "json_schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error as _};
+
 pub use crate::formatter::{Buffer, Format, FormatResult, token::string::Quote};
 use crate::{
     formatter::{
@@ -11,7 +15,17 @@ use crate::{
     write,
 };
 
-#[derive(Debug, Default, Clone)]
+/// The options of the formatter.
+///
+/// Serialized with camelCase keys and kebab-case values, e.g. `{ "quoteProperties": "as-needed" }`.
+/// Missing keys are filled in with their defaults, unknown keys are rejected.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "json_schema",
+    derive(JsonSchema),
+    schemars(rename = "FormatOptions", deny_unknown_fields)
+)]
+#[serde(remote = "Self", rename_all = "camelCase", default)]
 pub struct FormatOptions {
     /// The indent style.
     pub indent_style: IndentStyle,
@@ -55,25 +69,62 @@ pub struct FormatOptions {
     /// Whether to expand object and array literals to multiple lines. Defaults to "auto".
     pub expand: Expand,
 
-    /// Controls the position of operators in binary expressions. [**NOT SUPPORTED YET**]
+    /// The maximum number of consecutive empty lines kept between statements, class members
+    /// and comments. Empty lines at the start and end of blocks are always removed. Defaults to 1.
+    pub max_empty_lines: u8,
+
+    /// The number of calls from which a member chain like `a.b(x => x).c().d()` breaks with one call
+    /// per line if any of its calls has arguments other than literals, identifiers or short objects
+    /// and arrays. Shorter chains only break if they don't fit on a line. Defaults to 3.
+    pub member_chain_min_calls: u8,
+
+    /// Whether a single decorator of a class member or parameter stays on the same line as the
+    /// member, even if it's followed by a line break in the source. Decorators that don't fit on
+    /// the line and class decorators still break. Defaults to false.
+    pub decorators_same_line: bool,
+
+    /// Whether parentheses written in the source are kept, even where they aren't needed.
+    /// Only has an effect on programs parsed with `preserve_parens`, see
+    /// [`get_parse_options_with`](crate::get_parse_options_with). Defaults to false.
+    pub preserve_parens: bool,
+
+    /// Whether JSDoc comments (`/** ... */`) whose lines all start with `*` are re-indented to the
+    /// node they are attached to, with the `*` of each line aligned under the opening `/**` and
+    /// trailing whitespace removed. The text is never re-wrapped. Other comments are printed as
+    /// written. Defaults to false.
+    pub format_doc_comments: bool,
+
+    /// Controls the position of operators in binary and logical expressions that break over
+    /// multiple lines.
     ///
     /// Accepted values are:
-    /// - `"start"`: Places the operator at the beginning of the next line.
+    /// - `"start"`: Places the operator at the beginning of the next line, before the right
+    ///   operand. Comments after an operand stay on the operand's line.
     /// - `"end"`: Places the operator at the end of the current line (default).
     pub experimental_operator_position: OperatorPosition,
 
-    /// Try prettier's new ternary formatting before it becomes the default behavior. [**NOT SUPPORTED YET**]
+    /// Try prettier's new ternary formatting before it becomes the default behavior.
     ///
     /// Valid options:
     /// - `true` - Use curious ternaries, with the question mark after the condition.
     /// - `false` - Retain the default behavior of ternaries; keep question marks on the same line as the consequent.
     pub experimental_ternaries: bool,
 
-    /// Enable formatting for embedded languages (e.g., CSS, SQL, GraphQL) within template literals. Defaults to "auto".
+    /// Enable formatting for embedded languages (e.g., CSS, SQL, GraphQL) within template literals. Defaults to "off".
     pub embedded_language_formatting: EmbeddedLanguageFormatting,
 
     /// Sort import statements. By default disabled.
     pub experimental_sort_imports: Option<SortImportsOptions>,
+
+    /// Only format files whose first docblock contains a `@format` or `@prettier` pragma,
+    /// other files are returned unchanged. Defaults to false.
+    pub require_pragma: bool,
+
+    /// Insert a `/** @format */` pragma at the start of formatted files without one. Defaults to false.
+    pub insert_pragma: bool,
+
+    /// Whether the formatted code ends with a line break. Defaults to true.
+    pub insert_final_newline: bool,
 }
 
 impl FormatOptions {
@@ -93,10 +144,18 @@ impl FormatOptions {
             bracket_same_line: BracketSameLine::default(),
             attribute_position: AttributePosition::default(),
             expand: Expand::default(),
+            max_empty_lines: 1,
+            member_chain_min_calls: 3,
+            decorators_same_line: false,
+            preserve_parens: false,
+            format_doc_comments: false,
             experimental_operator_position: OperatorPosition::default(),
             experimental_ternaries: false,
             embedded_language_formatting: EmbeddedLanguageFormatting::default(),
             experimental_sort_imports: None,
+            require_pragma: false,
+            insert_pragma: false,
+            insert_final_newline: true,
         }
     }
 
@@ -105,6 +164,91 @@ impl FormatOptions {
     }
 }
 
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FormatOptions {
+    /// The keys of the serialized options.
+    const KEYS: [&'static str; 26] = [
+        "indentStyle",
+        "indentWidth",
+        "lineEnding",
+        "lineWidth",
+        "quoteStyle",
+        "jsxQuoteStyle",
+        "quoteProperties",
+        "trailingCommas",
+        "semicolons",
+        "arrowParentheses",
+        "bracketSpacing",
+        "bracketSameLine",
+        "attributePosition",
+        "expand",
+        "maxEmptyLines",
+        "memberChainMinCalls",
+        "decoratorsSameLine",
+        "preserveParens",
+        "formatDocComments",
+        "experimentalOperatorPosition",
+        "experimentalTernaries",
+        "embeddedLanguageFormatting",
+        "experimentalSortImports",
+        "requirePragma",
+        "insertPragma",
+        "insertFinalNewline",
+    ];
+
+    /// Returns the JSON Schema of the serialized options, so editors can offer completion for config files.
+    ///
+    /// # Panics
+    /// Panics if the schema can't be serialized.
+    #[cfg(feature = "json_schema")]
+    pub fn json_schema() -> String {
+        let schema = schemars::schema_for!(FormatOptions);
+        serde_json::to_string_pretty(&schema).unwrap()
+    }
+}
+
+impl Serialize for FormatOptions {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        FormatOptions::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for FormatOptions {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        if let serde_json::Value::Object(options) = &value
+            && let Some(key) = options.keys().find(|key| !Self::KEYS.contains(&key.as_str()))
+        {
+            let closest = Self::KEYS.iter().min_by_key(|valid| edit_distance(key, valid)).unwrap();
+            return Err(D::Error::custom(format!(
+                "unknown option `{key}`, did you mean `{closest}`?"
+            )));
+        }
+        FormatOptions::deserialize(value).map_err(D::Error::custom)
+    }
+}
+
+/// Returns the number of single character insertions, deletions and substitutions to turn `a` into `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, a) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(a != *b);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
 impl fmt::Display for FormatOptions {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "Indent style: {}", self.indent_style)?;
@@ -121,13 +265,24 @@ impl fmt::Display for FormatOptions {
         writeln!(f, "Bracket same line: {}", self.bracket_same_line.value())?;
         writeln!(f, "Attribute Position: {}", self.attribute_position)?;
         writeln!(f, "Expand lists: {}", self.expand)?;
+        writeln!(f, "Max empty lines: {}", self.max_empty_lines)?;
+        writeln!(f, "Member chain min calls: {}", self.member_chain_min_calls)?;
+        writeln!(f, "Decorators same line: {}", self.decorators_same_line)?;
+        writeln!(f, "Preserve parens: {}", self.preserve_parens)?;
+        writeln!(f, "Format doc comments: {}", self.format_doc_comments)?;
         writeln!(f, "Experimental operator position: {}", self.experimental_operator_position)?;
+        writeln!(f, "Experimental ternaries: {}", self.experimental_ternaries)?;
         writeln!(f, "Embedded language formatting: {}", self.embedded_language_formatting)?;
-        writeln!(f, "Experimental sort imports: {:?}", self.experimental_sort_imports)
+        writeln!(f, "Experimental sort imports: {:?}", self.experimental_sort_imports)?;
+        writeln!(f, "Require pragma: {}", self.require_pragma)?;
+        writeln!(f, "Insert pragma: {}", self.insert_pragma)?;
+        writeln!(f, "Insert final newline: {}", self.insert_final_newline)
     }
 }
 
-#[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq)]
+#[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
 pub enum IndentStyle {
     /// Tab
     Tab,
@@ -173,7 +328,9 @@ impl fmt::Display for IndentStyle {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Default)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
 pub enum LineEnding {
     ///  Line Feed only (\n), common on Linux and macOS as well as inside git repos
     #[default]
@@ -182,18 +339,53 @@ pub enum LineEnding {
     Crlf,
     /// Carriage Return character only (\r), used very rarely
     Cr,
+    /// The line ending used the most in the source text, see [LineEnding::detect]
+    Auto,
 }
 
 impl LineEnding {
+    /// Returns the bytes of the line ending.
+    ///
+    /// [LineEnding::Auto] is resolved against the source text before printing,
+    /// it prints line feeds if it isn't.
     #[inline]
     pub const fn as_bytes(self) -> &'static [u8] {
         match self {
-            LineEnding::Lf => b"\n",
+            LineEnding::Lf | LineEnding::Auto => b"\n",
             LineEnding::Crlf => b"\r\n",
             LineEnding::Cr => b"\r",
         }
     }
 
+    /// Returns the line ending used the most in `source_text`,
+    /// or [LineEnding::Lf] if it has no line breaks.
+    pub fn detect(source_text: &str) -> Self {
+        let (mut lf, mut crlf, mut cr) = (0usize, 0usize, 0usize);
+        let mut bytes = source_text.bytes().peekable();
+        while let Some(byte) = bytes.next() {
+            match byte {
+                b'\n' => lf += 1,
+                b'\r' if bytes.next_if_eq(&b'\n').is_some() => crlf += 1,
+                b'\r' => cr += 1,
+                _ => {}
+            }
+        }
+
+        if crlf > lf && crlf >= cr {
+            LineEnding::Crlf
+        } else if cr > lf && cr > crlf {
+            LineEnding::Cr
+        } else {
+            LineEnding::Lf
+        }
+    }
+
+    /// Returns the line ending detected in `source_text` if this is [LineEnding::Auto], or `self` otherwise.
+    #[must_use]
+    pub fn resolve(self, source_text: &str) -> Self {
+        if self.is_auto() { Self::detect(source_text) } else { self }
+    }
+
     /// Returns `true` if this is a [LineEnding::Lf].
     pub const fn is_line_feed(self) -> bool {
         matches!(self, LineEnding::Lf)
@@ -208,6 +400,11 @@ impl LineEnding {
     pub const fn is_carriage_return(self) -> bool {
         matches!(self, LineEnding::Cr)
     }
+
+    /// Returns `true` if this is a [LineEnding::Auto].
+    pub const fn is_auto(self) -> bool {
+        matches!(self, LineEnding::Auto)
+    }
 }
 
 impl FromStr for LineEnding {
@@ -218,6 +415,7 @@ impl FromStr for LineEnding {
             "lf" => Ok(Self::Lf),
             "crlf" => Ok(Self::Crlf),
             "cr" => Ok(Self::Cr),
+            "auto" => Ok(Self::Auto),
             _ => Err("Value not supported for LineEnding"),
         }
     }
@@ -229,12 +427,15 @@ impl fmt::Display for LineEnding {
             LineEnding::Lf => "LF",
             LineEnding::Crlf => "CRLF",
             LineEnding::Cr => "CR",
+            LineEnding::Auto => "Auto",
         };
         f.write_str(s)
     }
 }
 
-#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema), schemars(transparent))]
+#[serde(try_from = "u8", into = "u8")]
 pub struct IndentWidth(u8);
 
 impl IndentWidth {
@@ -291,7 +492,9 @@ impl fmt::Debug for IndentWidth {
 /// Validated value for the `line_width` formatter options
 ///
 /// The allowed range of values is 1..=320
-#[derive(Clone, Copy, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema), schemars(transparent))]
+#[serde(try_from = "u16", into = "u16")]
 pub struct LineWidth(u16);
 
 impl LineWidth {
@@ -418,7 +621,15 @@ impl From<LineWidth> for u16 {
     }
 }
 
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+impl From<IndentWidth> for u8 {
+    fn from(value: IndentWidth) -> Self {
+        value.0
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
 pub enum QuoteStyle {
     #[default]
     Double,
@@ -528,7 +739,9 @@ impl From<TabWidth> for u8 {
     }
 }
 
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
 pub enum QuoteProperties {
     /// Only add quotes around object properties where required.
     #[default]
@@ -569,7 +782,9 @@ impl fmt::Display for QuoteProperties {
     }
 }
 
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
 pub enum Semicolons {
     #[default]
     Always,
@@ -610,7 +825,9 @@ impl fmt::Display for Semicolons {
     }
 }
 
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
 pub enum ArrowParentheses {
     #[default]
     Always,
@@ -707,7 +924,9 @@ impl Format<'_> for FormatTrailingCommas {
 }
 
 /// Print trailing commas wherever possible in multi-line comma-separated syntactic structures.
-#[derive(Clone, Copy, Default, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Default, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
 pub enum TrailingCommas {
     /// Trailing commas wherever possible (including function parameters and calls).
     #[default]
@@ -757,7 +976,9 @@ impl fmt::Display for TrailingCommas {
     }
 }
 
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
 pub enum AttributePosition {
     #[default]
     Auto,
@@ -788,7 +1009,9 @@ impl FromStr for AttributePosition {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema), schemars(transparent))]
+#[serde(from = "bool", into = "bool")]
 pub struct BracketSpacing(bool);
 
 impl BracketSpacing {
@@ -810,6 +1033,12 @@ impl From<bool> for BracketSpacing {
     }
 }
 
+impl From<BracketSpacing> for bool {
+    fn from(value: BracketSpacing) -> Self {
+        value.0
+    }
+}
+
 impl fmt::Display for BracketSpacing {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         fmt::Display::fmt(&self.value(), f)
@@ -832,7 +1061,9 @@ impl FromStr for BracketSpacing {
 }
 
 /// Put the `>` of a multi-line HTML or JSX element at the end of the last line instead of being alone on the next line (does not apply to self closing elements).
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema), schemars(transparent))]
+#[serde(from = "bool", into = "bool")]
 pub struct BracketSameLine(bool);
 
 impl BracketSameLine {
@@ -848,6 +1079,12 @@ impl From<bool> for BracketSameLine {
     }
 }
 
+impl From<BracketSameLine> for bool {
+    fn from(value: BracketSameLine) -> Self {
+        value.0
+    }
+}
+
 impl fmt::Display for BracketSameLine {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         fmt::Display::fmt(&self.value(), f)
@@ -867,7 +1104,9 @@ impl FromStr for BracketSameLine {
     }
 }
 
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
 pub enum Expand {
     /// Objects are expanded when the first property has a leading newline. Arrays are always
     /// expanded if they are shorter than the line width.
@@ -903,7 +1142,9 @@ impl fmt::Display for Expand {
     }
 }
 
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
 pub enum OperatorPosition {
     /// When binary expressions wrap lines, print operators at the start of new lines.
     Start,
@@ -944,7 +1185,9 @@ impl fmt::Display for OperatorPosition {
     }
 }
 
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(JsonSchema))]
+#[serde(rename_all = "kebab-case")]
 pub enum EmbeddedLanguageFormatting {
     /// Enable formatting for embedded languages.
     Auto,
@@ -985,3 +1228,22 @@ impl fmt::Display for EmbeddedLanguageFormatting {
         f.write_str(s)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_match_serialized_options() {
+        let serde_json::Value::Object(options) =
+            serde_json::to_value(FormatOptions::default()).unwrap()
+        else {
+            panic!("Expected the options to serialize to an object");
+        };
+        let mut keys = options.keys().map(String::as_str).collect::<Vec<_>>();
+        let mut expected = FormatOptions::KEYS.to_vec();
+        keys.sort_unstable();
+        expected.sort_unstable();
+        assert_eq!(keys, expected);
+    }
+}
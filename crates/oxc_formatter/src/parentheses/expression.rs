@@ -194,7 +194,7 @@ impl NeedsParentheses<'_> for AstNode<'_, NumericLiteral<'_>> {
 
 impl NeedsParentheses<'_> for AstNode<'_, StringLiteral<'_>> {
     fn needs_parentheses(&self, f: &Formatter<'_, '_>) -> bool {
-        if f.comments().is_type_cast_node(self) {
+        if f.comments().is_type_cast_node(self) || f.context().is_json() {
             return false;
         }
 
@@ -223,7 +223,7 @@ impl NeedsParentheses<'_> for AstNode<'_, ArrayExpression<'_>> {
 
 impl NeedsParentheses<'_> for AstNode<'_, ObjectExpression<'_>> {
     fn needs_parentheses(&self, f: &Formatter<'_, '_>) -> bool {
-        if f.comments().is_type_cast_node(self) {
+        if f.comments().is_type_cast_node(self) || f.context().is_json() {
             return false;
         }
 
@@ -629,7 +629,8 @@ impl NeedsParentheses<'_> for AstNode<'_, Class<'_>> {
 
 impl NeedsParentheses<'_> for AstNode<'_, ParenthesizedExpression<'_>> {
     fn needs_parentheses(&self, _f: &Formatter<'_, '_>) -> bool {
-        unreachable!("Already disabled `preserveParens` option in the parser")
+        // Printed verbatim including its parentheses.
+        false
     }
 }
 
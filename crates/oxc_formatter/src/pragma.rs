@@ -0,0 +1,31 @@
+//! The `@format` / `@prettier` pragma, see [`crate::FormatOptions::require_pragma`]
+//! and [`crate::FormatOptions::insert_pragma`].
+
+use oxc_ast::ast::Program;
+
+/// The pragma inserted with [`crate::FormatOptions::insert_pragma`].
+pub const PRAGMA: &str = "/** @format */";
+
+/// Returns `true` if the docblock of `program` contains a `@format` or `@prettier` pragma.
+///
+/// The docblock is the block comment at the start of the file, after an optional hashbang.
+/// A pragma must be at the start of a line of the docblock, after its leading `*`.
+pub fn has_pragma(program: &Program) -> bool {
+    let Some(comment) = program.comments.first() else {
+        return false;
+    };
+    let start = program.hashbang.as_ref().map_or(0, |hashbang| hashbang.span.end);
+    if !comment.is_block()
+        || !program.source_text[start as usize..comment.span.start as usize].trim().is_empty()
+    {
+        return false;
+    }
+
+    comment.content_span().source_text(program.source_text).lines().any(|line| {
+        let line = line.trim_start().trim_start_matches('*').trim_start();
+        ["@format", "@prettier"].into_iter().any(|pragma| {
+            line.strip_prefix(pragma)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with(char::is_whitespace))
+        })
+    })
+}
@@ -0,0 +1,58 @@
+//! The builders of the formatter's intermediate representation, to implement [`Format`] for custom types.
+//!
+//! A [`Format`] implementation writes IR elements, like tokens, line breaks and groups, into the
+//! [`Formatter`] with the [`write!`](crate::write!) macro. The document built by
+//! [`format!`](crate::format!) is printed with [`Formatted::print`], which decides for every group
+//! whether it fits on a line or has to break.
+//!
+//! The [`format_args!`](crate::format_args!) and [`best_fitting!`](crate::best_fitting!) macros
+//! aren't part of the prelude because they would be ambiguous with the macros of `std`.
+//!
+//! [`token`] writes static text, e.g. punctuation, and [`text`] dynamic text,
+//! which must be allocated in the [`Formatter::allocator`] if it doesn't outlive the formatting.
+//!
+//! ```rust
+//! use oxc_allocator::Allocator;
+//! use oxc_formatter::{FormatOptions, format, format_args, prelude::*, write};
+//! use oxc_span::SourceType;
+//!
+//! /// A list formatted like `[a, b, c]`, or with an item per line if it doesn't fit on one line.
+//! struct List<'a>(&'a [&'a str]);
+//!
+//! impl<'a> Format<'a> for List<'a> {
+//!     fn fmt(&self, f: &mut Formatter<'_, 'a>) {
+//!         let items = format_with(|f| {
+//!             f.join_with(&format_args!(token(","), soft_line_break_or_space()))
+//!                 .entries(self.0.iter().map(|item| text(item)));
+//!         });
+//!         write!(f, [group(&format_args!(
+//!             token("["),
+//!             soft_block_indent(&format_args!(items, if_group_breaks(&token(",")))),
+//!             token("]")
+//!         ))]);
+//!     }
+//! }
+//!
+//! let allocator = Allocator::default();
+//! let context = FormatContext::new("", SourceType::mjs(), &[], &allocator, FormatOptions::default(), None);
+//! let formatted = format!(context, [List(&["a", "b", "c"])]);
+//! assert_eq!(formatted.print().unwrap().as_code(), "[a, b, c]");
+//! ```
+
+pub use crate::formatter::{
+    Argument, Arguments, Buffer, Buffer as _, Format, FormatContext, Formatted, GroupId,
+    PrintError, PrintResult, Printed, format,
+    format_element::tag::{Label, LabelId},
+    formatter::Formatter,
+    prelude::{
+        Align, BestFitting, BlockIndent, Dedent, ExpandParent, FormatLabelled, FormatOnce,
+        FormatWith, Group, HardSpace, IfGroupBreaks, Indent, IndentIfGroupBreaks, Line, LineSuffix,
+        LineSuffixBoundary, Space, Text, Token, align, block_indent, dedent, empty_line,
+        expand_parent, format_once, format_with, group, hard_line_break, hard_space,
+        if_group_breaks, if_group_fits_on_line, indent, indent_if_group_breaks, labelled,
+        line_suffix, line_suffix_boundary, maybe_space, soft_block_indent,
+        soft_block_indent_with_maybe_space, soft_line_break, soft_line_break_or_space,
+        soft_line_indent_or_space, space, text, token,
+    },
+    write,
+};
@@ -0,0 +1,259 @@
+use cow_utils::CowUtils;
+
+use crate::{FormatOptions, IndentStyle, IndentWidth, LineEnding, LineWidth};
+
+/// A section of an `.editorconfig` file, e.g. `[*.{js,ts}]` and its properties.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EditorConfigSection {
+    /// The glob of the section header, without brackets.
+    pub glob: String,
+    pub properties: EditorConfigProperties,
+}
+
+/// The `.editorconfig` properties relevant for formatting.
+///
+/// Properties which are missing, set to `unset` or have an invalid value are `None`.
+/// See <https://spec.editorconfig.org> for their meaning.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct EditorConfigProperties {
+    pub indent_style: Option<IndentStyle>,
+    pub indent_size: Option<EditorConfigIndentSize>,
+    pub tab_width: Option<u8>,
+    pub end_of_line: Option<LineEnding>,
+    pub max_line_length: Option<EditorConfigMaxLineLength>,
+    pub insert_final_newline: Option<bool>,
+}
+
+/// The value of the `indent_size` property.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditorConfigIndentSize {
+    /// `indent_size = tab`, which uses the `tab_width`.
+    Tab,
+    Width(u8),
+}
+
+/// The value of the `max_line_length` property.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditorConfigMaxLineLength {
+    /// `max_line_length = off`, which doesn't limit the line length.
+    Off,
+    Width(u16),
+}
+
+/// Parses the sections of an `.editorconfig` file.
+///
+/// Finding the `.editorconfig` files of a path and matching the section globs is up to the caller,
+/// the properties of all matching sections can be combined with [`EditorConfigProperties::merge`].
+/// Properties before the first section, like `root = true`, are ignored.
+pub fn parse_editorconfig(content: &str) -> Vec<EditorConfigSection> {
+    let mut sections: Vec<EditorConfigSection> = vec![];
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with(['#', ';']) {
+            continue;
+        }
+        if let Some(glob) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) {
+            sections.push(EditorConfigSection {
+                glob: glob.to_string(),
+                properties: EditorConfigProperties::default(),
+            });
+        } else if let Some((key, value)) = line.split_once('=')
+            && let Some(section) = sections.last_mut()
+        {
+            section.properties.set(key.trim(), value.trim());
+        }
+    }
+    sections
+}
+
+impl EditorConfigProperties {
+    /// Sets the property `key` to `value`, ignoring unknown keys and invalid values.
+    /// Keys and values are case-insensitive.
+    pub fn set(&mut self, key: &str, value: &str) {
+        let value = value.cow_to_ascii_lowercase();
+        let value = value.as_ref();
+        match key.cow_to_ascii_lowercase().as_ref() {
+            "indent_style" => {
+                self.indent_style = match value {
+                    "tab" => Some(IndentStyle::Tab),
+                    "space" => Some(IndentStyle::Space),
+                    _ => None,
+                };
+            }
+            "indent_size" => {
+                self.indent_size = match value {
+                    "tab" => Some(EditorConfigIndentSize::Tab),
+                    _ => value.parse().ok().map(EditorConfigIndentSize::Width),
+                };
+            }
+            "tab_width" => self.tab_width = value.parse().ok(),
+            "end_of_line" => {
+                self.end_of_line = match value {
+                    "lf" => Some(LineEnding::Lf),
+                    "crlf" => Some(LineEnding::Crlf),
+                    "cr" => Some(LineEnding::Cr),
+                    _ => None,
+                };
+            }
+            "max_line_length" => {
+                self.max_line_length = match value {
+                    "off" => Some(EditorConfigMaxLineLength::Off),
+                    _ => value.parse().ok().map(EditorConfigMaxLineLength::Width),
+                };
+            }
+            "insert_final_newline" => {
+                self.insert_final_newline = match value {
+                    "true" => Some(true),
+                    "false" => Some(false),
+                    _ => None,
+                };
+            }
+            _ => {}
+        }
+    }
+
+    /// Overrides the properties which are set in `other`,
+    /// e.g. with the properties of a later section matching the same path.
+    pub fn merge(&mut self, other: &Self) {
+        self.indent_style = other.indent_style.or(self.indent_style);
+        self.indent_size = other.indent_size.or(self.indent_size);
+        self.tab_width = other.tab_width.or(self.tab_width);
+        self.end_of_line = other.end_of_line.or(self.end_of_line);
+        self.max_line_length = other.max_line_length.or(self.max_line_length);
+        self.insert_final_newline = other.insert_final_newline.or(self.insert_final_newline);
+    }
+}
+
+impl FormatOptions {
+    /// Applies the `.editorconfig` properties of a file onto these options,
+    /// mapped like Prettier does.
+    ///
+    /// The properties override the options, so they should be applied before the options of a
+    /// formatter config, see [`Oxfmtrc::into_options_with_editorconfig`](crate::Oxfmtrc::into_options_with_editorconfig).
+    /// `max_line_length = off` maps to [`LineWidth::MAX`], invalid widths are ignored.
+    #[must_use]
+    pub fn with_editorconfig(mut self, properties: &EditorConfigProperties) -> Self {
+        if let Some(indent_style) = properties.indent_style {
+            self.indent_style = indent_style;
+        }
+        if properties.indent_size == Some(EditorConfigIndentSize::Tab) {
+            self.indent_style = IndentStyle::Tab;
+        }
+
+        // Tabs are as wide as `tab_width`, spaces are `indent_size` wide.
+        let indent_width = match (self.indent_style, properties.indent_size, properties.tab_width) {
+            (IndentStyle::Tab, _, Some(tab_width))
+            | (_, None | Some(EditorConfigIndentSize::Tab), Some(tab_width)) => Some(tab_width),
+            (_, Some(EditorConfigIndentSize::Width(indent_size)), _) => Some(indent_size),
+            (_, _, None) => None,
+        };
+        if let Some(indent_width) = indent_width.and_then(|width| IndentWidth::try_from(width).ok())
+        {
+            self.indent_width = indent_width;
+        }
+
+        if let Some(end_of_line) = properties.end_of_line {
+            self.line_ending = end_of_line;
+        }
+
+        if let Some(insert_final_newline) = properties.insert_final_newline {
+            self.insert_final_newline = insert_final_newline;
+        }
+
+        match properties.max_line_length {
+            Some(EditorConfigMaxLineLength::Off) => {
+                self.line_width = LineWidth::try_from(LineWidth::MAX).unwrap();
+            }
+            Some(EditorConfigMaxLineLength::Width(width)) => {
+                if let Ok(line_width) = LineWidth::try_from(width) {
+                    self.line_width = line_width;
+                }
+            }
+            None => {}
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format_options(editorconfig: &str) -> FormatOptions {
+        let mut properties = EditorConfigProperties::default();
+        for section in parse_editorconfig(editorconfig) {
+            properties.merge(&section.properties);
+        }
+        FormatOptions::default().with_editorconfig(&properties)
+    }
+
+    #[test]
+    fn test_parse_editorconfig() {
+        let sections = parse_editorconfig(
+            "root = true\n\n# Defaults\n[*]\nindent_style = space\nindent_size = 2\nend_of_line = CRLF\ninsert_final_newline = true\n\n; Makefiles need tabs\n[Makefile]\nindent_style = tab\ncharset = utf-8\n",
+        );
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[0].glob, "*");
+        assert_eq!(
+            sections[0].properties,
+            EditorConfigProperties {
+                indent_style: Some(IndentStyle::Space),
+                indent_size: Some(EditorConfigIndentSize::Width(2)),
+                end_of_line: Some(LineEnding::Crlf),
+                insert_final_newline: Some(true),
+                ..EditorConfigProperties::default()
+            }
+        );
+        assert_eq!(sections[1].glob, "Makefile");
+        assert_eq!(
+            sections[1].properties,
+            EditorConfigProperties {
+                indent_style: Some(IndentStyle::Tab),
+                ..EditorConfigProperties::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_spaces() {
+        let options = format_options("[*]\nindent_style = space\nindent_size = 4\ntab_width = 8");
+        assert_eq!(options.indent_style, IndentStyle::Space);
+        assert_eq!(options.indent_width.value(), 4);
+
+        let options = format_options("[*]\nindent_size = 3");
+        assert_eq!(options.indent_style, IndentStyle::Space);
+        assert_eq!(options.indent_width.value(), 3);
+    }
+
+    #[test]
+    fn test_tabs() {
+        let options = format_options("[*]\nindent_style = tab\nindent_size = 2\ntab_width = 8");
+        assert_eq!(options.indent_style, IndentStyle::Tab);
+        assert_eq!(options.indent_width.value(), 8);
+
+        let options = format_options("[*]\nindent_style = tab\nindent_size = 4");
+        assert_eq!(options.indent_style, IndentStyle::Tab);
+        assert_eq!(options.indent_width.value(), 4);
+
+        let options = format_options("[*]\nindent_size = tab\ntab_width = 3");
+        assert_eq!(options.indent_style, IndentStyle::Tab);
+        assert_eq!(options.indent_width.value(), 3);
+    }
+
+    #[test]
+    fn test_line_width_ending_and_final_newline() {
+        let options = format_options(
+            "[*]\nmax_line_length = 120\nend_of_line = cr\ninsert_final_newline = false",
+        );
+        assert_eq!(options.line_width.value(), 120);
+        assert_eq!(options.line_ending, LineEnding::Cr);
+        assert!(!options.insert_final_newline);
+
+        assert_eq!(format_options("[*]\nmax_line_length = off").line_width.value(), LineWidth::MAX);
+        // Invalid and unset values keep the defaults
+        let options =
+            format_options("[*]\nmax_line_length = 0\nindent_size = unset\nend_of_line = nel");
+        assert_eq!(options, FormatOptions::default());
+    }
+}
@@ -0,0 +1,187 @@
+use std::path::Path;
+
+use cow_utils::CowUtils;
+
+use crate::{
+    ArrowParentheses, AttributePosition, BracketSameLine, BracketSpacing,
+    EmbeddedLanguageFormatting, Expand, FormatOptions, IndentStyle, IndentWidth, LineEnding,
+    LineWidth, OperatorPosition, QuoteProperties, QuoteStyle, Semicolons, SortImportsOptions,
+    TrailingCommas,
+};
+
+/// Format options with overrides for the files matching a glob,
+/// e.g. a wider `line_width` for `*.test.ts` files.
+#[derive(Debug, Default, Clone)]
+pub struct FormatConfig {
+    /// The options for files without a matching override.
+    pub options: FormatOptions,
+    overrides: Vec<(String, FormatOptionsOverride)>,
+}
+
+impl FormatConfig {
+    pub fn new(options: FormatOptions) -> Self {
+        Self { options, overrides: vec![] }
+    }
+
+    /// Overrides the options of the files matching `glob`, after the previously added overrides.
+    ///
+    /// The glob supports `*`, `**`, `?`, `[...]` and `{a,b}`. Globs without a `/` match
+    /// the file name in any directory, e.g. `*.test.ts` matches `src/a.test.ts`.
+    #[must_use]
+    pub fn with_override(mut self, glob: &str, options: FormatOptionsOverride) -> Self {
+        let glob = if glob.contains('/') { glob.to_string() } else { format!("**/{glob}") };
+        self.overrides.push((glob, options));
+        self
+    }
+
+    /// Returns the options for the file at `path`.
+    /// The overrides matching the path are applied in order, so later overrides win.
+    pub fn resolve(&self, path: &Path) -> FormatOptions {
+        let path = path.to_string_lossy();
+        let path = path.cow_replace('\\', "/");
+        let mut options = self.options.clone();
+        for (glob, options_override) in &self.overrides {
+            if fast_glob::glob_match(glob, path.as_ref()) {
+                options_override.apply_to(&mut options);
+            }
+        }
+        options
+    }
+}
+
+/// Partial [`FormatOptions`], only the options which are `Some` are overridden.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FormatOptionsOverride {
+    pub indent_style: Option<IndentStyle>,
+    pub indent_width: Option<IndentWidth>,
+    pub line_ending: Option<LineEnding>,
+    pub line_width: Option<LineWidth>,
+    pub quote_style: Option<QuoteStyle>,
+    pub jsx_quote_style: Option<QuoteStyle>,
+    pub quote_properties: Option<QuoteProperties>,
+    pub trailing_commas: Option<TrailingCommas>,
+    pub semicolons: Option<Semicolons>,
+    pub arrow_parentheses: Option<ArrowParentheses>,
+    pub bracket_spacing: Option<BracketSpacing>,
+    pub bracket_same_line: Option<BracketSameLine>,
+    pub attribute_position: Option<AttributePosition>,
+    pub expand: Option<Expand>,
+    pub max_empty_lines: Option<u8>,
+    pub member_chain_min_calls: Option<u8>,
+    pub decorators_same_line: Option<bool>,
+    pub preserve_parens: Option<bool>,
+    pub format_doc_comments: Option<bool>,
+    pub experimental_operator_position: Option<OperatorPosition>,
+    pub experimental_ternaries: Option<bool>,
+    pub embedded_language_formatting: Option<EmbeddedLanguageFormatting>,
+    /// Enables sorting imports with these options.
+    pub experimental_sort_imports: Option<SortImportsOptions>,
+    pub require_pragma: Option<bool>,
+    pub insert_pragma: Option<bool>,
+    pub insert_final_newline: Option<bool>,
+}
+
+impl FormatOptionsOverride {
+    /// Overrides the options of `options` which are set.
+    pub fn apply_to(&self, options: &mut FormatOptions) {
+        fn set<T: Clone>(option: &mut T, value: Option<&T>) {
+            if let Some(value) = value {
+                option.clone_from(value);
+            }
+        }
+
+        set(&mut options.indent_style, self.indent_style.as_ref());
+        set(&mut options.indent_width, self.indent_width.as_ref());
+        set(&mut options.line_ending, self.line_ending.as_ref());
+        set(&mut options.line_width, self.line_width.as_ref());
+        set(&mut options.quote_style, self.quote_style.as_ref());
+        set(&mut options.jsx_quote_style, self.jsx_quote_style.as_ref());
+        set(&mut options.quote_properties, self.quote_properties.as_ref());
+        set(&mut options.trailing_commas, self.trailing_commas.as_ref());
+        set(&mut options.semicolons, self.semicolons.as_ref());
+        set(&mut options.arrow_parentheses, self.arrow_parentheses.as_ref());
+        set(&mut options.bracket_spacing, self.bracket_spacing.as_ref());
+        set(&mut options.bracket_same_line, self.bracket_same_line.as_ref());
+        set(&mut options.attribute_position, self.attribute_position.as_ref());
+        set(&mut options.expand, self.expand.as_ref());
+        set(&mut options.max_empty_lines, self.max_empty_lines.as_ref());
+        set(&mut options.member_chain_min_calls, self.member_chain_min_calls.as_ref());
+        set(&mut options.decorators_same_line, self.decorators_same_line.as_ref());
+        set(&mut options.preserve_parens, self.preserve_parens.as_ref());
+        set(&mut options.format_doc_comments, self.format_doc_comments.as_ref());
+        set(
+            &mut options.experimental_operator_position,
+            self.experimental_operator_position.as_ref(),
+        );
+        set(&mut options.experimental_ternaries, self.experimental_ternaries.as_ref());
+        set(&mut options.embedded_language_formatting, self.embedded_language_formatting.as_ref());
+        if let Some(sort_imports) = &self.experimental_sort_imports {
+            options.experimental_sort_imports = Some(sort_imports.clone());
+        }
+        set(&mut options.require_pragma, self.require_pragma.as_ref());
+        set(&mut options.insert_pragma, self.insert_pragma.as_ref());
+        set(&mut options.insert_final_newline, self.insert_final_newline.as_ref());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line_width(width: u16) -> FormatOptionsOverride {
+        FormatOptionsOverride {
+            line_width: Some(LineWidth::try_from(width).unwrap()),
+            ..FormatOptionsOverride::default()
+        }
+    }
+
+    fn config() -> FormatConfig {
+        let options =
+            FormatOptions { line_width: LineWidth::try_from(80).unwrap(), ..FormatOptions::new() };
+        FormatConfig::new(options)
+            .with_override("*.test.ts", line_width(100))
+            .with_override("src/legacy/**/*.{js,jsx}", line_width(120))
+            .with_override(
+                "src/legacy/vendor/**",
+                FormatOptionsOverride {
+                    semicolons: Some(Semicolons::AsNeeded),
+                    ..FormatOptionsOverride::default()
+                },
+            )
+    }
+
+    #[test]
+    fn test_base_options() {
+        let options = config().resolve(Path::new("src/index.ts"));
+        assert_eq!(options.line_width.value(), 80);
+        assert_eq!(options, config().options);
+    }
+
+    #[test]
+    fn test_file_name_glob() {
+        assert_eq!(config().resolve(Path::new("a.test.ts")).line_width.value(), 100);
+        assert_eq!(config().resolve(Path::new("src/utils/a.test.ts")).line_width.value(), 100);
+        assert_eq!(config().resolve(Path::new("src/utils/a.test.tsx")).line_width.value(), 80);
+    }
+
+    #[test]
+    fn test_globstar_and_braces() {
+        assert_eq!(config().resolve(Path::new("src/legacy/a.js")).line_width.value(), 120);
+        assert_eq!(config().resolve(Path::new("src/legacy/deep/b.jsx")).line_width.value(), 120);
+        assert_eq!(config().resolve(Path::new("src/legacy/deep/c.ts")).line_width.value(), 80);
+        assert_eq!(config().resolve(Path::new("lib/legacy/a.js")).line_width.value(), 80);
+    }
+
+    #[test]
+    fn test_later_overrides_win() {
+        let config = config().with_override("src/**", line_width(90));
+        assert_eq!(config.resolve(Path::new("src/legacy/a.js")).line_width.value(), 90);
+        assert_eq!(config.resolve(Path::new("src/a.test.ts")).line_width.value(), 90);
+        assert_eq!(config.resolve(Path::new("a.test.ts")).line_width.value(), 100);
+
+        // Options which aren't set by the later override are kept
+        let options = config.resolve(Path::new("src/legacy/vendor/a.js"));
+        assert_eq!(options.line_width.value(), 90);
+        assert_eq!(options.semicolons, Semicolons::AsNeeded);
+    }
+}
@@ -0,0 +1,45 @@
+use std::path::PathBuf;
+
+use oxc_allocator::AllocatorPool;
+use rayon::prelude::*;
+
+use super::format_source::format_source_in;
+use crate::{FormatOptions, FormatSourceResult};
+
+/// A file to format with [`format_files`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileInput {
+    /// The path of the file, its extension determines the language, see [`crate::detect_file_kind`].
+    pub path: PathBuf,
+    pub source_text: String,
+}
+
+/// The result of formatting a [`FileInput`] with [`format_files`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileResult {
+    pub path: PathBuf,
+    /// The formatted code, or the error of [`crate::format_source`].
+    pub result: Result<FormatSourceResult, String>,
+}
+
+/// Formats the files of `inputs` in parallel, see [`crate::format_source`].
+///
+/// The results are in the order of `inputs`. A file which can't be formatted doesn't stop the
+/// others, its error is part of its result.
+/// Every file is parsed into its own allocator, which is taken from a pool and reset after the
+/// file is formatted, so the memory of the allocators is reused for the following files.
+pub fn format_files(
+    inputs: impl IntoParallelIterator<Item = FileInput>,
+    options: &FormatOptions,
+) -> Vec<FileResult> {
+    let pool = AllocatorPool::new(rayon::current_num_threads());
+    inputs
+        .into_par_iter()
+        .map(|input| {
+            let allocator = pool.get();
+            let result =
+                format_source_in(&allocator, &input.path, &input.source_text, options.clone());
+            FileResult { path: input.path, result }
+        })
+        .collect()
+}
@@ -0,0 +1,90 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+
+use crate::{
+    FileKind, FormatOptions, Formatter, detect_file_kind, enable_jsx_source_type,
+    get_parse_options_with,
+};
+
+const BOM: &str = "\u{feff}";
+
+/// The result of [`format_source`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatSourceResult {
+    /// The formatted code, starting with a BOM if the source text did.
+    pub code: String,
+    /// Whether the formatted code differs from the source text, e.g. to skip writing unchanged files.
+    pub changed: bool,
+}
+
+/// Formats the `source_text` of a file, detecting its language from the extension of `path`,
+/// see [`detect_file_kind`]. JSON files are formatted with [`Formatter::format_json`].
+///
+/// A UTF-8 BOM is kept at the very start, before a hashbang, and the formatted code ends with
+/// exactly one line break unless [`FormatOptions::insert_final_newline`] is disabled.
+///
+/// # Errors
+/// Returns an error if the extension of `path` isn't supported or `source_text` has syntax errors.
+pub fn format_source(
+    path: &Path,
+    source_text: &str,
+    options: FormatOptions,
+) -> Result<FormatSourceResult, String> {
+    format_source_in(&Allocator::default(), path, source_text, options)
+}
+
+/// Formats the `source_text` of a file like [`format_source`], but parses it into `allocator`
+/// instead of a new one.
+///
+/// `allocator` is reset when formatting is done, also if it failed, so its memory is reused by
+/// the next call. Setting up a new allocator is a large part of formatting a small file, so
+/// long-running processes like language servers should keep one allocator for all files.
+/// The result doesn't borrow from the allocator.
+///
+/// # Errors
+/// Returns an error if the extension of `path` isn't supported or `source_text` has syntax errors.
+pub fn format_source_with_allocator(
+    allocator: &mut Allocator,
+    path: &Path,
+    source_text: &str,
+    options: FormatOptions,
+) -> Result<FormatSourceResult, String> {
+    let result = format_source_in(allocator, path, source_text, options);
+    allocator.reset();
+    result
+}
+
+/// Formats the `source_text` of a file like [`format_source`], parsing it into `allocator`.
+pub fn format_source_in(
+    allocator: &Allocator,
+    path: &Path,
+    source_text: &str,
+    options: FormatOptions,
+) -> Result<FormatSourceResult, String> {
+    let file_kind = detect_file_kind(path).map_err(|error| error.to_string())?;
+    let (bom, text) = match source_text.strip_prefix(BOM) {
+        Some(text) => (BOM, text),
+        None => ("", source_text),
+    };
+
+    let formatted = match file_kind {
+        FileKind::Source(source_type) => {
+            let source_type = enable_jsx_source_type(source_type);
+            let ret = Parser::new(allocator, text, source_type)
+                .with_options(get_parse_options_with(&options))
+                .parse();
+            if !ret.errors.is_empty() {
+                let errors = ret.errors.iter().map(ToString::to_string).collect::<Vec<_>>();
+                return Err(errors.join("\n"));
+            }
+            Formatter::new(allocator, options).build(&ret.program)
+        }
+        FileKind::Json(kind) => Formatter::new(allocator, options).format_json(text, kind)?,
+    };
+
+    let code = format!("{bom}{formatted}");
+    let changed = code != source_text;
+    Ok(FormatSourceResult { code, changed })
+}
@@ -1,2 +1,8 @@
+pub mod editorconfig;
+pub mod format_config;
+#[cfg(feature = "rayon")]
+pub mod format_files;
+pub mod format_source;
 pub mod oxfmtrc;
 pub mod parse_utils;
+pub mod prettierrc;
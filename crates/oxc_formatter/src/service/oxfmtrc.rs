@@ -5,10 +5,10 @@ use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
 
 use crate::{
-    ArrowParentheses, AttributePosition, BracketSameLine, BracketSpacing,
+    ArrowParentheses, AttributePosition, BracketSameLine, BracketSpacing, EditorConfigProperties,
     EmbeddedLanguageFormatting, Expand, FormatOptions, IndentStyle, IndentWidth, LineEnding,
-    LineWidth, QuoteProperties, QuoteStyle, Semicolons, SortImportsOptions, SortOrder,
-    TrailingCommas, default_groups, default_internal_patterns,
+    LineWidth, OperatorPosition, QuoteProperties, QuoteStyle, Semicolons, SortImportsOptions,
+    SortOrder, TrailingCommas, default_groups, default_internal_patterns,
 };
 
 /// Configuration options for the Oxfmt.
@@ -63,20 +63,45 @@ pub struct Oxfmtrc {
     /// Put each attribute on a new line in JSX. (Default: `false`)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub single_attribute_per_line: Option<bool>,
+    /// Only format files with a `@format` or `@prettier` pragma in their first docblock. (Default: `false`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require_pragma: Option<bool>,
+    /// Insert a `/** @format */` pragma at the start of formatted files without one. (Default: `false`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub insert_pragma: Option<bool>,
 
-    // NOTE: These experimental options are not yet supported.
-    // Just be here to report error if they are used.
+    /// Where to print operators when binary expressions wrap lines. (Default: `"end"`)
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[schemars(skip)]
-    pub experimental_operator_position: Option<serde_json::Value>,
+    pub experimental_operator_position: Option<OperatorPositionConfig>,
+
+    /// Use curious ternaries, with the question mark after the condition. (Default: `false`)
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[schemars(skip)]
-    pub experimental_ternaries: Option<serde_json::Value>,
+    pub experimental_ternaries: Option<bool>,
 
     /// Control whether formats quoted code embedded in the file. (Default: `"auto"`)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub embedded_language_formatting: Option<EmbeddedLanguageFormattingConfig>,
 
+    /// Maximum number of consecutive empty lines to keep between statements, class members and comments. (Default: `1`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_empty_lines: Option<u8>,
+
+    /// Number of calls from which a member chain with non-trivial arguments breaks with one call per line. (Default: `3`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_chain_min_calls: Option<u8>,
+
+    /// Keep a single class member or parameter decorator on the same line as the member. (Default: `false`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decorators_same_line: Option<bool>,
+
+    /// Keep parentheses written in the source, even where they aren't needed. (Default: `false`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preserve_parens: Option<bool>,
+
+    /// Re-indent JSDoc comments to their node and align the `*` of each line. (Default: `false`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format_doc_comments: Option<bool>,
+
     /// Experimental: Sort import statements. Disabled by default.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub experimental_sort_imports: Option<SortImportsConfig>,
@@ -99,6 +124,7 @@ pub enum EndOfLineConfig {
     Lf,
     Crlf,
     Cr,
+    Auto,
 }
 
 #[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, JsonSchema)]
@@ -136,6 +162,14 @@ pub enum ObjectWrapConfig {
     Always,
 }
 
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum OperatorPositionConfig {
+    Start,
+    #[default]
+    End,
+}
+
 #[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum EmbeddedLanguageFormattingConfig {
@@ -272,18 +306,25 @@ impl Oxfmtrc {
     /// # Errors
     /// Returns error if any option value is invalid
     pub fn into_options(self) -> Result<(FormatOptions, OxfmtOptions), String> {
-        // Not yet supported options:
-        // [Prettier] experimentalOperatorPosition: "start" | "end"
-        // [Prettier] experimentalTernaries: boolean
-        if self.experimental_operator_position.is_some() {
-            return Err("Unsupported option: `experimentalOperatorPosition`".to_string());
-        }
-        if self.experimental_ternaries.is_some() {
-            return Err("Unsupported option: `experimentalTernaries`".to_string());
-        }
+        self.into_options_with_base(FormatOptions::default())
+    }
 
-        let mut format_options = FormatOptions::default();
+    /// Like [`Oxfmtrc::into_options`], but options which aren't specified are taken from the
+    /// `.editorconfig` properties of the file, see [`FormatOptions::with_editorconfig`].
+    ///
+    /// # Errors
+    /// Returns error if any option value is invalid
+    pub fn into_options_with_editorconfig(
+        self,
+        editorconfig: &EditorConfigProperties,
+    ) -> Result<(FormatOptions, OxfmtOptions), String> {
+        self.into_options_with_base(FormatOptions::default().with_editorconfig(editorconfig))
+    }
 
+    fn into_options_with_base(
+        self,
+        mut format_options: FormatOptions,
+    ) -> Result<(FormatOptions, OxfmtOptions), String> {
         // [Prettier] useTabs: boolean
         if let Some(use_tabs) = self.use_tabs {
             format_options.indent_style =
@@ -297,12 +338,12 @@ impl Oxfmtrc {
         }
 
         // [Prettier] endOfLine: "lf" | "cr" | "crlf" | "auto"
-        // NOTE: "auto" is not supported
         if let Some(ending) = self.end_of_line {
             format_options.line_ending = match ending {
                 EndOfLineConfig::Lf => LineEnding::Lf,
                 EndOfLineConfig::Crlf => LineEnding::Crlf,
                 EndOfLineConfig::Cr => LineEnding::Cr,
+                EndOfLineConfig::Auto => LineEnding::Auto,
             };
         }
 
@@ -375,6 +416,16 @@ impl Oxfmtrc {
             };
         }
 
+        // [Prettier] requirePragma: boolean
+        if let Some(require_pragma) = self.require_pragma {
+            format_options.require_pragma = require_pragma;
+        }
+
+        // [Prettier] insertPragma: boolean
+        if let Some(insert_pragma) = self.insert_pragma {
+            format_options.insert_pragma = insert_pragma;
+        }
+
         // [Prettier] objectWrap: "preserve" | "collapse"
         if let Some(object_wrap) = self.object_wrap {
             format_options.expand = match object_wrap {
@@ -385,6 +436,19 @@ impl Oxfmtrc {
             };
         }
 
+        // [Prettier] experimentalOperatorPosition: "start" | "end"
+        if let Some(position) = self.experimental_operator_position {
+            format_options.experimental_operator_position = match position {
+                OperatorPositionConfig::Start => OperatorPosition::Start,
+                OperatorPositionConfig::End => OperatorPosition::End,
+            };
+        }
+
+        // [Prettier] experimentalTernaries: boolean
+        if let Some(experimental_ternaries) = self.experimental_ternaries {
+            format_options.experimental_ternaries = experimental_ternaries;
+        }
+
         // [Prettier] embeddedLanguageFormatting: "auto" | "off"
         if let Some(embedded_language_formatting) = self.embedded_language_formatting {
             format_options.embedded_language_formatting = match embedded_language_formatting {
@@ -395,6 +459,26 @@ impl Oxfmtrc {
 
         // Below are our own extensions
 
+        if let Some(max_empty_lines) = self.max_empty_lines {
+            format_options.max_empty_lines = max_empty_lines;
+        }
+
+        if let Some(member_chain_min_calls) = self.member_chain_min_calls {
+            format_options.member_chain_min_calls = member_chain_min_calls;
+        }
+
+        if let Some(decorators_same_line) = self.decorators_same_line {
+            format_options.decorators_same_line = decorators_same_line;
+        }
+
+        if let Some(preserve_parens) = self.preserve_parens {
+            format_options.preserve_parens = preserve_parens;
+        }
+
+        if let Some(format_doc_comments) = self.format_doc_comments {
+            format_options.format_doc_comments = format_doc_comments;
+        }
+
         if let Some(sort_imports_config) = self.experimental_sort_imports {
             // `partition_by_newline: true` and `newlines_between` cannot be used together
             if sort_imports_config.partition_by_newline && sort_imports_config.newlines_between {
@@ -447,13 +531,13 @@ impl Oxfmtrc {
         obj.insert("tabWidth".to_string(), Value::from(options.indent_width.value()));
 
         // [Prettier] endOfLine: "lf" | "cr" | "crlf" | "auto"
-        // NOTE: "auto" is not supported by `oxc_formatter`
         obj.insert(
             "endOfLine".to_string(),
             Value::from(match options.line_ending {
                 LineEnding::Lf => "lf",
                 LineEnding::Crlf => "crlf",
                 LineEnding::Cr => "cr",
+                LineEnding::Auto => "auto",
             }),
         );
 
@@ -541,6 +625,21 @@ impl Oxfmtrc {
             }),
         );
 
+        // [Prettier] experimentalOperatorPosition: "start" | "end"
+        obj.insert(
+            "experimentalOperatorPosition".to_string(),
+            Value::from(match options.experimental_operator_position {
+                OperatorPosition::Start => "start",
+                OperatorPosition::End => "end",
+            }),
+        );
+
+        // [Prettier] experimentalTernaries: boolean
+        obj.insert(
+            "experimentalTernaries".to_string(),
+            Value::from(options.experimental_ternaries),
+        );
+
         // [Prettier] embeddedLanguageFormatting: "auto" | "off"
         obj.insert(
             "embeddedLanguageFormatting".to_string(),
@@ -552,6 +651,11 @@ impl Oxfmtrc {
 
         // Below are our own extensions, just remove them
         obj.remove("ignorePatterns");
+        obj.remove("maxEmptyLines");
+        obj.remove("memberChainMinCalls");
+        obj.remove("decoratorsSameLine");
+        obj.remove("preserveParens");
+        obj.remove("formatDocComments");
         obj.remove("experimentalSortImports");
         obj.remove("experimentalSortPackageJson");
 
@@ -736,6 +840,85 @@ mod tests {
         assert_eq!(format_options.expand, Expand::Always);
     }
 
+    #[test]
+    fn test_operator_position() {
+        let config: Oxfmtrc =
+            serde_json::from_str(r#"{"experimentalOperatorPosition": "start"}"#).unwrap();
+        let (format_options, _) = config.into_options().unwrap();
+        assert!(format_options.experimental_operator_position.is_start());
+
+        let config: Oxfmtrc =
+            serde_json::from_str(r#"{"experimentalOperatorPosition": "end"}"#).unwrap();
+        let (format_options, _) = config.into_options().unwrap();
+        assert!(format_options.experimental_operator_position.is_end());
+    }
+
+    #[test]
+    fn test_experimental_ternaries() {
+        let config: Oxfmtrc = serde_json::from_str(r#"{"experimentalTernaries": true}"#).unwrap();
+        let (format_options, _) = config.into_options().unwrap();
+        assert!(format_options.experimental_ternaries);
+
+        let config: Oxfmtrc = serde_json::from_str("{}").unwrap();
+        let (format_options, _) = config.into_options().unwrap();
+        assert!(!format_options.experimental_ternaries);
+    }
+
+    #[test]
+    fn test_max_empty_lines() {
+        let config: Oxfmtrc = serde_json::from_str(r#"{"maxEmptyLines": 2}"#).unwrap();
+        let (format_options, _) = config.into_options().unwrap();
+        assert_eq!(format_options.max_empty_lines, 2);
+
+        let config: Oxfmtrc = serde_json::from_str("{}").unwrap();
+        let (format_options, _) = config.into_options().unwrap();
+        assert_eq!(format_options.max_empty_lines, 1);
+    }
+
+    #[test]
+    fn test_member_chain_min_calls() {
+        let config: Oxfmtrc = serde_json::from_str(r#"{"memberChainMinCalls": 2}"#).unwrap();
+        let (format_options, _) = config.into_options().unwrap();
+        assert_eq!(format_options.member_chain_min_calls, 2);
+
+        let config: Oxfmtrc = serde_json::from_str("{}").unwrap();
+        let (format_options, _) = config.into_options().unwrap();
+        assert_eq!(format_options.member_chain_min_calls, 3);
+    }
+
+    #[test]
+    fn test_decorators_same_line() {
+        let config: Oxfmtrc = serde_json::from_str(r#"{"decoratorsSameLine": true}"#).unwrap();
+        let (format_options, _) = config.into_options().unwrap();
+        assert!(format_options.decorators_same_line);
+
+        let config: Oxfmtrc = serde_json::from_str("{}").unwrap();
+        let (format_options, _) = config.into_options().unwrap();
+        assert!(!format_options.decorators_same_line);
+    }
+
+    #[test]
+    fn test_preserve_parens() {
+        let config: Oxfmtrc = serde_json::from_str(r#"{"preserveParens": true}"#).unwrap();
+        let (format_options, _) = config.into_options().unwrap();
+        assert!(format_options.preserve_parens);
+
+        let config: Oxfmtrc = serde_json::from_str("{}").unwrap();
+        let (format_options, _) = config.into_options().unwrap();
+        assert!(!format_options.preserve_parens);
+    }
+
+    #[test]
+    fn test_format_doc_comments() {
+        let config: Oxfmtrc = serde_json::from_str(r#"{"formatDocComments": true}"#).unwrap();
+        let (format_options, _) = config.into_options().unwrap();
+        assert!(format_options.format_doc_comments);
+
+        let config: Oxfmtrc = serde_json::from_str("{}").unwrap();
+        let (format_options, _) = config.into_options().unwrap();
+        assert!(!format_options.format_doc_comments);
+    }
+
     #[test]
     fn test_sort_imports_config() {
         let config: Oxfmtrc = serde_json::from_str(
@@ -820,6 +1003,29 @@ mod tests {
         assert_eq!(sort_imports.groups[4], vec!["index".to_string()]);
     }
 
+    #[test]
+    fn test_editorconfig_precedence() {
+        let editorconfig = EditorConfigProperties {
+            indent_style: Some(IndentStyle::Tab),
+            tab_width: Some(4),
+            max_line_length: Some(crate::EditorConfigMaxLineLength::Width(120)),
+            ..EditorConfigProperties::default()
+        };
+
+        let config: Oxfmtrc = serde_json::from_str(r#"{ "useTabs": false }"#).unwrap();
+        let (format_options, _) = config.into_options_with_editorconfig(&editorconfig).unwrap();
+        assert!(format_options.indent_style.is_space());
+        assert_eq!(format_options.indent_width.value(), 4);
+        assert_eq!(format_options.line_width.value(), 120);
+
+        let config: Oxfmtrc =
+            serde_json::from_str(r#"{ "tabWidth": 2, "printWidth": 80 }"#).unwrap();
+        let (format_options, _) = config.into_options_with_editorconfig(&editorconfig).unwrap();
+        assert!(format_options.indent_style.is_tab());
+        assert_eq!(format_options.indent_width.value(), 2);
+        assert_eq!(format_options.line_width.value(), 80);
+    }
+
     #[test]
     fn test_populate_prettier_config_defaults() {
         let json_string = r"{}";
@@ -1,7 +1,12 @@
+use std::{fmt, path::Path};
+
+use cow_utils::CowUtils;
 use oxc_parser::ParseOptions;
 use oxc_span::SourceType;
 use phf::phf_set;
 
+use crate::{FormatOptions, JsonKind};
+
 pub fn get_parse_options() -> ParseOptions {
     ParseOptions {
         // Do not need to parse regexp
@@ -9,11 +14,17 @@ pub fn get_parse_options() -> ParseOptions {
         // Enable all syntax features
         allow_return_outside_function: true,
         allow_v8_intrinsics: true,
-        // `oxc_formatter` expects this to be `false`, otherwise panics
+        // `oxc_formatter` expects this to be `false`, otherwise parenthesized expressions are printed verbatim
         preserve_parens: false,
     }
 }
 
+/// Returns the [`ParseOptions`] to parse a program formatted with `options`,
+/// which keep parenthesized expressions for [`FormatOptions::preserve_parens`].
+pub fn get_parse_options_with(options: &FormatOptions) -> ParseOptions {
+    ParseOptions { preserve_parens: options.preserve_parens, ..get_parse_options() }
+}
+
 // Additional extensions from linguist-languages, which Prettier also supports
 // - https://github.com/ikatyang-collab/linguist-languages/blob/d1dc347c7ced0f5b42dd66c7d1c4274f64a3eb6b/data/JavaScript.js
 // No special extensions for TypeScript
@@ -51,12 +62,20 @@ static SPECIAL_JS_FILENAMES: phf::Set<&'static str> = phf_set! {
     "end.frag",
 };
 
-pub fn get_supported_source_type(path: &std::path::Path) -> Option<SourceType> {
+pub fn get_supported_source_type(path: &Path) -> Option<SourceType> {
     // Standard extensions, also supported by `oxc_span::VALID_EXTENSIONS`
     // NOTE: Use `path` directly for `.d.ts` detection
     if let Ok(source_type) = SourceType::from_path(path) {
         return Some(source_type);
     }
+    // Uppercase extensions like `.TSX` or `.D.TS`
+    let file_name = path.file_name()?.to_str()?;
+    let lowercase_file_name = file_name.cow_to_ascii_lowercase();
+    if lowercase_file_name != file_name
+        && let Ok(source_type) = SourceType::from_path(lowercase_file_name.as_ref())
+    {
+        return Some(source_type);
+    }
 
     // Check special filenames first
     if let Some(file_name) = path.file_name()
@@ -100,3 +119,113 @@ pub fn enable_jsx_source_type(source_type: SourceType) -> SourceType {
 
     source_type
 }
+
+/// The kind of a file the formatter supports, see [`detect_file_kind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileKind {
+    /// JavaScript or TypeScript, formatted with [`crate::Formatter::format`].
+    Source(SourceType),
+    /// JSON or JSONC, formatted with [`crate::Formatter::format_json`].
+    Json(JsonKind),
+}
+
+/// Returns how to format the file at `path`, based on its extension.
+///
+/// `.cjs` and `.cts` files are scripts, `.d.ts`, `.d.mts` and `.d.cts` files are declaration files,
+/// see [`SourceType::from_path`]. Extensions are case-insensitive.
+///
+/// # Errors
+/// Returns [`UnsupportedFileError`] if the extension isn't recognized.
+pub fn detect_file_kind(path: &Path) -> Result<FileKind, UnsupportedFileError> {
+    if let Some(source_type) = get_supported_source_type(path) {
+        return Ok(FileKind::Source(source_type));
+    }
+    let extension = path.extension().and_then(|extension| extension.to_str()).unwrap_or_default();
+    match extension.cow_to_ascii_lowercase().as_ref() {
+        "json" => Ok(FileKind::Json(JsonKind::Json)),
+        "jsonc" => Ok(FileKind::Json(JsonKind::Jsonc)),
+        _ => Err(UnsupportedFileError { path: path.to_string_lossy().into_owned() }),
+    }
+}
+
+/// The error of [`detect_file_kind`] for a file with an unrecognized extension.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsupportedFileError {
+    pub path: String,
+}
+
+impl fmt::Display for UnsupportedFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Unsupported file extension for {}, expected one of: .js, .mjs, .cjs, .jsx, .ts, .mts, .cts, .tsx, .d.ts, .d.mts, .d.cts, .json or .jsonc",
+            self.path
+        )
+    }
+}
+
+impl std::error::Error for UnsupportedFileError {}
+
+#[cfg(test)]
+mod tests {
+    use oxc_span::ModuleKind;
+
+    use super::*;
+
+    fn source_type(path: &str) -> SourceType {
+        match detect_file_kind(Path::new(path)) {
+            Ok(FileKind::Source(source_type)) => source_type,
+            kind => panic!("Expected a source type for {path}, got {kind:?}"),
+        }
+    }
+
+    #[test]
+    fn test_module_kind() {
+        for path in ["a.js", "a.mjs", "a.jsx", "a.ts", "a.mts", "a.tsx"] {
+            assert_eq!(source_type(path).module_kind(), ModuleKind::Module, "{path}");
+        }
+        for path in ["a.cjs", "a.cts"] {
+            assert_eq!(source_type(path).module_kind(), ModuleKind::Script, "{path}");
+        }
+    }
+
+    #[test]
+    fn test_typescript() {
+        for path in ["a.ts", "a.mts", "a.cts", "a.tsx"] {
+            assert!(source_type(path).is_typescript(), "{path}");
+            assert!(!source_type(path).is_typescript_definition(), "{path}");
+        }
+        for path in ["a.d.ts", "a.d.mts", "a.d.cts"] {
+            assert!(source_type(path).is_typescript_definition(), "{path}");
+        }
+        assert_eq!(source_type("a.d.mts").module_kind(), ModuleKind::Module);
+        assert_eq!(source_type("a.d.cts").module_kind(), ModuleKind::Script);
+    }
+
+    #[test]
+    fn test_uppercase_extensions() {
+        assert_eq!(source_type("App.TSX"), source_type("App.tsx"));
+        assert_eq!(source_type("index.JS"), source_type("index.js"));
+        assert_eq!(source_type("config.CTS"), source_type("config.cts"));
+        assert_eq!(source_type("types.D.TS"), source_type("types.d.ts"));
+        assert_eq!(detect_file_kind(Path::new("data.JSON")), Ok(FileKind::Json(JsonKind::Json)));
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(detect_file_kind(Path::new("package.json")), Ok(FileKind::Json(JsonKind::Json)));
+        assert_eq!(
+            detect_file_kind(Path::new(".vscode/settings.jsonc")),
+            Ok(FileKind::Json(JsonKind::Jsonc))
+        );
+    }
+
+    #[test]
+    fn test_unsupported_extension() {
+        for path in ["style.css", "README", "a.tsv"] {
+            let error = detect_file_kind(Path::new(path)).unwrap_err();
+            assert_eq!(error.path, path);
+            assert!(error.to_string().contains(".d.mts, .d.cts, .json or .jsonc"));
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use serde_json::Value;
+
+use crate::{FormatOptions, LineWidth, Oxfmtrc};
+
+/// Prettier options which have a counterpart in [`FormatOptions`].
+/// Their values are converted like the ones of an [`Oxfmtrc`], which uses the same names.
+const SUPPORTED_OPTIONS: [&str; 19] = [
+    "printWidth",
+    "tabWidth",
+    "useTabs",
+    "semi",
+    "singleQuote",
+    "jsxSingleQuote",
+    "quoteProps",
+    "trailingComma",
+    "bracketSpacing",
+    "bracketSameLine",
+    "arrowParens",
+    "endOfLine",
+    "objectWrap",
+    "singleAttributePerLine",
+    "experimentalTernaries",
+    "experimentalOperatorPosition",
+    "embeddedLanguageFormatting",
+    "requirePragma",
+    "insertPragma",
+];
+
+/// Prettier's default `printWidth`, which differs from ours.
+const PRETTIER_PRINT_WIDTH: u16 = 80;
+
+impl FormatOptions {
+    /// Loads the options of a Prettier config file like `.prettierrc` or `.prettierrc.json`,
+    /// see [`FormatOptions::from_prettier_value`].
+    ///
+    /// Comments are allowed, YAML and JavaScript config files aren't supported.
+    ///
+    /// # Errors
+    /// Returns an error if `json` isn't valid JSON or if an option value is invalid.
+    pub fn from_prettier_json(json: &str) -> Result<(Self, Vec<String>), String> {
+        let mut json = json.to_string();
+        json_strip_comments::strip(&mut json)
+            .map_err(|err| format!("Failed to strip comments from Prettier config: {err}"))?;
+        let value = serde_json::from_str(&json)
+            .map_err(|err| format!("Failed to parse Prettier config: {err}"))?;
+        Self::from_prettier_value(value)
+    }
+
+    /// Maps the options of a Prettier config onto [`FormatOptions`], e.g. `semi: false` onto
+    /// [`Semicolons::AsNeeded`](crate::Semicolons::AsNeeded).
+    ///
+    /// Options without a counterpart, like `overrides` or `plugins`, are ignored and reported
+    /// in the returned warnings. A missing `printWidth` defaults to Prettier's 80.
+    ///
+    /// # Errors
+    /// Returns an error if `value` isn't an object or if an option value is invalid.
+    pub fn from_prettier_value(value: Value) -> Result<(Self, Vec<String>), String> {
+        let Value::Object(config) = value else {
+            return Err("Prettier config must be an object".to_string());
+        };
+
+        let mut warnings = Vec::new();
+        let mut supported = serde_json::Map::new();
+        for (key, value) in config {
+            if SUPPORTED_OPTIONS.contains(&key.as_str()) {
+                supported.insert(key, value);
+            } else if key != "$schema" {
+                warnings.push(format!("Unsupported Prettier option `{key}` is ignored"));
+            }
+        }
+
+        let has_print_width = supported.contains_key("printWidth");
+        let oxfmtrc: Oxfmtrc = serde_json::from_value(Value::Object(supported))
+            .map_err(|err| format!("Invalid Prettier config: {err}"))?;
+        let (mut options, _) = oxfmtrc.into_options()?;
+        if !has_print_width {
+            options.line_width = LineWidth::try_from(PRETTIER_PRINT_WIDTH).unwrap();
+        }
+
+        Ok((options, warnings))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        ArrowParentheses, BracketSameLine, BracketSpacing, IndentStyle, IndentWidth,
+        QuoteProperties, QuoteStyle, Semicolons, TrailingCommas,
+    };
+
+    #[test]
+    fn test_prettierrc() {
+        let json = r#"{
+            // Shared by the whole team
+            "$schema": "https://json.schemastore.org/prettierrc",
+            "printWidth": 120,
+            "tabWidth": 4,
+            "useTabs": true,
+            "semi": false,
+            "singleQuote": true,
+            "quoteProps": "preserve",
+            "jsxSingleQuote": true,
+            "trailingComma": "es5",
+            "bracketSpacing": false,
+            "bracketSameLine": true,
+            "arrowParens": "avoid",
+            "plugins": ["prettier-plugin-tailwindcss"],
+            "overrides": [{ "files": "*.md", "options": { "proseWrap": "always" } }]
+        }"#;
+        let (options, warnings) = FormatOptions::from_prettier_json(json).unwrap();
+
+        assert_eq!(options.line_width.value(), 120);
+        assert_eq!(options.indent_width, IndentWidth::try_from(4).unwrap());
+        assert_eq!(options.indent_style, IndentStyle::Tab);
+        assert_eq!(options.semicolons, Semicolons::AsNeeded);
+        assert_eq!(options.quote_style, QuoteStyle::Single);
+        assert_eq!(options.quote_properties, QuoteProperties::Preserve);
+        assert_eq!(options.jsx_quote_style, QuoteStyle::Single);
+        assert_eq!(options.trailing_commas, TrailingCommas::Es5);
+        assert_eq!(options.bracket_spacing, BracketSpacing::from(false));
+        assert_eq!(options.bracket_same_line, BracketSameLine::from(true));
+        assert_eq!(options.arrow_parentheses, ArrowParentheses::AsNeeded);
+        assert_eq!(
+            warnings,
+            [
+                "Unsupported Prettier option `overrides` is ignored",
+                "Unsupported Prettier option `plugins` is ignored",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_prettier_defaults() {
+        let (options, warnings) = FormatOptions::from_prettier_value(json!({})).unwrap();
+        assert_eq!(options.line_width.value(), 80);
+        assert_eq!(options.semicolons, Semicolons::Always);
+        assert_eq!(options.quote_style, QuoteStyle::Double);
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_prettier_config() {
+        assert!(FormatOptions::from_prettier_value(json!({ "semi": "no" })).is_err());
+        assert!(FormatOptions::from_prettier_value(json!({ "printWidth": 0 })).is_err());
+        assert!(FormatOptions::from_prettier_value(json!(["semi"])).is_err());
+        assert!(FormatOptions::from_prettier_json("{ semi: false }").is_err());
+    }
+}
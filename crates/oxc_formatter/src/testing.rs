@@ -0,0 +1,291 @@
+//! Helpers for testing the formatter, e.g. from fuzzers or snapshot suites.
+//!
+//! [`check_stable`] verifies that formatting the formatted code again doesn't change it, and
+//! [`check_semantics_preserved`] that the formatted code parses to the same program.
+//! Both return a readable report of the first divergence, their `assert_*` versions panic with it.
+
+use oxc_allocator::{Allocator, Vec as ArenaVec};
+use oxc_ast::{AstBuilder, ast::*};
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_parser::{ParseOptions, Parser};
+use oxc_span::{ContentEq, SPAN, SourceType};
+use oxc_syntax::scope::ScopeFlags;
+use similar::TextDiff;
+
+use crate::{FormatOptions, Formatter, get_parse_options, get_parse_options_with};
+
+/// Checks that formatting the formatted code of `source_text` again results in the same code.
+///
+/// # Errors
+/// Returns the position of the first difference between both passes and a diff of them,
+/// or the parse errors if `source_text` or its formatted code can't be parsed.
+pub fn check_stable(
+    source_text: &str,
+    source_type: SourceType,
+    options: &FormatOptions,
+) -> Result<(), String> {
+    let first = format(source_text, source_type, options, "source text")?;
+    let second = format(&first, source_type, options, "formatted code")?;
+    if first == second {
+        return Ok(());
+    }
+
+    let offset = first
+        .bytes()
+        .zip(second.bytes())
+        .position(|(a, b)| a != b)
+        .unwrap_or_else(|| first.len().min(second.len()));
+    let line = first[..offset].matches('\n').count() + 1;
+    let column = offset - first[..offset].rfind('\n').map_or(0, |index| index + 1) + 1;
+    let diff = TextDiff::from_lines(&first, &second)
+        .unified_diff()
+        .header("first pass", "second pass")
+        .to_string();
+    Err(format!("Formatting isn't stable, the second pass differs at {line}:{column}\n{diff}"))
+}
+
+/// Checks that the formatted code of `source_text` parses to the same program,
+/// ignoring spans, comments and the raw text of literals.
+///
+/// Differences that don't change the program are ignored as well: quotes of property keys,
+/// shorthand properties, parentheses around functions, empty statements, unions and
+/// intersections of a single type, whitespace in JSX text and whitespace in tagged templates
+/// and `/* GraphQL */` templates, which may contain embedded languages like CSS.
+///
+/// # Errors
+/// Returns a diff of the source text and the formatted code if the programs differ,
+/// or the parse errors if `source_text` or its formatted code can't be parsed.
+pub fn check_semantics_preserved(
+    source_text: &str,
+    source_type: SourceType,
+    options: &FormatOptions,
+) -> Result<(), String> {
+    let formatted = format(source_text, source_type, options, "source text")?;
+
+    let allocator = Allocator::default();
+    // Parentheses aren't part of the program, even if `preserve_parens` keeps them.
+    let original = parse(&allocator, source_text, source_type, get_parse_options(), "source text")?;
+    let reparsed =
+        parse(&allocator, &formatted, source_type, get_parse_options(), "formatted code")?;
+    for program in [&mut *original, &mut *reparsed] {
+        let source_text = program.source_text;
+        Normalize { ast: AstBuilder::new(&allocator), source_text }.visit_program(program);
+    }
+    if original.content_eq(reparsed) {
+        return Ok(());
+    }
+
+    let diff = TextDiff::from_lines(source_text, &formatted)
+        .unified_diff()
+        .header("source text", "formatted code")
+        .to_string();
+    Err(format!("Formatting changed the program\n{diff}"))
+}
+
+/// Asserts that formatting `source_text` is stable, see [`check_stable`].
+///
+/// # Panics
+/// Panics with the report of [`check_stable`].
+pub fn assert_stable(source_text: &str, source_type: SourceType, options: &FormatOptions) {
+    if let Err(report) = check_stable(source_text, source_type, options) {
+        panic!("{report}");
+    }
+}
+
+/// Asserts that formatting `source_text` preserves the program, see [`check_semantics_preserved`].
+///
+/// # Panics
+/// Panics with the report of [`check_semantics_preserved`].
+pub fn assert_semantics_preserved(
+    source_text: &str,
+    source_type: SourceType,
+    options: &FormatOptions,
+) {
+    if let Err(report) = check_semantics_preserved(source_text, source_type, options) {
+        panic!("{report}");
+    }
+}
+
+fn format(
+    source_text: &str,
+    source_type: SourceType,
+    options: &FormatOptions,
+    name: &str,
+) -> Result<String, String> {
+    let allocator = Allocator::default();
+    let program =
+        parse(&allocator, source_text, source_type, get_parse_options_with(options), name)?;
+    Ok(Formatter::new(&allocator, options.clone()).build(program))
+}
+
+fn parse<'a>(
+    allocator: &'a Allocator,
+    source_text: &'a str,
+    source_type: SourceType,
+    parse_options: ParseOptions,
+    name: &str,
+) -> Result<&'a mut Program<'a>, String> {
+    let ret = Parser::new(allocator, source_text, source_type).with_options(parse_options).parse();
+    if ret.errors.is_empty() {
+        Ok(allocator.alloc(ret.program))
+    } else {
+        let errors = ret.errors.iter().map(ToString::to_string).collect::<Vec<_>>().join("\n");
+        Err(format!("Failed to parse the {name}:\n{errors}\n\n{source_text}"))
+    }
+}
+
+/// Rewrites the parts of a program the formatter is allowed to change into a canonical form.
+struct Normalize<'a> {
+    ast: AstBuilder<'a>,
+    source_text: &'a str,
+}
+
+impl<'a> VisitMut<'a> for Normalize<'a> {
+    fn visit_statements(&mut self, it: &mut ArenaVec<'a, Statement<'a>>) {
+        it.retain(|statement| !matches!(statement, Statement::EmptyStatement(_)));
+        walk_mut::walk_statements(self, it);
+    }
+
+    fn visit_object_property(&mut self, it: &mut ObjectProperty<'a>) {
+        it.shorthand = false;
+        walk_mut::walk_object_property(self, it);
+    }
+
+    fn visit_function(&mut self, it: &mut Function<'a>, flags: ScopeFlags) {
+        it.pife = false;
+        walk_mut::walk_function(self, it, flags);
+    }
+
+    fn visit_arrow_function_expression(&mut self, it: &mut ArrowFunctionExpression<'a>) {
+        it.pife = false;
+        walk_mut::walk_arrow_function_expression(self, it);
+    }
+
+    fn visit_property_key(&mut self, it: &mut PropertyKey<'a>) {
+        let value = match it {
+            PropertyKey::StaticIdentifier(identifier) => identifier.name,
+            PropertyKey::NumericLiteral(number) => self.ast.atom(&number.value.to_string()),
+            _ => return walk_mut::walk_property_key(self, it),
+        };
+        *it = PropertyKey::StringLiteral(self.ast.alloc_string_literal(SPAN, value, None));
+    }
+
+    fn visit_ts_enum_member_name(&mut self, it: &mut TSEnumMemberName<'a>) {
+        if let TSEnumMemberName::Identifier(identifier) = it {
+            *it = self.ast.ts_enum_member_name_string(SPAN, identifier.name, None);
+        }
+    }
+
+    fn visit_import_attribute_key(&mut self, it: &mut ImportAttributeKey<'a>) {
+        if let ImportAttributeKey::Identifier(identifier) = it {
+            *it = self.ast.import_attribute_key_string_literal(SPAN, identifier.name, None);
+        }
+    }
+
+    fn visit_ts_type(&mut self, it: &mut TSType<'a>) {
+        walk_mut::walk_ts_type(self, it);
+        let single = match it {
+            TSType::TSUnionType(union) if union.types.len() == 1 => union.types.pop(),
+            TSType::TSIntersectionType(intersection) if intersection.types.len() == 1 => {
+                intersection.types.pop()
+            }
+            _ => None,
+        };
+        if let Some(single) = single {
+            *it = single;
+        }
+    }
+
+    fn visit_tagged_template_expression(&mut self, it: &mut TaggedTemplateExpression<'a>) {
+        self.collapse_template_whitespace(&mut it.quasi);
+        walk_mut::walk_tagged_template_expression(self, it);
+    }
+
+    fn visit_template_literal(&mut self, it: &mut TemplateLiteral<'a>) {
+        if self.source_text[..it.span.start as usize].trim_end().ends_with("/* GraphQL */") {
+            self.collapse_template_whitespace(it);
+        }
+        walk_mut::walk_template_literal(self, it);
+    }
+
+    /// Replaces the text of `children` with the text JSX evaluates it to, merging adjacent text
+    /// and `{" "}` expressions.
+    fn visit_jsx_children(&mut self, it: &mut ArenaVec<'a, JSXChild<'a>>) {
+        let mut children = self.ast.vec_with_capacity(it.len());
+        let mut text = String::new();
+        for mut child in it.drain(..) {
+            match &child {
+                JSXChild::Text(jsx_text) => {
+                    text.push_str(&jsx_text_value(&jsx_text.value));
+                    continue;
+                }
+                JSXChild::ExpressionContainer(container) => {
+                    if let JSXExpression::StringLiteral(string) = &container.expression
+                        && string.value.trim().is_empty()
+                    {
+                        text.push_str(&string.value);
+                        continue;
+                    }
+                }
+                _ => {}
+            }
+            self.push_jsx_text(&mut children, &mut text);
+            self.visit_jsx_child(&mut child);
+            children.push(child);
+        }
+        self.push_jsx_text(&mut children, &mut text);
+        *it = children;
+    }
+}
+
+impl<'a> Normalize<'a> {
+    fn collapse_template_whitespace(&self, template: &mut TemplateLiteral<'a>) {
+        for quasi in &mut template.quasis {
+            let collapsed = collapse_whitespace(quasi.value.raw.trim());
+            quasi.value.raw = self.ast.atom(&collapsed);
+            quasi.value.cooked = None;
+        }
+    }
+
+    /// Pushes the non-empty `text` with collapsed whitespace, which the formatter may change.
+    fn push_jsx_text(&self, children: &mut ArenaVec<'a, JSXChild<'a>>, text: &mut String) {
+        if text.is_empty() {
+            return;
+        }
+        let collapsed = collapse_whitespace(text);
+        children.push(self.ast.jsx_child_text(SPAN, self.ast.atom(&collapsed), None));
+        text.clear();
+    }
+}
+
+/// Replaces every run of whitespace in `text` with a single space.
+fn collapse_whitespace(text: &str) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    for char in text.chars() {
+        if !char.is_whitespace() {
+            collapsed.push(char);
+        } else if !collapsed.ends_with(' ') {
+            collapsed.push(' ');
+        }
+    }
+    collapsed
+}
+
+/// Returns the text a JSX text evaluates to: lines are trimmed and non-empty lines joined by a space.
+fn jsx_text_value(text: &str) -> String {
+    if !text.contains('\n') {
+        return text.to_string();
+    }
+    let lines = text.split('\n').collect::<Vec<_>>();
+    let last = lines.len() - 1;
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            let line = if index == 0 { line } else { line.trim_start() };
+            if index == last { line } else { line.trim_end() }
+        })
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -0,0 +1,120 @@
+//! Minimal edits turning a source text into its formatted code, see [`crate::Formatter::format_to_edits`].
+
+use std::{
+    ops::Range,
+    time::{Duration, Instant},
+};
+
+use similar::{Algorithm, DiffTag, TextDiff};
+
+use oxc_span::Span;
+
+/// Replaces the source text in [`TextEdit::span`] with [`TextEdit::text`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    /// The replaced span of the source text, empty for insertions.
+    pub span: Span,
+    /// The replacement, empty for deletions.
+    pub text: String,
+}
+
+/// The time after which diffing the lines gives up and reports the remaining lines as changed.
+/// Diffing lines takes quadratic time in the worst case, e.g. when minified code is formatted.
+const LINE_DIFF_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// The maximum combined length in bytes of a block of changed lines whose characters are diffed.
+/// Diffing characters takes quadratic time in the worst case, e.g. for minified code, so larger
+/// blocks are replaced as a whole.
+const MAX_CHAR_DIFF_LEN: usize = 8 * 1024;
+
+/// Returns the edits that turn `source_text` into `formatted`.
+///
+/// The lines of both texts are diffed first, then the characters of every block of changed lines,
+/// so that a changed line only produces edits for the characters that differ.
+/// Blocks longer than [`MAX_CHAR_DIFF_LEN`] produce a single edit, and so do all remaining lines
+/// once diffing the lines takes longer than [`LINE_DIFF_TIMEOUT`].
+/// The edits are sorted by span and don't overlap or touch each other.
+pub fn text_edits(source_text: &str, formatted: &str) -> Vec<TextEdit> {
+    let mut edits = Vec::new();
+
+    let lines = TextDiff::configure()
+        .algorithm(Algorithm::Myers)
+        .deadline(Instant::now() + LINE_DIFF_TIMEOUT)
+        .diff_lines(source_text, formatted);
+    let old_offsets = offsets(lines.old_slices());
+    let new_offsets = offsets(lines.new_slices());
+
+    for op in lines.ops() {
+        let (tag, old_lines, new_lines) = op.as_tag_tuple();
+        if tag == DiffTag::Equal {
+            continue;
+        }
+
+        let old = old_offsets[old_lines.start]..old_offsets[old_lines.end];
+        let new = new_offsets[new_lines.start]..new_offsets[new_lines.end];
+        if tag == DiffTag::Replace && old.len() + new.len() <= MAX_CHAR_DIFF_LEN {
+            for (old, new) in
+                char_edits(&source_text[old.clone()], &formatted[new.clone()], old.start, new.start)
+            {
+                push_edit(&mut edits, old, &formatted[new]);
+            }
+        } else {
+            push_edit(&mut edits, old, &formatted[new]);
+        }
+    }
+
+    edits
+}
+
+/// Returns the byte ranges of the changed characters in `source_text` and `formatted`,
+/// which start at `old_start` and `new_start` respectively.
+fn char_edits(
+    source_text: &str,
+    formatted: &str,
+    old_start: usize,
+    new_start: usize,
+) -> Vec<(Range<usize>, Range<usize>)> {
+    let chars =
+        TextDiff::configure().algorithm(Algorithm::Myers).diff_chars(source_text, formatted);
+    let old_offsets = offsets(chars.old_slices());
+    let new_offsets = offsets(chars.new_slices());
+
+    chars
+        .ops()
+        .iter()
+        .filter(|op| op.tag() != DiffTag::Equal)
+        .map(|op| {
+            let (old, new) = (op.old_range(), op.new_range());
+            (
+                old_start + old_offsets[old.start]..old_start + old_offsets[old.end],
+                new_start + new_offsets[new.start]..new_start + new_offsets[new.end],
+            )
+        })
+        .collect()
+}
+
+/// Returns the byte offset of every slice, followed by the end of the last slice.
+fn offsets(slices: &[&str]) -> Vec<usize> {
+    let mut offset = 0;
+    let mut offsets = Vec::with_capacity(slices.len() + 1);
+    offsets.push(0);
+    for slice in slices {
+        offset += slice.len();
+        offsets.push(offset);
+    }
+    offsets
+}
+
+/// Pushes the edit replacing `old` with `text`, merged into the last edit if it ends where `old` starts.
+#[expect(clippy::cast_possible_truncation)]
+fn push_edit(edits: &mut Vec<TextEdit>, old: Range<usize>, text: &str) {
+    let span = Span::new(old.start as u32, old.end as u32);
+    if let Some(last) = edits.last_mut()
+        && last.span.end == span.start
+    {
+        last.span.end = span.end;
+        last.text.push_str(text);
+    } else {
+        edits.push(TextEdit { span, text: text.to_string() });
+    }
+}
@@ -10,8 +10,7 @@ use crate::{
     },
     utils::{
         format_node_without_trailing_comments::FormatNodeWithoutTrailingComments,
-        member_chain::is_member_call_chain,
-        object::{format_property_key, write_member_name},
+        member_chain::is_member_call_chain, object::write_member_name,
     },
     write,
     write::{BinaryLikeExpression, FormatJsArrowFunctionExpressionOptions, FormatWrite},
@@ -269,7 +268,7 @@ impl<'a> AssignmentLike<'a, '_> {
                 if property.computed {
                     write!(f, ["[", property.key(), "]"]);
                 } else {
-                    format_property_key(property.key(), f);
+                    write_member_name(property.key(), f);
                 }
 
                 // Write optional, definite, and type annotation
@@ -637,7 +636,7 @@ fn should_break_after_operator<'a>(
     is_left_short: bool,
     f: &Formatter<'_, 'a>,
 ) -> bool {
-    if right.is_jsx() {
+    if right.without_parentheses().is_jsx() {
         return false;
     }
 
@@ -662,6 +661,8 @@ fn should_break_after_operator<'a>(
         Expression::LogicalExpression(logical) => {
             !BinaryLikeExpression::can_inline_logical_expr(logical)
         }
+        // Curious ternaries are laid out as a case-like chain that starts on its own line.
+        Expression::ConditionalExpression(_) if f.options().experimental_ternaries => true,
         Expression::ConditionalExpression(conditional) => match &conditional.test {
             Expression::BinaryExpression(_) => true,
             Expression::LogicalExpression(logical) => {
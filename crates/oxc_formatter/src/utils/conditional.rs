@@ -6,6 +6,7 @@ use oxc_span::{GetSpan, Span};
 use crate::{
     Format,
     ast_nodes::{AstNode, AstNodes},
+    format_args,
     formatter::{
         Formatter,
         prelude::*,
@@ -103,6 +104,11 @@ impl ConditionalLayout {
         matches!(self, Self::NestedTest)
     }
 
+    #[inline]
+    fn is_nested_consequent(self) -> bool {
+        matches!(self, Self::NestedConsequent)
+    }
+
     #[inline]
     fn is_nested_alternate(self) -> bool {
         matches!(self, Self::NestedAlternate)
@@ -200,7 +206,7 @@ impl<'a> FormatConditionalLike<'a, '_> {
     fn is_jsx_conditional_chain(&self) -> bool {
         #[inline]
         fn has_jsx_expression(expr: &Expression) -> bool {
-            match expr {
+            match expr.without_parentheses() {
                 Expression::JSXElement(_) | Expression::JSXFragment(_) => true,
                 Expression::ConditionalExpression(conditional) => recurse(conditional),
                 _ => false,
@@ -453,8 +459,26 @@ impl<'a> FormatConditionalLike<'a, '_> {
                 write!(f, [FormatNodeWithoutTrailingComments(conditional.false_type())]);
             }
         });
+        // A conditional in the alternate continues the chain at the same indentation:
+        // ```typescript
+        // type A<T> = T extends string
+        //   ? "string"
+        //   : T extends number
+        //   ? "number"
+        //   : "object";
+        // ```
+        let is_nested_alternate = match self.conditional {
+            ConditionalLike::ConditionalExpression(conditional) => {
+                matches!(conditional.alternate, Expression::ConditionalExpression(_))
+            }
+            ConditionalLike::TSConditionalType(conditional) => {
+                matches!(conditional.false_type, TSType::TSConditionalType(_))
+            }
+        };
         let format_alternative = format_with(|f| {
-            if f.options().indent_style.is_space() {
+            if is_nested_alternate {
+                write!(f, format_alternative);
+            } else if f.options().indent_style.is_space() {
                 write!(f, [align(2, &format_alternative)]);
             } else {
                 write!(f, [indent(&format_alternative)]);
@@ -506,6 +530,30 @@ impl<'a> Format<'a> for FormatConditionalLike<'a, '_> {
         let should_extra_indent = self.should_extra_indent(layout);
         let is_jsx_chain = self.options.jsx_chain || layout.is_jsx_chain();
 
+        if f.options().experimental_ternaries
+            && let ConditionalLike::ConditionalExpression(conditional) = self.conditional
+        {
+            let format_inner = format_with(|f| {
+                if layout.is_nested_consequent() {
+                    // A ternary in the consequent always breaks, so its cases stand out from the parent's.
+                    write!(f, [expand_parent()]);
+                }
+
+                FormatExperimentalTernaryChain(conditional).fmt(f);
+
+                if !should_extra_indent && self.is_parent_static_member_expression(layout) {
+                    write!(f, [soft_line_break()]);
+                }
+            });
+
+            if layout.is_nested_test() || should_extra_indent {
+                write!(f, [group(&soft_block_indent(&group(&format_inner)))]);
+            } else {
+                write!(f, [group(&format_inner)]);
+            }
+            return;
+        }
+
         let format_inner = format_with(|f| {
             self.format_test(f, layout);
 
@@ -588,6 +636,90 @@ impl<'a> Format<'a> for FormatConditionalLike<'a, '_> {
     }
 }
 
+/// Formats a [`ConditionalExpression`] and the conditionals in its alternate as a case-like chain
+/// when [`experimental_ternaries`](crate::FormatOptions::experimental_ternaries) is enabled.
+///
+/// The `?` stays on the line of the test. A lone conditional moves its consequent to an indented
+/// line when it breaks, while every case of a chain stays on one line as long as it fits:
+///
+/// ```javascript
+/// const animal =
+///   isBird ? "bird"
+///   : isCat ? "cat"
+///   : isLongNamedAnimal ?
+///     "an animal with a name so long that it doesn't fit on the line of its case"
+///   : "unknown";
+/// ```
+struct FormatExperimentalTernaryChain<'a, 'b>(&'b AstNode<'a, ConditionalExpression<'a>>);
+
+impl<'a> Format<'a> for FormatExperimentalTernaryChain<'a, '_> {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) {
+        let is_chain = matches!(self.0.alternate, Expression::ConditionalExpression(_));
+        let mut current = self.0;
+
+        loop {
+            let format_case = format_with(|f| {
+                let format_test = format_with(|f| {
+                    write!(f, FormatNodeWithoutTrailingComments(current.test()));
+                    format_trailing_comments(
+                        current.test.span().end,
+                        current.consequent.span().start,
+                        b'?',
+                        f,
+                    );
+                });
+
+                // Tests after the first one follow a `: `, so their continuation lines are aligned with it.
+                if current.span == self.0.span {
+                    write!(f, format_test);
+                } else {
+                    write!(f, [align(2, &format_test)]);
+                }
+
+                write!(
+                    f,
+                    [
+                        space(),
+                        "?",
+                        indent(&format_args!(
+                            soft_line_break_or_space(),
+                            format_with(|f| {
+                                write!(f, FormatNodeWithoutTrailingComments(current.consequent()));
+                                format_trailing_comments(
+                                    current.consequent.span().end,
+                                    current.alternate.span().start,
+                                    b':',
+                                    f,
+                                );
+                            })
+                        ))
+                    ]
+                );
+            });
+
+            if is_chain {
+                write!(f, [group(&format_case)]);
+            } else {
+                write!(f, format_case);
+            }
+
+            write!(f, [soft_line_break_or_space(), ":", space()]);
+
+            if let AstNodes::ConditionalExpression(alternate) = current.alternate().as_ast_nodes() {
+                current = alternate;
+            } else {
+                let format_alternate = FormatNodeWithoutTrailingComments(current.alternate());
+                if f.options().indent_style.is_space() {
+                    write!(f, [align(2, &format_alternate)]);
+                } else {
+                    write!(f, [indent(&format_alternate)]);
+                }
+                return;
+            }
+        }
+    }
+}
+
 /// Formats JSX consequent with conditional wrapping
 fn format_jsx_chain_consequent<'a, 'b>(
     expression: &'b AstNode<'a, Expression<'a>>,
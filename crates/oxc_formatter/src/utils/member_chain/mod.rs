@@ -128,7 +128,7 @@ impl<'a, 'b> MemberChain<'a, 'b> {
             })
             .peekable();
 
-        let mut calls_count = 0;
+        let mut calls_count: usize = 0;
         let mut has_function_like_argument = false;
         let mut has_complex_args = false;
 
@@ -142,7 +142,7 @@ impl<'a, 'b> MemberChain<'a, 'b> {
 
             has_complex_args = has_complex_args || !has_simple_arguments(call);
 
-            if calls_count > 2 && has_complex_args {
+            if calls_count >= usize::from(f.options().member_chain_min_calls) && has_complex_args {
                 return true;
             }
         }
@@ -1,10 +1,19 @@
+use std::borrow::Cow;
+
+use oxc_allocator::StringBuilder;
 use oxc_ast::ast::*;
 use oxc_span::GetSpan;
 
+use unicode_width::UnicodeWidthStr;
+
 use crate::{
-    Buffer, Format,
+    Buffer, Format, QuoteProperties,
     ast_nodes::{AstNode, AstNodes},
-    formatter::Formatter,
+    formatter::{
+        Formatter,
+        prelude::text,
+        token::number::{NumberFormatOptions, format_number_token},
+    },
     utils::string::{
         FormatLiteralStringToken, StringLiteralParentKind, is_identifier_name_patched,
     },
@@ -13,14 +22,14 @@ use crate::{
 
 pub fn format_property_key<'a>(key: &AstNode<'a, PropertyKey<'a>>, f: &mut Formatter<'_, 'a>) {
     if let PropertyKey::StringLiteral(s) = key.as_ref() {
-        // `"constructor"` property in the class should be kept quoted
-        let kind = if matches!(key.parent, AstNodes::PropertyDefinition(_))
-            && matches!(key.as_ref(), PropertyKey::StringLiteral(string) if string.value == "constructor")
+        let kind = string_key_parent_kind(key, s);
+
+        if kind == StringLiteralParentKind::Member
+            && let Some(name) = unescaped_identifier_key(s, f)
         {
-            StringLiteralParentKind::Expression
-        } else {
-            StringLiteralParentKind::Member
-        };
+            write!(f, text(name));
+            return;
+        }
 
         FormatLiteralStringToken::new(
             f.source_text().text_for(s.as_ref()),
@@ -39,18 +48,43 @@ pub fn write_member_name<'a>(
     f: &mut Formatter<'_, 'a>,
 ) -> usize {
     if let AstNodes::StringLiteral(string) = key.as_ast_nodes() {
-        let format = FormatLiteralStringToken::new(
-            f.source_text().text_for(string),
-            false,
-            StringLiteralParentKind::Member,
-        )
-        .clean_text(f);
+        let kind = string_key_parent_kind(key, string);
+
+        if kind == StringLiteralParentKind::Member
+            && let Some(name) = unescaped_identifier_key(string, f)
+        {
+            string.format_leading_comments(f);
+            write!(f, text(name));
+            string.format_trailing_comments(f);
+
+            return name.width();
+        }
+
+        let format = FormatLiteralStringToken::new(f.source_text().text_for(string), false, kind)
+            .clean_text(f);
 
         string.format_leading_comments(f);
         write!(f, format);
         string.format_trailing_comments(f);
 
         format.width()
+    } else if let AstNodes::IdentifierName(ident) = key.as_ast_nodes()
+        && matches!(key.parent, AstNodes::ObjectProperty(_) | AstNodes::PropertyDefinition(_))
+        && f.context().is_quote_needed()
+    {
+        ident.format_leading_comments(f);
+        let width = write_quoted_member_name(ident.name().as_str(), f);
+        ident.format_trailing_comments(f);
+
+        width
+    } else if let AstNodes::NumericLiteral(number) = key.as_ast_nodes()
+        && should_quote_numeric_key(number, f)
+    {
+        number.format_leading_comments(f);
+        let width = write_quoted_member_name(&numeric_key_text(number, f), f);
+        number.format_trailing_comments(f);
+
+        width
     } else {
         write!(f, key);
 
@@ -58,10 +92,190 @@ pub fn write_member_name<'a>(
     }
 }
 
+/// Returns the kind used to format a string literal key.
+///
+/// Some keys must keep their quotes, so they are formatted like string literals in expressions:
+/// - `"constructor"` property in the class, because an unquoted one is a syntax error.
+/// - `"__proto__"` property in the object literal, which has special semantics there.
+fn string_key_parent_kind(
+    key: &AstNode<'_, PropertyKey<'_>>,
+    string: &StringLiteral<'_>,
+) -> StringLiteralParentKind {
+    let must_keep_quotes = match key.parent {
+        AstNodes::PropertyDefinition(_) => string.value == "constructor",
+        AstNodes::ObjectProperty(_) => string.value == "__proto__",
+        _ => false,
+    };
+
+    if must_keep_quotes {
+        StringLiteralParentKind::Expression
+    } else {
+        StringLiteralParentKind::Member
+    }
+}
+
+/// Writes `name` as a quoted property key and returns its width.
+///
+/// The quotes and escapes are chosen the same way as for string literal keys, so identifier keys
+/// that get quoted (e.g. by [`crate::QuoteProperties::Consistent`]) are printed like string literal keys.
+pub fn write_quoted_member_name(name: &str, f: &mut Formatter<'_, '_>) -> usize {
+    let quote = f.options().quote_style.as_str();
+    let quoted =
+        StringBuilder::from_strs_array_in([quote, name, quote], f.context().allocator()).into_str();
+
+    // Identifier keys are passed with their cooked name, e.g. `a` for `\u0061`, so neither they nor
+    // numeric keys contain quotes, backslashes, or line breaks. There is nothing to normalize and
+    // we can write the quoted name directly.
+    if !name.bytes().any(|b| matches!(b, b'"' | b'\'' | b'\\' | b'\r')) {
+        write!(f, text(quoted));
+        return name.width() + 2;
+    }
+
+    let format = FormatLiteralStringToken::new(
+        quoted,
+        /* jsx */ false,
+        StringLiteralParentKind::Expression,
+    )
+    .clean_text(f);
+
+    write!(f, format);
+
+    format.width()
+}
+
 /// Determine if the property key string literal should preserve its quotes
-pub fn should_preserve_quote(key: &PropertyKey<'_>, f: &Formatter<'_, '_>) -> bool {
-    matches!(&key, PropertyKey::StringLiteral(string) if {
-        let quote_less_content = f.source_text().text_for(&string.span.shrink(1));
-        !is_identifier_name_patched(quote_less_content)
+pub fn should_preserve_quote(key: &PropertyKey<'_>) -> bool {
+    matches!(&key, PropertyKey::StringLiteral(string) if string_key_requires_quotes(string))
+}
+
+/// Determine if the properties of an object expression should all be quoted when
+/// [`crate::QuoteProperties::Consistent`] is used.
+///
+/// Only string literal keys can require quotes, numeric keys never do. However, if a numeric key
+/// can't be quoted safely (e.g. `1e2`), or there is a BigInt key (e.g. `1n`), which is never quoted,
+/// we leave all keys as they are rather than producing a mix of quoted and unquoted keys.
+pub fn object_has_property_requiring_quotes(
+    properties: &[ObjectPropertyKind<'_>],
+    f: &Formatter<'_, '_>,
+) -> bool {
+    let mut requires_quotes = false;
+    for property in properties.iter().filter_map(ObjectPropertyKind::as_property) {
+        if property.computed {
+            continue;
+        }
+        match &property.key {
+            PropertyKey::StringLiteral(string) => {
+                // `"__proto__"` always keeps its quotes, see `string_key_parent_kind`.
+                requires_quotes |=
+                    string.value == "__proto__" || string_key_requires_quotes(string);
+            }
+            PropertyKey::NumericLiteral(number) if !can_quote_numeric_key(number, f) => {
+                return false;
+            }
+            PropertyKey::BigIntLiteral(_) => return false,
+            _ => {}
+        }
+    }
+    requires_quotes
+}
+
+/// Determine if the numeric literal is an object property key that should be printed quoted,
+/// e.g. `{ 1: a, "a-b": b }` -> `{ "1": a, "a-b": b }`.
+pub fn should_quote_numeric_key(number: &AstNode<'_, NumericLiteral<'_>>, f: &Formatter) -> bool {
+    matches!(number.parent, AstNodes::ObjectProperty(property)
+        if !property.computed && property.key.span() == number.span)
+        && f.context().is_quote_needed()
+        && can_quote_numeric_key(number, f)
+}
+
+/// Determine if quoting the numeric key keeps its meaning.
+///
+/// Based on <https://github.com/prettier/prettier/blob/3.6.2/src/language-js/print/property.js>,
+/// only simple numbers (e.g. `1`, `1.5`) are quoted, and only when the printed number is exactly
+/// its value, which rules out keys like `1e2`, `0x1`, `1.0`, or `999999999999999999`. Like in
+/// prettier, the printed number is checked, so `1.50` is quoted as `"1.5"`.
+/// Number-like keys have a different meaning in TypeScript, so they are never quoted there.
+fn can_quote_numeric_key(number: &NumericLiteral<'_>, f: &Formatter<'_, '_>) -> bool {
+    if f.context().source_type().is_typescript() {
+        return false;
+    }
+
+    let text = numeric_key_text(number, f);
+    is_simple_number(&text) && number.value.to_string() == text
+}
+
+/// Returns the text a numeric key is printed with, e.g. `1.5` for `1.50`.
+pub fn numeric_key_text<'a>(number: &NumericLiteral<'_>, f: &Formatter<'_, 'a>) -> Cow<'a, str> {
+    format_number_token(
+        f.source_text().text_for(number),
+        NumberFormatOptions::keep_one_trailing_decimal_zero(),
+    )
+    .cleaned_text()
+}
+
+fn is_simple_number(text: &str) -> bool {
+    let is_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    match text.split_once('.') {
+        Some((integer, fraction)) => is_digits(integer) && is_digits(fraction),
+        None => is_digits(text),
+    }
+}
+
+/// Determine if any non-computed enum member name is a string literal that requires quotes,
+/// e.g. `enum E { "a-b" = 1 }`.
+pub fn enum_has_member_requiring_quotes(members: &[TSEnumMember<'_>]) -> bool {
+    members.iter().any(|member| {
+        matches!(&member.id, TSEnumMemberName::String(string) if string_key_requires_quotes(string))
     })
 }
+
+/// Determine if any property or method signature of an interface body or a type literal has a
+/// string literal key that requires quotes.
+///
+/// Index, call, and construct signatures have no key, so they never count.
+pub fn signatures_have_property_requiring_quotes(signatures: &[TSSignature<'_>]) -> bool {
+    signatures.iter().any(|signature| {
+        let key = match signature {
+            TSSignature::TSPropertySignature(property) => &property.key,
+            TSSignature::TSMethodSignature(method) => &method.key,
+            _ => return false,
+        };
+        should_preserve_quote(key)
+    })
+}
+
+/// Determine if any key of an import attributes clause is a string literal that requires quotes,
+/// e.g. `with { "content-type": "json" }`.
+pub fn import_attributes_has_key_requiring_quotes(attributes: &[ImportAttribute<'_>]) -> bool {
+    attributes.iter().any(|attribute| {
+        matches!(&attribute.key, ImportAttributeKey::StringLiteral(string) if string_key_requires_quotes(string))
+    })
+}
+
+/// Determine if the string literal key requires quotes.
+///
+/// The decision is based on the cooked value rather than the raw content, so escapes that
+/// still result in an identifier (e.g. `"\u0066oo"`) don't require quotes.
+fn string_key_requires_quotes(string: &StringLiteral<'_>) -> bool {
+    !is_identifier_name_patched(string.value.as_str())
+}
+
+/// Returns the cooked value of a string literal key that contains escapes but can be printed as
+/// an identifier, e.g. `"\u0066oo"` -> `foo`.
+///
+/// Keys without escapes are handled by [`FormatLiteralStringToken`], which works on the raw content.
+fn unescaped_identifier_key<'a>(
+    string: &StringLiteral<'a>,
+    f: &Formatter<'_, 'a>,
+) -> Option<&'a str> {
+    let can_remove_quotes = match f.options().quote_properties {
+        QuoteProperties::AsNeeded => true,
+        QuoteProperties::Preserve => false,
+        QuoteProperties::Consistent => !f.context().is_quote_needed(),
+    };
+
+    let raw_content = f.source_text().text_for(&string.span.shrink(1));
+    let name = string.value.as_str();
+    (can_remove_quotes && raw_content.contains('\\') && is_identifier_name_patched(name))
+        .then_some(name)
+}
@@ -73,7 +73,17 @@ impl CleanedStringLiteralText<'_> {
 
 impl<'a> Format<'a> for CleanedStringLiteralText<'a> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) {
-        text(f.context().allocator().alloc_str(&self.text)).fmt(f);
+        // Only a rewritten string is allocated, an unchanged one is printed from the source text
+        let string = match &self.text {
+            Cow::Borrowed(string) => string,
+            Cow::Owned(string) => f.context().allocator().alloc_str(string),
+        };
+        // Line continuations keep their line breaks as they are in the source
+        if string.contains(['\n', '\r']) {
+            verbatim_text(string).fmt(f);
+        } else {
+            text(string).fmt(f);
+        }
     }
 }
 
@@ -225,23 +235,13 @@ impl<'a> LiteralStringNormalizer<'a> {
     }
 
     fn can_remove_number_quotes_by_file_type(&self, source_type: SourceType) -> bool {
-        let text_to_check = self.raw_content();
-
-        if text_to_check.bytes().next().is_some_and(|b| b.is_ascii_digit()) {
-            if let Ok(parsed) = text_to_check.parse::<f64>() {
-                // In TypeScript, numbers like members have different meaning from numbers.
-                // Hence, if we see a number, we bail straightaway
-                if source_type.is_typescript() {
-                    return false;
-                }
-
-                // Rule out inexact floats and octal literals
-                return parsed.to_string() == text_to_check;
-            }
-
+        // In TypeScript, numbers like members have different meaning from numbers.
+        // Hence, if we see a number, we bail straightaway
+        if source_type.is_typescript() {
             return false;
         }
-        false
+
+        is_simple_integer_key(self.raw_content())
     }
 
     fn normalize_type_member(
@@ -308,17 +308,19 @@ impl<'a> Format<'a> for FormatLiteralStringToken<'a> {
 ///
 /// - escaping `preferred_quote`
 /// - unescape alternate quotes of `preferred_quote` if `quotes_will_change`
-/// - normalize the new lines by replacing `\r\n` and `\r` with `\n`.
+///
+/// Line continuations are left untouched because their line breaks are part of the string and
+/// must not follow the configured line ending.
 ///
 /// The function allocates a new string only if at least one change is performed.
 ///
-/// In the following example `"` is escaped and the newline is normalized.
+/// In the following example `"` is escaped and the line continuation is kept.
 ///
 /// ```
 /// use biome_formatter::token::string::{normalize_string, Quote};
 /// assert_eq!(
-///     normalize_string(" \"He\\llo\\tworld\" \\' \\' \r\n ", Quote::Double, true),
-///     " \\\"He\\llo\\tworld\\\" ' ' \n ",
+///     normalize_string(" \"He\\llo\\tworld\" \\' \\' \\\r\n ", Quote::Double, true),
+///     " \\\"He\\llo\\tworld\\\" ' ' \\\r\n ",
 /// );
 /// ```
 pub fn normalize_string(
@@ -336,19 +338,7 @@ pub fn normalize_string(
             // If the next character is escaped
             b'\\' => {
                 if let Some(&(escaped_index, escaped)) = bytes.peek() {
-                    if escaped == b'\r' {
-                        bytes.next(); // consume the \r
-                        // Copy up to (not including) the \r
-                        reduced_string.push_str(&raw_content[copy_start..escaped_index]);
-                        if bytes.next_if(|(_, b)| *b == b'\n').is_some() {
-                            // \\\r\n -> keep \\ and \n, skip \r
-                            // The \n will be included when we copy from copy_start
-                        } else {
-                            // \\\r -> convert \r to \n
-                            reduced_string.push('\n');
-                        }
-                        copy_start = escaped_index + 1;
-                    } else if quotes_will_change && escaped == alternate_quote {
+                    if quotes_will_change && escaped == alternate_quote {
                         bytes.next(); // consume the escaped character
                         // Unescape alternate quotes if quotes are changing
                         reduced_string.push_str(&raw_content[copy_start..byte_index]);
@@ -358,17 +348,6 @@ pub fn normalize_string(
                     }
                 }
             }
-            // Normalize \r\n and \r to \n
-            b'\r' => {
-                reduced_string.push_str(&raw_content[copy_start..byte_index]);
-                if bytes.next_if(|(_, b)| *b == b'\n').is_some() {
-                    // \r\n -> skip \r, the \n will be included when we copy from copy_start
-                } else {
-                    // Single \r -> convert to \n
-                    reduced_string.push('\n');
-                }
-                copy_start = byte_index + 1;
-            }
             _ => {
                 // If we encounter a preferred quote and it's not escaped, we have to replace it with
                 // an escaped version.
@@ -391,6 +370,25 @@ pub fn normalize_string(
     }
 }
 
+/// Returns `true` if a string key can be printed as a numeric key without changing its meaning.
+///
+/// Only plain decimal integers without leading zeros that are safe integers qualify, so keys like
+/// `"0.5"`, `"-1"`, `"00"`, `"1e21"`, or `"999999999999999999"` stay quoted.
+fn is_simple_integer_key(text: &str) -> bool {
+    /// `Number.MAX_SAFE_INTEGER`
+    const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
+    let bytes = text.as_bytes();
+    if bytes.is_empty() || !bytes.iter().all(u8::is_ascii_digit) {
+        return false;
+    }
+    if bytes.len() > 1 && bytes[0] == b'0' {
+        return false;
+    }
+
+    text.parse::<u64>().is_ok_and(|value| value <= MAX_SAFE_INTEGER)
+}
+
 /// `is_identifier_name` patched with KATAKANA MIDDLE DOT and HALFWIDTH KATAKANA MIDDLE DOT
 /// Otherwise `({ 'x・': 0 })` gets converted to `({ x・: 0 })`, which breaks in Unicode 4.1 to
 /// 15.
@@ -406,19 +404,27 @@ mod tests {
     use super::*;
 
     #[test]
-    fn normalize_newline() {
-        // \n unchanged
-        assert_eq!(normalize_string("a\nb", QuoteStyle::Double, true), "a\nb");
-        // \r\n -> \n
-        assert_eq!(normalize_string("a\r\nb", QuoteStyle::Double, true), "a\nb");
-        // \r -> \n (single CR)
-        assert_eq!(normalize_string("a\rb", QuoteStyle::Double, true), "a\nb");
-        assert_eq!(normalize_string("a\r", QuoteStyle::Double, true), "a\n");
-        assert_eq!(normalize_string("\rb", QuoteStyle::Double, true), "\nb");
-        // escaped \r\n -> escaped \n
-        assert_eq!(normalize_string("a\\\r\nb", QuoteStyle::Double, true), "a\\\nb");
-        // escaped \r -> escaped \n (single CR)
-        assert_eq!(normalize_string("a\\\rb", QuoteStyle::Double, true), "a\\\nb");
+    fn simple_integer_key() {
+        assert!(is_simple_integer_key("0"));
+        assert!(is_simple_integer_key("1"));
+        assert!(is_simple_integer_key("9007199254740991"));
+
+        assert!(!is_simple_integer_key(""));
+        assert!(!is_simple_integer_key("00"));
+        assert!(!is_simple_integer_key("01"));
+        assert!(!is_simple_integer_key("-1"));
+        assert!(!is_simple_integer_key("0.5"));
+        assert!(!is_simple_integer_key("1e21"));
+        assert!(!is_simple_integer_key("9007199254740992"));
+        assert!(!is_simple_integer_key("999999999999999999"));
+    }
+
+    #[test]
+    fn keep_line_continuations() {
+        assert_eq!(normalize_string("a\\\nb", QuoteStyle::Double, true), "a\\\nb");
+        assert_eq!(normalize_string("a\\\r\nb", QuoteStyle::Double, true), "a\\\r\nb");
+        assert_eq!(normalize_string("a\\\rb", QuoteStyle::Double, true), "a\\\rb");
+        assert!(matches!(normalize_string("a\\\r\nb", QuoteStyle::Double, true), Cow::Borrowed(_)));
     }
 
     #[test]
@@ -444,4 +450,60 @@ mod tests {
         assert_eq!(normalize_string("\\'", QuoteStyle::Single, false), "\\'");
         assert_eq!(normalize_string("\\\"", QuoteStyle::Single, false), "\\\"");
     }
+
+    fn normalize_literal(string: &str, quote_style: QuoteStyle) -> Cow<'_, str> {
+        let token =
+            FormatLiteralStringToken::new(string, false, StringLiteralParentKind::Expression);
+        LiteralStringNormalizer::new(token, quote_style, false).normalize_text(SourceType::mjs())
+    }
+
+    #[test]
+    fn preferred_quote() {
+        for (string, double, single) in [
+            // No quotes: the configured quote
+            (r#""hello""#, r#""hello""#, r"'hello'"),
+            (r"'hello'", r#""hello""#, r"'hello'"),
+            // Only one kind of quotes: the quote that needs no escapes
+            (r#"'say "hi"'"#, r#"'say "hi"'"#, r#"'say "hi"'"#),
+            (r#""it's""#, r#""it's""#, r#""it's""#),
+            (r"'it\'s'", r#""it's""#, r#""it's""#),
+            // Equal counts: the configured quote
+            (r#"'both " and \''"#, r#""both \" and '""#, r#"'both " and \''"#),
+            (r#""both ' and \"""#, r#""both ' and \"""#, r#"'both \' and "'"#),
+            // Unequal counts: the quote that needs fewer escapes
+            (r#"'both "" and \''"#, r#"'both "" and \''"#, r#"'both "" and \''"#),
+            (r#""both '' and \"""#, r#""both '' and \"""#, r#""both '' and \"""#),
+        ] {
+            assert_eq!(normalize_literal(string, QuoteStyle::Double), double, "{string}");
+            assert_eq!(normalize_literal(string, QuoteStyle::Single), single, "{string}");
+        }
+    }
+
+    #[test]
+    fn escapes_are_preserved() {
+        for (string, expected) in [
+            (r"'\u2028 \u2029'", r#""\u2028 \u2029""#),
+            ("'\u{2028} \u{2029}'", "\"\u{2028} \u{2029}\""),
+            (r"'\uD800 \uDC00 \u{D800}'", r#""\uD800 \uDC00 \u{D800}""#),
+            (r"'\0 \012 \7 \8'", r#""\0 \012 \7 \8""#),
+            (r"'\x41 \u{1F600} \d'", r#""\x41 \u{1F600} \d""#),
+        ] {
+            assert_eq!(normalize_literal(string, QuoteStyle::Double), expected, "{string}");
+        }
+    }
+
+    #[test]
+    fn clean_literal_is_borrowed() {
+        for (string, quote_style) in [
+            (r#""hello world""#, QuoteStyle::Double),
+            (r"'hello world'", QuoteStyle::Single),
+            (r#""don't \n""#, QuoteStyle::Single),
+        ] {
+            let normalized = normalize_literal(string, quote_style);
+            assert!(matches!(normalized, Cow::Borrowed(_)), "{string}");
+            assert!(std::ptr::eq(normalized.as_ptr(), string.as_ptr()), "{string}");
+        }
+
+        assert!(matches!(normalize_literal("'hello'", QuoteStyle::Double), Cow::Owned(_)));
+    }
 }
@@ -2,7 +2,11 @@ use oxc_span::Span;
 
 use crate::{
     Buffer, Format,
-    formatter::{Formatter, prelude::*},
+    formatter::{
+        Formatter,
+        prelude::*,
+        trivia::{FormatLeadingComments, FormatTrailingComments},
+    },
     write,
 };
 
@@ -17,6 +21,22 @@ impl<'a> Format<'a> for FormatSuppressedNode {
     }
 }
 
+/// Formats the statements covering the given span, which are inside a
+/// `prettier-ignore-start` / `prettier-ignore-end` region, verbatim.
+///
+/// Unlike [`FormatSuppressedNode`], this also formats the comments before and after the statements,
+/// which is otherwise done by each statement.
+pub struct FormatSuppressedStatements(pub Span);
+
+impl<'a> Format<'a> for FormatSuppressedStatements {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) {
+        write!(f, [FormatLeadingComments::Node(self.0), FormatSuppressedNode(self.0)]);
+
+        let trailing_comments = f.context().comments().end_of_line_comments_after(self.0.end);
+        write!(f, FormatTrailingComments::Comments(trailing_comments));
+    }
+}
+
 fn mark_comments_as_printed_before(end: u32, f: &mut Formatter<'_, '_>) {
     let count = f.comments().unprinted_comments().iter().take_while(|c| c.span.end <= end).count();
     f.context_mut().comments_mut().increase_printed_count_by(count);
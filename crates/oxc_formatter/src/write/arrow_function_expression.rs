@@ -172,8 +172,8 @@ impl<'a, 'b> FormatJsArrowFunctionExpression<'a, 'b> {
                     };
                 }
 
-                let body_has_soft_line_break =
-                    arrow_expression.is_none_or(|expression| match expression {
+                let body_has_soft_line_break = arrow_expression.is_none_or(|expression| {
+                    match expression.without_parentheses() {
                         Expression::ArrowFunctionExpression(_)
                         | Expression::ArrayExpression(_)
                         | Expression::ObjectExpression(_) => {
@@ -183,7 +183,8 @@ impl<'a, 'b> FormatJsArrowFunctionExpression<'a, 'b> {
                         _ => {
                             is_multiline_template_starting_on_same_line(expression, f.source_text())
                         }
-                    });
+                    }
+                });
 
                 if body_has_soft_line_break {
                     write!(f, [formatted_signature, space(), format_body]);
@@ -1,3 +1,5 @@
+use std::iter::Peekable;
+
 use oxc_allocator::Vec;
 use oxc_ast::ast::*;
 use oxc_span::GetSpan;
@@ -32,7 +34,7 @@ impl<'a> Format<'a> for AssignmentTargetPropertyListNode<'a, '_> {
 }
 
 struct AssignmentTargetPropertyListIter<'a, 'b> {
-    properties: AstNodeIterator<'a, AssignmentTargetProperty<'a>>,
+    properties: Peekable<AstNodeIterator<'a, AssignmentTargetProperty<'a>>>,
     rest: Option<&'b AstNode<'a, AssignmentTargetRest<'a>>>,
 }
 
@@ -41,6 +43,13 @@ impl<'a, 'b> Iterator for AssignmentTargetPropertyListIter<'a, 'b> {
 
     fn next(&mut self) -> Option<Self::Item> {
         if let Some(property) = self.properties.next() {
+            // Comments between the last property and the rest element belong to the rest element
+            let property = match self.rest {
+                Some(rest) if self.properties.peek().is_none() => {
+                    property.with_following_span(rest.span())
+                }
+                _ => property,
+            };
             Some(AssignmentTargetPropertyListNode::Property(property))
         } else {
             self.rest.take().map(AssignmentTargetPropertyListNode::Rest)
@@ -72,7 +81,7 @@ impl<'a> Format<'a> for AssignmentTargetPropertyList<'a, '_> {
         };
         f.join_nodes_with_soft_line().entries_with_trailing_separator(
             AssignmentTargetPropertyListIter {
-                properties: self.properties.iter(),
+                properties: self.properties.iter().peekable(),
                 rest: self.rest,
             },
             ",",
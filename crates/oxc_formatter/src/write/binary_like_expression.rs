@@ -5,7 +5,7 @@ use oxc_syntax::precedence::{GetPrecedence, Precedence};
 use crate::{
     Format,
     ast_nodes::{AstNode, AstNodes},
-    formatter::Formatter,
+    formatter::{Formatter, trivia::FormatLeadingComments},
 };
 
 use crate::{format_args, formatter::prelude::*, write};
@@ -110,9 +110,20 @@ impl<'a, 'b> BinaryLikeExpression<'a, 'b> {
 
     /// Determines if a binary like expression should be flattened or not. As a rule of thumb, an expression
     /// can be flattened if its left hand side has the same operator-precedence
+    ///
+    /// Binary expressions with a numeric literal operand, e.g. `count + 1` in `count + 1 + label`,
+    /// aren't flattened, so that the grouping of the arithmetic stays visible when the chain breaks.
     fn can_flatten(&self) -> bool {
         let left_operator = match self.left().as_ref() {
-            Expression::BinaryExpression(expr) => BinaryLikeOperator::from(expr.operator),
+            Expression::BinaryExpression(expr) => {
+                if [&expr.left, &expr.right, self.right().as_ref()]
+                    .into_iter()
+                    .any(|operand| matches!(operand, Expression::NumericLiteral(_)))
+                {
+                    return false;
+                }
+                BinaryLikeOperator::from(expr.operator)
+            }
             Expression::LogicalExpression(expr) => BinaryLikeOperator::from(expr.operator),
             _ => return false,
         };
@@ -128,7 +139,7 @@ impl<'a, 'b> BinaryLikeExpression<'a, 'b> {
     }
 
     pub fn can_inline_logical_expr(logical: &LogicalExpression) -> bool {
-        match &logical.right {
+        match logical.right.without_parentheses() {
             Expression::ObjectExpression(object) => !object.properties.is_empty(),
             Expression::ArrayExpression(array) => !array.elements.is_empty(),
             Expression::JSXElement(_) | Expression::JSXFragment(_) => true,
@@ -146,7 +157,8 @@ impl<'a, 'b> BinaryLikeExpression<'a, 'b> {
             AstNodes::ReturnStatement(_)
             | AstNodes::ThrowStatement(_)
             | AstNodes::ForStatement(_)
-            | AstNodes::TemplateLiteral(_) => true,
+            | AstNodes::TemplateLiteral(_)
+            | AstNodes::ParenthesizedExpression(_) => true,
             AstNodes::JSXExpressionContainer(container) => {
                 matches!(container.parent, AstNodes::JSXAttribute(_))
             }
@@ -399,9 +411,10 @@ impl<'a> Format<'a> for BinaryLeftOrRightSide<'a, '_> {
                         write!(
                             f,
                             [
-                                space(),
-                                operator.as_str(),
-                                soft_line_break_or_space(),
+                                FormatOperatorAndLine {
+                                    operator: BinaryLikeOperator::from(operator),
+                                    right_start: right_logical.span().start,
+                                },
                                 format_with(|f| {
                                     // If the left side of the right logical expression is still a logical expression with
                                     // the same operator, we need to recursively format it inline.
@@ -437,12 +450,10 @@ impl<'a> Format<'a> for BinaryLeftOrRightSide<'a, '_> {
                 let right = binary_like_expression.right();
 
                 let operator_and_right_expression = format_with(|f| {
-                    write!(f, [space(), binary_like_expression.operator()]);
-
                     let should_inline = binary_like_expression.should_inline_logical_expression();
 
                     if should_inline {
-                        write!(f, [space()]);
+                        write!(f, [space(), binary_like_expression.operator(), space()]);
 
                         if !right.is_jsx()
                             && f.comments().has_leading_own_line_comment(right.span().start)
@@ -450,7 +461,13 @@ impl<'a> Format<'a> for BinaryLeftOrRightSide<'a, '_> {
                             return write!(f, soft_line_indent_or_space(right));
                         }
                     } else {
-                        write!(f, [soft_line_break_or_space()]);
+                        write!(
+                            f,
+                            [FormatOperatorAndLine {
+                                operator: binary_like_expression.operator(),
+                                right_start: right.span().start,
+                            }]
+                        );
                     }
 
                     write!(f, right);
@@ -512,11 +529,61 @@ impl<'a> Format<'a> for BinaryLeftOrRightSide<'a, '_> {
     }
 }
 
+/// Formats the operator of a binary like expression together with the line break
+/// before its right hand side operand that starts at `right_start`.
+///
+/// The line break goes after the operator by default, or before it if
+/// [`crate::OperatorPosition::Start`] is set:
+///
+/// ```js
+/// veryLongConditionNumberOne &&
+///   veryLongConditionNumberTwo;
+///
+/// veryLongConditionNumberOne
+///   && veryLongConditionNumberTwo;
+/// ```
+struct FormatOperatorAndLine {
+    operator: BinaryLikeOperator,
+    right_start: u32,
+}
+
+impl<'a> Format<'a> for FormatOperatorAndLine {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) {
+        if f.options().experimental_operator_position.is_end() {
+            write!(f, [space(), self.operator, soft_line_break_or_space()]);
+            return;
+        }
+
+        write!(f, [soft_line_break_or_space()]);
+
+        // Comments after the operator that break the line are printed before it,
+        // so that the operator still starts the line of its right hand side.
+        // ```js
+        // a && // comment
+        //   b;
+        // ```
+        // becomes
+        // ```js
+        // a
+        //   // comment
+        //   && b;
+        // ```
+        let comments = f.context().comments().comments_before(self.right_start);
+        if comments.iter().any(|comment| comment.is_line() || comment.followed_by_newline()) {
+            write!(f, [FormatLeadingComments::Comments(comments)]);
+        }
+
+        write!(f, [self.operator, space()]);
+    }
+}
+
 impl BinaryLeftOrRightSide<'_, '_> {
     fn is_jsx(&self) -> bool {
         match self {
-            BinaryLeftOrRightSide::Left { parent } => parent.left().is_jsx(),
-            BinaryLeftOrRightSide::Right { parent, .. } => parent.right().is_jsx(),
+            BinaryLeftOrRightSide::Left { parent } => parent.left().without_parentheses().is_jsx(),
+            BinaryLeftOrRightSide::Right { parent, .. } => {
+                parent.right().without_parentheses().is_jsx()
+            }
         }
     }
 }
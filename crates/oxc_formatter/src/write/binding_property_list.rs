@@ -1,3 +1,5 @@
+use std::iter::Peekable;
+
 use oxc_allocator::Vec;
 use oxc_ast::ast::*;
 use oxc_span::GetSpan;
@@ -37,7 +39,7 @@ impl<'a> Format<'a> for BindingPropertyListNode<'a, '_> {
 }
 
 struct BindingPropertyListIter<'a, 'b> {
-    properties: AstNodeIterator<'a, BindingProperty<'a>>,
+    properties: Peekable<AstNodeIterator<'a, BindingProperty<'a>>>,
     rest: Option<&'b AstNode<'a, BindingRestElement<'a>>>,
 }
 
@@ -46,6 +48,13 @@ impl<'a, 'b> Iterator for BindingPropertyListIter<'a, 'b> {
 
     fn next(&mut self) -> Option<Self::Item> {
         if let Some(property) = self.properties.next() {
+            // Comments between the last property and the rest element belong to the rest element
+            let property = match self.rest {
+                Some(rest) if self.properties.peek().is_none() => {
+                    property.with_following_span(rest.span())
+                }
+                _ => property,
+            };
             Some(BindingPropertyListNode::Property(property))
         } else {
             self.rest.take().map(BindingPropertyListNode::Rest)
@@ -72,7 +81,10 @@ impl<'a> Format<'a> for BindingPropertyList<'a, '_> {
         };
 
         f.join_nodes_with_soft_line().entries_with_trailing_separator(
-            BindingPropertyListIter { properties: self.properties.iter(), rest: self.rest },
+            BindingPropertyListIter {
+                properties: self.properties.iter().peekable(),
+                rest: self.rest,
+            },
             ",",
             trailing_separator,
         );
@@ -1,39 +1,68 @@
 use oxc_allocator::Vec;
 use oxc_ast::ast::*;
+use oxc_span::{GetSpan, Span};
 
 use super::FormatWrite;
 use crate::{
     ast_nodes::{AstNode, AstNodes},
-    format_args,
+    format_range::FormatRangeEntries,
     formatter::{Buffer, Formatter, prelude::*},
+    utils::suppressed::FormatSuppressedStatements,
     write,
 };
 
 impl<'a> Format<'a> for AstNode<'a, Vec<'a, Statement<'a>>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) {
-        f.join_nodes_with_hardline().entries(
-            self.iter().filter(|stmt| !matches!(stmt.as_ref(), Statement::EmptyStatement(_))),
+        write_statements(
+            || self.iter().filter(|stmt| !matches!(stmt.as_ref(), Statement::EmptyStatement(_))),
+            GetSpan::span,
+            None,
+            f,
         );
     }
 }
 
-impl<'a> FormatWrite<'a> for AstNode<'a, BlockStatement<'a>> {
-    fn write(&self, f: &mut Formatter<'_, 'a>) {
-        write!(f, "{");
+/// Joins `statements` with hard lines, where `span_of` returns the span of a statement.
+///
+/// Consecutive statements inside a `prettier-ignore-start` / `prettier-ignore-end` region
+/// are printed verbatim, as one entry.
+/// `bounds` is passed to [`FormatRangeEntries::new`].
+pub fn write_statements<'a, I>(
+    statements: impl Fn() -> I,
+    span_of: impl Fn(&AstNode<'a, Statement<'a>>) -> Span,
+    bounds: Option<Span>,
+    f: &mut Formatter<'_, 'a>,
+) where
+    I: Iterator<Item = &'a AstNode<'a, Statement<'a>>>,
+{
+    let range_entries = FormatRangeEntries::new(statements().map(&span_of), bounds, f);
+    let ignored_regions = f.context().ignored_regions();
 
-        let comments_before_catch_clause = if let AstNodes::CatchClause(catch) = self.parent {
-            f.context().get_cached_element(&catch.span)
-        } else {
-            None
+    let mut join = f.join_nodes_with_hardline();
+    let mut statements = statements().map(|stmt| (stmt, span_of(stmt))).enumerate().peekable();
+    while let Some((index, (stmt, span))) = statements.next() {
+        let Some(region) = ignored_regions.iter().find(|region| region.contains_inclusive(span))
+        else {
+            join.entry(span, &range_entries.entry(index, span, stmt));
+            continue;
         };
 
-        let has_comment_before_catch_clause = comments_before_catch_clause.is_some();
-        // See reason in `[AstNode<'a, CatchClause<'a>>::write]`
-        let formatted_comments_before_catch_clause = format_once(|f| {
-            if let Some(comments) = comments_before_catch_clause {
-                f.write_element(comments);
-            }
-        });
+        let mut last = (index, span);
+        while let Some((next_index, (_, next_span))) = statements.peek()
+            && region.contains_inclusive(*next_span)
+        {
+            last = (*next_index, *next_span);
+            statements.next();
+        }
+
+        let suppressed = FormatSuppressedStatements(Span::new(span.start, last.1.end));
+        join.entry(span, &range_entries.entries(index, last.0, suppressed.0, &suppressed));
+    }
+}
+
+impl<'a> FormatWrite<'a> for AstNode<'a, BlockStatement<'a>> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) {
+        write!(f, "{");
 
         if is_empty_block(&self.body) {
             // `if (a) /* comment */ {}`
@@ -41,24 +70,13 @@ impl<'a> FormatWrite<'a> for AstNode<'a, BlockStatement<'a>> {
             // `if (a) { /* comment */ }`
             //
             // Some comments are not inside the block, but we need to print them inside the block.
-            if has_comment_before_catch_clause
-                || f.context().comments().has_comment_before(self.span.end)
-            {
-                write!(
-                    f,
-                    block_indent(&format_args!(
-                        &formatted_comments_before_catch_clause,
-                        format_dangling_comments(self.span)
-                    ))
-                );
+            if f.context().comments().has_comment_before(self.span.end) {
+                write!(f, block_indent(&format_dangling_comments(self.span)));
             } else if is_non_collapsible(self.parent) {
                 write!(f, hard_line_break());
             }
         } else {
-            write!(
-                f,
-                block_indent(&format_args!(&formatted_comments_before_catch_clause, self.body()))
-            );
+            write!(f, block_indent(&self.body()));
         }
         write!(f, "}");
     }
@@ -8,6 +8,7 @@ use crate::{
     Semicolons, TrailingSeparator,
     ast_nodes::{AstNode, AstNodes},
     format_args,
+    format_range::FormatRangeEntries,
     formatter::{
         Buffer, Formatter,
         prelude::*,
@@ -40,7 +41,9 @@ impl<'a> FormatWrite<'a> for AstNode<'a, ClassBody<'a>> {
                     _ => return false,
                 };
 
-                should_preserve_quote(key, f)
+                // Private keys (e.g. `#a`) can never be quoted, and `should_preserve_quote`
+                // only considers string literal keys, so they don't affect the result.
+                should_preserve_quote(key)
             });
             f.context_mut().push_quote_needed(quote_needed);
         }
@@ -55,17 +58,45 @@ impl<'a> FormatWrite<'a> for AstNode<'a, ClassBody<'a>> {
 
 impl<'a> Format<'a> for AstNode<'a, Vec<'a, ClassElement<'a>>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) {
+        let range_entries = FormatRangeEntries::new(self.iter().map(GetSpan::span), None, f);
+
         // Join class elements with hard line breaks between them
         let mut join = f.join_nodes_with_hardline();
         // Iterate through pairs of consecutive elements to handle semicolons properly
         // Each element is paired with the next one (or None for the last element)
-        let mut iter = self.iter().peekable();
-        while let Some(element) = iter.next() {
-            join.entry(element.span(), &(element, iter.peek().copied()));
+        let mut iter = self.iter().enumerate().peekable();
+        let mut previous = None;
+        while let Some((index, element)) = iter.next() {
+            let content = (element, iter.peek().map(|(_, next)| *next));
+            let entry = range_entries.entry(index, element.span(), &content);
+            if previous.is_some_and(|previous| is_overload_of(previous, element)) {
+                // Overload signatures stay together with each other and their implementation
+                join.entry_no_separator(&format_args!(hard_line_break(), entry));
+            } else {
+                join.entry(element.span(), &entry);
+            }
+            previous = Some(element);
         }
     }
 }
 
+/// Returns `true` if `previous` is an overload signature of the method `element`, e.g.
+/// `overload(a: string): void;` followed by another signature or the implementation.
+fn is_overload_of(previous: &ClassElement<'_>, element: &ClassElement<'_>) -> bool {
+    let (ClassElement::MethodDefinition(previous), ClassElement::MethodDefinition(element)) =
+        (previous, element)
+    else {
+        return false;
+    };
+
+    previous.value.body.is_none()
+        && !previous.r#type.is_abstract()
+        && previous.kind == element.kind
+        && previous.r#static == element.r#static
+        && previous.computed == element.computed
+        && previous.key.static_name().is_some_and(|name| element.key.static_name() == Some(name))
+}
+
 impl<'a> Format<'a> for (&AstNode<'a, ClassElement<'a>>, Option<&AstNode<'a, ClassElement<'a>>>) {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) {
         FormatClassElementWithSemicolon::new(self.0, self.1).fmt(f);
@@ -142,7 +173,10 @@ impl<'a> FormatWrite<'a> for AstNode<'a, PropertyDefinition<'a>> {
 
 impl<'a> FormatWrite<'a> for AstNode<'a, PrivateIdentifier<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
-        write!(f, ["#", text_without_whitespace(self.name().as_str())]);
+        write!(
+            f,
+            [source_position(self.span.start), "#", text_without_whitespace(self.name().as_str())]
+        );
     }
 }
 
@@ -605,7 +639,9 @@ impl<'a> Format<'a> for FormatClassElementWithSemicolon<'a, '_> {
             ClassElement::PropertyDefinition(_) | ClassElement::AccessorProperty(_)
         );
 
+        // A suppressed element is printed verbatim, including its semicolon if it has one.
         let needs_semi = needs_semi
+            && !f.comments().is_suppressed(self.element.span().start)
             && match f.options().semicolons {
                 Semicolons::Always => true,
                 Semicolons::AsNeeded => self.needs_semicolon(),
@@ -95,11 +95,16 @@ impl<'a> FormatWrite<'a> for AstNode<'a, Decorator<'a>> {
     }
 }
 
-/// Check if decorators should expand (have newlines between them)
+/// Check if decorators should expand (have newlines between them).
+/// A single decorator never expands with the `decorators_same_line` option.
 #[inline]
 fn should_expand_decorators<'a>(
     decorators: &AstNode<'a, Vec<'a, Decorator<'a>>>,
     f: &Formatter<'_, 'a>,
 ) -> bool {
+    if f.options().decorators_same_line && decorators.len() == 1 {
+        return false;
+    }
+
     decorators.iter().any(|decorator| f.source_text().has_newline_after(decorator.span().end))
 }
@@ -9,8 +9,9 @@ use crate::{
     formatter::{
         Formatter, prelude::*, separated::FormatSeparatedIter, trivia::FormatLeadingComments,
     },
-    utils::string::{
-        FormatLiteralStringToken, StringLiteralParentKind, is_identifier_name_patched,
+    utils::{
+        object::import_attributes_has_key_requiring_quotes,
+        string::{FormatLiteralStringToken, StringLiteralParentKind},
     },
     write,
     write::semicolon::OptionalSemicolon,
@@ -168,13 +169,7 @@ impl<'a> FormatWrite<'a> for AstNode<'a, ImportNamespaceSpecifier<'a>> {
 impl<'a> FormatWrite<'a> for AstNode<'a, WithClause<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
         if f.options().quote_properties.is_consistent() {
-            let quote_needed = self.with_entries.iter().any(|attribute| {
-                matches!(&attribute.key, ImportAttributeKey::StringLiteral(string) if {
-                    let quote_less_content = f.source_text().text_for(&string.span.shrink(1));
-                    !is_identifier_name_patched(quote_less_content)
-                })
-            });
-
+            let quote_needed = import_attributes_has_key_requiring_quotes(&self.with_entries);
             f.context_mut().push_quote_needed(quote_needed);
         }
 
@@ -184,19 +179,33 @@ impl<'a> FormatWrite<'a> for AstNode<'a, WithClause<'a>> {
                 write!(f, [space(), FormatLeadingComments::Comments(comments)]);
             }
         });
-        write!(
-            f,
-            [
-                space(),
-                format_comment,
-                match self.keyword() {
-                    WithClauseKeyword::With => "with",
-                    WithClauseKeyword::Assert => "assert",
-                },
-                space(),
-                self.with_entries()
-            ]
-        );
+        let entries = self.with_entries().memoized();
+        let format_clause = format_with(|f| {
+            write!(
+                f,
+                [
+                    format_comment,
+                    match self.keyword() {
+                        WithClauseKeyword::With => "with",
+                        WithClauseKeyword::Assert => "assert",
+                    },
+                    space(),
+                    entries
+                ]
+            );
+        });
+
+        if entries.inspect(f).will_break() {
+            // The entries keep their expanded layout, so the clause stays after the source
+            write!(f, [space(), format_clause]);
+        } else {
+            // A clause that doesn't fit moves to its own indented line before its entries break:
+            // ```js
+            // import { aVeryLongSpecifier } from "./a-very-long-module-specifier"
+            //   with { type: "json" };
+            // ```
+            write!(f, [group(&indent(&format_args!(soft_line_break_or_space(), format_clause)))]);
+        }
 
         if f.options().quote_properties.is_consistent() {
             f.context_mut().pop_quote_needed();
@@ -226,11 +235,12 @@ impl<'a> Format<'a> for AstNode<'a, Vec<'a, ImportAttribute<'a>>> {
                             let trailing_separator =
                                 FormatTrailingCommas::ES5.trailing_separator(f.options());
 
-                            f.join_with(soft_line_break()).entries_with_trailing_separator(
-                                self.iter(),
-                                ",",
-                                trailing_separator,
-                            );
+                            f.join_with(soft_line_break_or_space())
+                                .entries_with_trailing_separator(
+                                    self.iter(),
+                                    ",",
+                                    trailing_separator,
+                                );
                         },),
                         should_insert_space_around_brackets
                     )]
@@ -3,8 +3,9 @@ use oxc_ast::ast::*;
 use oxc_span::GetSpan;
 
 use crate::{
-    ast_nodes::AstNode,
-    formatter::{Formatter, prelude::*},
+    ast_nodes::{AstNode, AstNodes},
+    formatter::{Formatter, prelude::*, trivia::FormatTrailingComments},
+    parentheses::NeedsParentheses,
     utils::typescript::is_object_like_type,
     write,
     write::FormatWrite,
@@ -12,11 +13,94 @@ use crate::{
 
 impl<'a> FormatWrite<'a> for AstNode<'a, TSIntersectionType<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
-        let content = format_with(|f| format_intersection_types(self.types(), f));
+        // Object-like members are hugged and keep their trailing `&`:
+        // ```ts
+        // type A = B & {
+        //   c: string;
+        // } & D;
+        // ```
+        if self.types.iter().any(is_object_like_type) {
+            let content = format_with(|f| format_intersection_types(self.types(), f));
+            return write!(f, [group(&content)]);
+        }
+
+        let has_leading_comments = f.comments().has_comment_before(self.span().start);
+
+        let should_indent = !has_leading_comments
+            && match self.parent {
+                // These parents have indent for their content, so we don't need to indent here
+                AstNodes::TSTypeAliasDeclaration(alias) => {
+                    !f.comments().printed_comments().last().is_some_and(|comment| {
+                        comment.span.start
+                            > alias.type_parameters().map_or(alias.id.span.end, |tp| tp.span.end)
+                            && comment.followed_by_newline()
+                    })
+                }
+                AstNodes::TSTypeAssertion(_)
+                | AstNodes::TSTupleType(_)
+                | AstNodes::TSTypeParameterInstantiation(_) => false,
+                _ => true,
+            };
+
+        let types = format_with(|f| {
+            // ```ts
+            // type A =
+            //   & B
+            //   & C;
+            // ```
+            let separator = format_with(|f| {
+                if should_indent {
+                    write!(f, [soft_line_break_or_space()]);
+                }
+                write!(f, [token("&"), space()]);
+            });
+
+            write!(f, [if_group_breaks(&separator)]);
+
+            format_leading_separated_types(self.types(), f);
+        });
+
+        let content = format_with(|f| {
+            if self.needs_parentheses(f) {
+                write!(f, [indent(&types), soft_line_break()]);
+            } else if should_indent {
+                write!(f, [indent(&types)]);
+            } else {
+                write!(f, [types]);
+            }
+        });
+
         write!(f, [group(&content)]);
     }
 }
 
+/// Formats the members of an intersection without object-like members, placing the `&`
+/// in front of every member when the intersection breaks.
+fn format_leading_separated_types<'a>(
+    node: &AstNode<'a, Vec<'a, TSType<'a>>>,
+    f: &mut Formatter<'_, 'a>,
+) {
+    let mut node_iter = node.iter().peekable();
+    while let Some(element) = node_iter.next() {
+        write!(f, [align(2, &element)]);
+
+        if let Some(next_node_span) = node_iter.peek().map(GetSpan::span) {
+            let comments_before_separator =
+                f.context().comments().comments_before_character(element.span().end, b'&');
+            FormatTrailingComments::Comments(comments_before_separator).fmt(f);
+
+            // Own line comments between `&` and the next member are printed before the `&`,
+            // so they stay on their own line above the member.
+            if f.comments().has_leading_own_line_comment(next_node_span.start) {
+                let comments = f.context().comments().comments_before(next_node_span.start);
+                FormatTrailingComments::Comments(comments).fmt(f);
+            }
+
+            write!(f, [soft_line_break_or_space(), "&", space()]);
+        }
+    }
+}
+
 // [Prettier applies]: https://github.com/prettier/prettier/blob/cd3e530c2e51fb8296c0fb7738a9afdd3a3a4410/src/language-js/print/type-annotation.js#L93-L120
 fn format_intersection_types<'a>(
     node: &AstNode<'a, Vec<'a, TSType<'a>>>,
@@ -54,10 +54,17 @@ impl<'a> AnyJsxTagWithChildren<'a, '_> {
     /// Checks if a JSX Element should be wrapped in parentheses. Returns a [WrapState] which
     /// indicates when the element should be wrapped in parentheses.
     pub fn get_wrap_state(&self) -> WrapState {
-        let parent = self.parent();
+        let mut parent = self.parent();
+        let mut span = self.span();
         // Call site has ensures that only non-nested JSX elements are passed.
         debug_assert!(!matches!(parent, AstNodes::JSXElement(_) | AstNodes::JSXFragment(_)));
 
+        // Parentheses kept with `preserve_parens` aren't printed around JSX, which adds its own.
+        while let AstNodes::ParenthesizedExpression(parenthesized) = parent {
+            span = parenthesized.span();
+            parent = parenthesized.parent;
+        }
+
         match parent {
             AstNodes::ArrayExpression(_)
             | AstNodes::JSXAttribute(_)
@@ -71,10 +78,8 @@ impl<'a> AnyJsxTagWithChildren<'a, '_> {
                 }
             }
             // It is a argument of a call expression
-            AstNodes::CallExpression(call) if call.is_argument_span(self.span()) => {
-                WrapState::NoWrap
-            }
-            AstNodes::NewExpression(new) if new.is_argument_span(self.span()) => WrapState::NoWrap,
+            AstNodes::CallExpression(call) if call.is_argument_span(span) => WrapState::NoWrap,
+            AstNodes::NewExpression(new) if new.is_argument_span(span) => WrapState::NoWrap,
             AstNodes::ExpressionStatement(stmt) => {
                 // `() => <div></div>`
                 //        ^^^^^^^^^^^
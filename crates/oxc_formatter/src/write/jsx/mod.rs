@@ -350,7 +350,10 @@ impl<'a> FormatWrite<'a> for AstNode<'a, JSXSpreadAttribute<'a>> {
 
 impl<'a> FormatWrite<'a> for AstNode<'a, JSXIdentifier<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
-        write!(f, text_without_whitespace(self.name().as_str()));
+        write!(
+            f,
+            [source_position(self.span.start), text_without_whitespace(self.name().as_str())]
+        );
     }
 }
 
@@ -40,9 +40,9 @@ pub use arrow_function_expression::{
 pub use binary_like_expression::{BinaryLikeExpression, should_flatten};
 pub use function::FormatFunctionOptions;
 
-use cow_utils::CowUtils;
+use std::borrow::Cow;
 
-use oxc_allocator::{StringBuilder, Vec};
+use oxc_allocator::Vec;
 use oxc_ast::ast::*;
 use oxc_span::GetSpan;
 
@@ -53,7 +53,7 @@ use crate::{
         Buffer, Format, Formatter,
         prelude::*,
         separated::FormatSeparatedIter,
-        token::number::{NumberFormatOptions, format_number_token},
+        token::number::{NumberFormatOptions, format_number_token, lowercase_number_letters},
         trivia::{
             DanglingIndentMode, FormatDanglingComments, FormatLeadingComments,
             FormatTrailingComments,
@@ -69,9 +69,15 @@ use crate::{
         expression::ExpressionLeftSide,
         format_node_without_trailing_comments::FormatNodeWithoutTrailingComments,
         member_chain::MemberChain,
-        object::{format_property_key, should_preserve_quote},
+        object::{
+            enum_has_member_requiring_quotes, format_property_key, numeric_key_text,
+            object_has_property_requiring_quotes, should_quote_numeric_key,
+            signatures_have_property_requiring_quotes, write_quoted_member_name,
+        },
         statement_body::FormatStatementBody,
         string::{FormatLiteralStringToken, StringLiteralParentKind},
+        suppressed::FormatSuppressedNode,
+        typecast::is_type_cast_node,
     },
     write,
     write::parameters::can_avoid_parentheses,
@@ -99,7 +105,6 @@ pub trait FormatWrite<'ast, T = ()> {
 
 impl<'a> FormatWrite<'a> for AstNode<'a, IdentifierName<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
-        let text = text_without_whitespace(self.name().as_str());
         let is_property_key_parent = matches!(
             self.parent,
             AstNodes::ObjectProperty(_)
@@ -109,37 +114,47 @@ impl<'a> FormatWrite<'a> for AstNode<'a, IdentifierName<'a>> {
                 | AstNodes::PropertyDefinition(_)
                 | AstNodes::AccessorProperty(_)
                 | AstNodes::ImportAttribute(_)
+                | AstNodes::TSEnumMember(_)
         );
+        write!(f, source_position(self.span.start));
         if is_property_key_parent && f.context().is_quote_needed() {
-            let quote_str = f.options().quote_style.as_str();
-            write!(f, [quote_str, text, quote_str]);
+            write_quoted_member_name(self.name().as_str(), f);
         } else {
-            write!(f, text);
+            write!(f, text_without_whitespace(self.name().as_str()));
         }
     }
 }
 
 impl<'a> FormatWrite<'a> for AstNode<'a, IdentifierReference<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
-        write!(f, text_without_whitespace(self.name().as_str()));
+        write!(
+            f,
+            [source_position(self.span.start), text_without_whitespace(self.name().as_str())]
+        );
     }
 }
 
 impl<'a> FormatWrite<'a> for AstNode<'a, BindingIdentifier<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
-        write!(f, text_without_whitespace(self.name().as_str()));
+        write!(
+            f,
+            [source_position(self.span.start), text_without_whitespace(self.name().as_str())]
+        );
     }
 }
 
 impl<'a> FormatWrite<'a> for AstNode<'a, LabelIdentifier<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
-        write!(f, text_without_whitespace(self.name().as_str()));
+        write!(
+            f,
+            [source_position(self.span.start), text_without_whitespace(self.name().as_str())]
+        );
     }
 }
 
 impl<'a> FormatWrite<'a> for AstNode<'a, ThisExpression> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
-        write!(f, "this");
+        write!(f, [source_position(self.span.start), "this"]);
     }
 }
 
@@ -156,9 +171,7 @@ impl<'a> FormatWrite<'a> for AstNode<'a, Elision> {
 impl<'a> FormatWrite<'a> for AstNode<'a, ObjectExpression<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
         if f.options().quote_properties.is_consistent() {
-            let quote_needed = self.properties.iter().any(|kind| {
-                kind.as_property().is_some_and(|property| should_preserve_quote(&property.key, f))
-            });
+            let quote_needed = object_has_property_requiring_quotes(&self.properties, f);
             f.context_mut().push_quote_needed(quote_needed);
         }
 
@@ -225,6 +238,11 @@ impl<'a> FormatWrite<'a> for AstNode<'a, ObjectProperty<'a>> {
             if let Some(body) = &value.body() {
                 write!(f, [space(), body]);
             }
+        } else if self.shorthand && f.context().is_quote_needed() {
+            // A shorthand property can't have a quoted key, so expand it when the other keys
+            // of the object are quoted, e.g. `{ "a-b": 1, x }` -> `{ "a-b": 1, "x": x }`.
+            format_property_key(self.key(), f);
+            write!(f, [":", space(), self.value()]);
         } else {
             write!(f, AssignmentLike::ObjectProperty(self));
         }
@@ -392,7 +410,17 @@ impl<'a> FormatWrite<'a> for AstNode<'a, ArrayAssignmentTarget<'a>> {
 
 impl<'a> FormatWrite<'a> for AstNode<'a, ObjectAssignmentTarget<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
+        // Patterns don't participate in consistent quoting, but they can be nested inside an
+        // object-like node that does, so reset the state to avoid inheriting it.
+        if f.options().quote_properties.is_consistent() {
+            f.context_mut().push_quote_needed(false);
+        }
+
         ObjectPatternLike::ObjectAssignmentTarget(self).fmt(f);
+
+        if f.options().quote_properties.is_consistent() {
+            f.context_mut().pop_quote_needed();
+        }
     }
 }
 
@@ -432,7 +460,7 @@ impl<'a> FormatWrite<'a> for AstNode<'a, AssignmentTargetPropertyProperty<'a>> {
 
 impl<'a> FormatWrite<'a> for AstNode<'a, Super> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
-        write!(f, "super");
+        write!(f, [source_position(self.span.start), "super"]);
     }
 }
 
@@ -486,8 +514,47 @@ impl<'a> FormatWrite<'a> for AstNode<'a, ChainExpression<'a>> {
 }
 
 impl<'a> FormatWrite<'a> for AstNode<'a, ParenthesizedExpression<'a>> {
-    fn write(&self, _f: &mut Formatter<'_, 'a>) {
-        unreachable!("No `ParenthesizedExpression` as we disabled `preserve_parens` in the parser")
+    fn write(&self, f: &mut Formatter<'_, 'a>) {
+        // Parentheses are only kept by the parser with `preserve_parens`, which the formatter disables
+        // because it decides on its own where they are needed. Print the source text as-is instead,
+        // unless the `preserve_parens` option asks to keep them.
+        if !f.options().preserve_parens {
+            f.context_mut().report_unsupported_syntax(self.span, "ParenthesizedExpression");
+            write!(f, FormatSuppressedNode(self.span));
+            return;
+        }
+
+        let expression = self.expression();
+        // The expression prints its own parentheses if it needs them, e.g. `(a, b)` or a type cast,
+        // and nested parentheses like `((a))` are printed once. JSX adds parentheses on its own
+        // when it breaks over multiple lines, which would otherwise be doubled on the next run.
+        if expression.needs_parentheses(f)
+            || is_type_cast_node(expression, f).is_some()
+            || matches!(
+                expression.as_ref(),
+                Expression::ParenthesizedExpression(_)
+                    | Expression::JSXElement(_)
+                    | Expression::JSXFragment(_)
+            )
+        {
+            return write!(f, expression);
+        }
+
+        // Break inside the parentheses of operators like `(a && b) || c`, as they don't indent on
+        // their own. Other expressions, e.g. calls, functions and objects, hug the parentheses.
+        if matches!(
+            expression.as_ref(),
+            Expression::LogicalExpression(_)
+                | Expression::BinaryExpression(_)
+                | Expression::ConditionalExpression(_)
+        ) {
+            write!(
+                f,
+                [group(&format_args!(token("("), soft_block_indent(&expression), token(")")))]
+            );
+        } else {
+            write!(f, [token("("), expression, token(")")]);
+        }
     }
 }
 
@@ -585,6 +652,10 @@ fn expression_statement_needs_semicolon<'a>(
 
 impl<'a> FormatWrite<'a> for AstNode<'a, ExpressionStatement<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
+        if f.context().is_json() {
+            return write!(f, self.expression());
+        }
+
         // Check if we need a leading semicolon to prevent ASI issues
         if f.options().semicolons == Semicolons::AsNeeded
             && expression_statement_needs_semicolon(self, f)
@@ -968,7 +1039,16 @@ impl<'a> FormatWrite<'a> for AstNode<'a, AssignmentPattern<'a>> {
 
 impl<'a> FormatWrite<'a> for AstNode<'a, ObjectPattern<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
+        // See the comment in `ObjectAssignmentTarget`.
+        if f.options().quote_properties.is_consistent() {
+            f.context_mut().push_quote_needed(false);
+        }
+
         ObjectPatternLike::ObjectPattern(self).fmt(f);
+
+        if f.options().quote_properties.is_consistent() {
+            f.context_mut().pop_quote_needed();
+        }
     }
 }
 
@@ -1001,18 +1081,27 @@ impl<'a> FormatWrite<'a> for AstNode<'a, V8IntrinsicExpression<'a>> {
 
 impl<'a> FormatWrite<'a> for AstNode<'a, BooleanLiteral> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
-        write!(f, if self.value() { "true" } else { "false" });
+        write!(f, [source_position(self.span.start), if self.value() { "true" } else { "false" }]);
     }
 }
 
 impl<'a> FormatWrite<'a> for AstNode<'a, NullLiteral> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
-        write!(f, "null");
+        write!(f, [source_position(self.span.start), "null"]);
     }
 }
 
 impl<'a> FormatWrite<'a> for AstNode<'a, NumericLiteral<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
+        write!(f, source_position(self.span.start));
+        if f.context().is_json() {
+            return write!(f, text(f.source_text().text_for(self)));
+        }
+        if should_quote_numeric_key(self, f) {
+            write_quoted_member_name(&numeric_key_text(self, f), f);
+            return;
+        }
+
         format_number_token(
             f.source_text().text_for(self),
             NumberFormatOptions::keep_one_trailing_decimal_zero(),
@@ -1024,6 +1113,10 @@ impl<'a> FormatWrite<'a> for AstNode<'a, NumericLiteral<'a>> {
 impl<'a> FormatWrite<'a> for AstNode<'a, StringLiteral<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
         let is_jsx = matches!(self.parent, AstNodes::JSXAttribute(_));
+        write!(f, source_position(self.span.start));
+        if f.context().is_json() {
+            return write!(f, text(f.source_text().text_for(self)));
+        }
         FormatLiteralStringToken::new(
             f.source_text().text_for(self),
             /* jsx */
@@ -1036,23 +1129,29 @@ impl<'a> FormatWrite<'a> for AstNode<'a, StringLiteral<'a>> {
 
 impl<'a> FormatWrite<'a> for AstNode<'a, BigIntLiteral<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
+        // Literals that weren't created by the parser have no raw text.
+        let raw = match self.raw() {
+            Some(raw) => lowercase_number_letters(raw.as_str()),
+            None => Cow::Owned(std::format!("{}n", self.value())),
+        };
         write!(
             f,
-            text(f.context().allocator().alloc_str(&self.raw().unwrap().cow_to_ascii_lowercase()))
+            [source_position(self.span.start), text(f.context().allocator().alloc_str(&raw))]
         );
     }
 }
 
 impl<'a> FormatWrite<'a> for AstNode<'a, RegExpLiteral<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
-        let raw = self.raw().unwrap().as_str();
-        let (pattern, flags) = raw.rsplit_once('/').unwrap();
-        // TODO: print the flags without allocation.
-        let mut flags = flags.chars().collect::<std::vec::Vec<_>>();
-        flags.sort_unstable();
-        let flags = flags.iter().collect::<String>();
-        let s = StringBuilder::from_strs_array_in([pattern, "/", &flags], f.context().allocator());
-        write!(f, text(s.into_str()));
+        write!(f, source_position(self.span.start));
+
+        // Regex literals are printed verbatim, including the order of their flags.
+        if let Some(raw) = self.raw() {
+            write!(f, text(raw.as_str()));
+        } else {
+            let regex = self.regex().to_string();
+            write!(f, text(f.context().allocator().alloc_str(&regex)));
+        }
     }
 }
 
@@ -1071,10 +1170,7 @@ impl<'a> FormatWrite<'a> for AstNode<'a, TSEnumDeclaration<'a>> {
 impl<'a> FormatWrite<'a> for AstNode<'a, TSEnumBody<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
         if self.members().is_empty() {
-            write!(
-                f,
-                group(&format_args!(format_dangling_comments(self.span()), soft_line_break()))
-            );
+            write!(f, format_dangling_comments(self.span()).with_block_indent());
         } else {
             write!(f, block_indent(self.members()));
         }
@@ -1083,19 +1179,31 @@ impl<'a> FormatWrite<'a> for AstNode<'a, TSEnumBody<'a>> {
 
 impl<'a> Format<'a> for AstNode<'a, Vec<'a, TSEnumMember<'a>>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) {
+        if f.options().quote_properties.is_consistent() {
+            let quote_needed = enum_has_member_requiring_quotes(self.as_ref());
+            f.context_mut().push_quote_needed(quote_needed);
+        }
+
         let trailing_separator = FormatTrailingCommas::ES5.trailing_separator(f.options());
         f.join_nodes_with_soft_line().entries_with_trailing_separator(
             self.iter(),
             ",",
             trailing_separator,
         );
+
+        if f.options().quote_properties.is_consistent() {
+            f.context_mut().pop_quote_needed();
+        }
     }
 }
 
 impl<'a> FormatWrite<'a> for AstNode<'a, TSEnumMember<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
         let id = self.id();
-        let is_computed = matches!(id.as_ref(), TSEnumMemberName::ComputedTemplateString(_));
+        let is_computed = matches!(
+            id.as_ref(),
+            TSEnumMemberName::ComputedString(_) | TSEnumMemberName::ComputedTemplateString(_)
+        );
 
         if is_computed {
             write!(f, "[");
@@ -1264,7 +1372,7 @@ impl<'a> FormatWrite<'a> for AstNode<'a, TSSymbolKeyword> {
 
 impl<'a> FormatWrite<'a> for AstNode<'a, TSThisType> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
-        write!(f, "this");
+        write!(f, [source_position(self.span.start), "this"]);
     }
 }
 
@@ -1512,14 +1620,7 @@ impl<'a> Format<'a> for FormatTSSignature<'a, '_> {
 impl<'a> Format<'a> for AstNode<'a, Vec<'a, TSSignature<'a>>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) {
         if f.options().quote_properties.is_consistent() {
-            let quote_needed = self.as_ref().iter().any(|signature| {
-                let key = match signature {
-                    TSSignature::TSPropertySignature(property) => &property.key,
-                    TSSignature::TSMethodSignature(property) => &property.key,
-                    _ => return false,
-                };
-                should_preserve_quote(key, f)
-            });
+            let quote_needed = signatures_have_property_requiring_quotes(self.as_ref());
             f.context_mut().push_quote_needed(quote_needed);
         }
 
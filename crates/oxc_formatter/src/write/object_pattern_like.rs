@@ -38,6 +38,8 @@ impl<'a> ObjectPatternLike<'a, '_> {
         }
     }
 
+    /// Whether the pattern is a parameter, or the left side of a default-valued parameter like
+    /// `function f({ a, b } = {}) {}`, which hug the parentheses instead of breaking on their own.
     fn is_inline(&self, _f: &Formatter<'_, 'a>) -> bool {
         match self {
             Self::ObjectPattern(node) => match node.parent {
@@ -62,7 +64,7 @@ impl<'a> ObjectPatternLike<'a, '_> {
                         | AstNodes::AssignmentPattern(_)
                 );
 
-                if parent_is_parameter_or_assignment_pattern {
+                if parent_is_parameter_or_assignment_pattern || self.is_for_in_or_of_left() {
                     return false;
                 }
 
@@ -74,6 +76,10 @@ impl<'a> ObjectPatternLike<'a, '_> {
                 })
             }
             Self::ObjectAssignmentTarget(node) => {
+                if self.is_for_in_or_of_left() {
+                    return false;
+                }
+
                 node.properties.iter().any(|property| match property {
                     AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(_) => false,
                     AssignmentTargetProperty::AssignmentTargetPropertyProperty(prop) => {
@@ -88,6 +94,39 @@ impl<'a> ObjectPatternLike<'a, '_> {
         }
     }
 
+    /// Whether the pattern is the left side of a `for...in` or `for...of` loop, like
+    /// `for (const { a: { b } } of xs)`, which only breaks if it doesn't fit on a line.
+    fn is_for_in_or_of_left(&self) -> bool {
+        let (parent, span) = match self {
+            Self::ObjectPattern(node) => {
+                if !matches!(node.parent, AstNodes::VariableDeclarator(_)) {
+                    return false;
+                }
+                (node.parent.parent().parent(), node.span)
+            }
+            Self::ObjectAssignmentTarget(node) => (node.parent, node.span),
+        };
+
+        match parent {
+            AstNodes::ForInStatement(stmt) => stmt.left.span().contains_inclusive(span),
+            AstNodes::ForOfStatement(stmt) => stmt.left.span().contains_inclusive(span),
+            _ => false,
+        }
+    }
+
+    /// Whether a comment between the braces is on its own line, like `// user fields` in
+    /// `const {\n  // user fields\n  name\n} = user`, which must not be collapsed onto one line.
+    fn has_own_line_comment(&self, f: &Formatter<'_, 'a>) -> bool {
+        let span = self.span();
+        f.comments()
+            .comments_in_range(span.start + 1, span.end - 1)
+            .iter()
+            .any(|comment| comment.preceded_by_newline())
+    }
+
+    /// Whether the pattern is the left side of an assignment or declarator, which isn't grouped on its own
+    /// so that the assignment first breaks after the `=`, and the pattern only breaks if the left side,
+    /// including a type annotation like `const { a, b }: Props`, doesn't fit on a line.
     fn is_in_assignment_like(&self) -> bool {
         match self {
             Self::ObjectPattern(node) => matches!(node.parent, AstNodes::VariableDeclarator(_)),
@@ -107,7 +146,7 @@ impl<'a> ObjectPatternLike<'a, '_> {
             return ObjectPatternLayout::Inline;
         }
 
-        let break_properties = self.should_break_properties();
+        let break_properties = self.should_break_properties() || self.has_own_line_comment(f);
 
         if break_properties {
             ObjectPatternLayout::Group { expand: true }
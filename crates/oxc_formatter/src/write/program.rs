@@ -2,19 +2,21 @@ use std::ops::Deref;
 
 use oxc_allocator::Vec;
 use oxc_ast::ast::*;
-use oxc_span::GetSpan;
+use oxc_span::{GetSpan, Span};
 use oxc_syntax::identifier::ZWNBSP;
 
 use crate::{
     Buffer, Format,
     ast_nodes::AstNode,
+    format_args,
     formatter::{prelude::*, trivia::FormatTrailingComments},
+    pragma::{PRAGMA, has_pragma},
     utils::string::{FormatLiteralStringToken, StringLiteralParentKind},
     write,
     write::semicolon::OptionalSemicolon,
 };
 
-use super::FormatWrite;
+use super::{FormatWrite, block_statement::write_statements};
 
 impl<'a> FormatWrite<'a> for AstNode<'a, Program<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
@@ -35,10 +37,12 @@ impl<'a> FormatWrite<'a> for AstNode<'a, Program<'a>> {
                     .is_some_and(|c| c == ZWNBSP)
                     .then_some(text("\u{feff}")),
                 self.hashbang(),
+                (f.options().insert_pragma && !has_pragma(self))
+                    .then_some(format_args!(text(PRAGMA), empty_line())),
                 self.directives(),
                 FormatProgramBody(self.body()),
                 format_trailing_comments,
-                hard_line_break()
+                f.options().insert_final_newline.then_some(hard_line_break())
             ]
         );
     }
@@ -55,41 +59,44 @@ impl<'a> Deref for FormatProgramBody<'a, '_> {
 
 impl<'a> Format<'a> for FormatProgramBody<'a, '_> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) {
-        let mut join = f.join_nodes_with_hardline();
-        for stmt in
-            self.iter().filter(|stmt| !matches!(stmt.as_ref(), Statement::EmptyStatement(_)))
-        {
-            let span = match stmt.as_ref() {
-                // `@decorator export class A {}`
-                // Get the span of the decorator.
-                Statement::ExportNamedDeclaration(export) => {
-                    if let Some(Declaration::ClassDeclaration(decl)) = &export.declaration
-                        && let Some(decorator) = decl.decorators.first()
-                        && decorator.span().start < export.span.start
-                    {
-                        decorator.span()
-                    } else {
-                        export.span
-                    }
-                }
-                // `@decorator export default class A {}`
-                // Get the span of the decorator.
-                Statement::ExportDefaultDeclaration(export) => {
-                    if let ExportDefaultDeclarationKind::ClassDeclaration(decl) =
-                        &export.declaration
-                        && let Some(decorator) = decl.decorators.first()
-                        && decorator.span().start < export.span.start
-                    {
-                        decorator.span()
-                    } else {
-                        export.span
-                    }
-                }
-                _ => stmt.span(),
-            };
-
-            join.entry(span, stmt);
+        write_statements(
+            || self.iter().filter(|stmt| !matches!(stmt.as_ref(), Statement::EmptyStatement(_))),
+            statement_span,
+            // The program claims any range that isn't covered by a nested list.
+            Some(self.parent.span()),
+            f,
+        );
+    }
+}
+
+/// Returns the span of a program statement, including decorators preceding an `export`.
+fn statement_span(stmt: &AstNode<'_, Statement<'_>>) -> Span {
+    match stmt.as_ref() {
+        // `@decorator export class A {}`
+        // Get the span of the decorator.
+        Statement::ExportNamedDeclaration(export) => {
+            if let Some(Declaration::ClassDeclaration(decl)) = &export.declaration
+                && let Some(decorator) = decl.decorators.first()
+                && decorator.span().start < export.span.start
+            {
+                decorator.span()
+            } else {
+                export.span
+            }
+        }
+        // `@decorator export default class A {}`
+        // Get the span of the decorator.
+        Statement::ExportDefaultDeclaration(export) => {
+            if let ExportDefaultDeclarationKind::ClassDeclaration(decl) = &export.declaration
+                && let Some(decorator) = decl.decorators.first()
+                && decorator.span().start < export.span.start
+            {
+                decorator.span()
+            } else {
+                export.span
+            }
         }
+        _ => stmt.span(),
     }
 }
 
@@ -121,8 +128,8 @@ impl<'a> Format<'a> for AstNode<'a, Vec<'a, Directive<'a>>> {
             last_directive.span.end
         };
 
-        let need_extra_empty_line = f.source_text().lines_after(end) > 1;
-        write!(f, if need_extra_empty_line { empty_line() } else { hard_line_break() });
+        let line_breaks = f.source_text().lines_after(end);
+        write!(f, empty_lines(f.source_empty_lines(line_breaks)));
     }
 }
 
@@ -147,10 +154,7 @@ impl<'a> FormatWrite<'a> for AstNode<'a, Hashbang<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
         write!(f, ["#!", text(self.value().as_str().trim_end())]);
 
-        if f.source_text().lines_after(self.span.end) > 1 {
-            write!(f, [empty_line()]);
-        } else {
-            write!(f, [hard_line_break()]);
-        }
+        let line_breaks = f.source_text().lines_after(self.span.end);
+        write!(f, [empty_lines(f.source_empty_lines(line_breaks))]);
     }
 }
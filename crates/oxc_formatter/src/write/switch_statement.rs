@@ -1,15 +1,15 @@
 use oxc_allocator::Vec;
-use oxc_ast::ast::*;
+use oxc_ast::{Comment, ast::*};
 use oxc_span::GetSpan;
 
 use crate::{
     Format,
-    ast_nodes::AstNode,
+    ast_nodes::{AstNode, AstNodes},
     format_args,
     formatter::{
         Formatter,
         prelude::*,
-        trivia::{DanglingIndentMode, FormatDanglingComments},
+        trivia::{DanglingIndentMode, FormatDanglingComments, format_dangling_comments},
     },
     utils::statement_body::FormatStatementBody,
     write,
@@ -21,22 +21,23 @@ impl<'a> FormatWrite<'a> for AstNode<'a, SwitchStatement<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
         let discriminant = self.discriminant();
         let cases = self.cases();
-        let format_cases =
-            format_with(|f| if cases.is_empty() { hard_line_break().fmt(f) } else { cases.fmt(f) });
         write!(
             f,
-            [
-                "switch",
-                space(),
-                "(",
-                group(&soft_block_indent(&discriminant)),
-                ")",
-                space(),
-                "{",
-                block_indent(&format_cases),
-                "}"
-            ]
+            ["switch", space(), "(", group(&soft_block_indent(&discriminant)), ")", space(), "{"]
         );
+
+        if cases.is_empty() {
+            // Keep comments of an empty switch inside its braces, e.g. `switch (x) { // comment }`
+            if f.context().comments().has_comment_before(self.span.end) {
+                write!(f, [format_dangling_comments(self.span).with_block_indent()]);
+            } else {
+                write!(f, [hard_line_break()]);
+            }
+        } else {
+            write!(f, [block_indent(&cases)]);
+        }
+
+        write!(f, "}");
     }
 }
 
@@ -92,6 +93,17 @@ impl<'a> FormatWrite<'a> for AstNode<'a, SwitchCase<'a>> {
             // Print nothing to ensure that trailing comments on the same line
             // are printed on the same line. The parent list formatter takes
             // care of inserting a hard line break between cases.
+            // Own-line comments that are indented like a consequent keep that indentation.
+            let (comments, dedented_comments) = consequent_trailing_comments(self, f);
+            if !comments.is_empty() {
+                write!(
+                    f,
+                    [
+                        indent(&format_consequent_comments(comments, self.span.end)),
+                        format_dedented_comments(dedented_comments)
+                    ]
+                );
+            }
             return;
         }
 
@@ -134,8 +146,107 @@ impl<'a> FormatWrite<'a> for AstNode<'a, SwitchCase<'a>> {
         if is_single_block_statement {
             write!(f, [FormatStatementBody::new(first_statement)]);
         } else {
+            let (comments, dedented_comments) = consequent_trailing_comments(self, f);
+            let consequent_end = consequent.last().unwrap().span().end;
             // no line break needed after because it is added by the indent in the switch statement
-            write!(f, indent(&format_args!(hard_line_break(), consequent)));
+            write!(
+                f,
+                [
+                    indent(&format_args!(
+                        hard_line_break(),
+                        consequent,
+                        format_consequent_comments(comments, consequent_end)
+                    )),
+                    format_dedented_comments(dedented_comments)
+                ]
+            );
         }
     }
 }
+
+/// Prints `comments` on their own lines after the consequent ending at `consequent_end`.
+fn format_consequent_comments(comments: &[Comment], consequent_end: u32) -> impl Format<'_> {
+    format_with(move |f| {
+        if comments.is_empty() {
+            return;
+        }
+        if f.source_text().lines_after(consequent_end) > 1 {
+            write!(f, [empty_line()]);
+        } else {
+            write!(f, [hard_line_break()]);
+        }
+        write!(
+            f,
+            [FormatDanglingComments::Comments { comments, indent: DanglingIndentMode::None }]
+        );
+    })
+}
+
+/// Prints `comments` on their own lines at the level of the `case` keyword.
+fn format_dedented_comments(comments: &[Comment]) -> impl Format<'_> {
+    format_with(move |f| {
+        if !comments.is_empty() {
+            write!(
+                f,
+                [
+                    hard_line_break(),
+                    FormatDanglingComments::Comments { comments, indent: DanglingIndentMode::None }
+                ]
+            );
+        }
+    })
+}
+
+/// Returns the own-line comments after the consequent of `case` that are indented deeper than
+/// the `case` keyword, e.g. a `// fallthrough` comment, so that they keep the indentation of the
+/// consequent instead of becoming leading comments of the next case.
+///
+/// For the last case, the remaining comments before the closing brace are returned as well, so
+/// that they are printed inside the switch statement at the level of the `case` keyword.
+///
+/// ```js
+/// switch (key) {
+///   case 1:
+///     doSomething();
+///     // fallthrough
+///   // leading comment of case 2
+///   case 2:
+///     doSomethingElse();
+///     // trailing comment of the consequent
+///   // trailing comment of case 2
+/// }
+/// ```
+fn consequent_trailing_comments<'a>(
+    case: &AstNode<'a, SwitchCase<'a>>,
+    f: &Formatter<'_, 'a>,
+) -> (&'a [Comment], &'a [Comment]) {
+    let AstNodes::SwitchStatement(switch) = case.parent else {
+        return (&[], &[]);
+    };
+    let next_case_start =
+        switch.cases.iter().map(|case| case.span.start).find(|&start| start >= case.span.end);
+
+    let source_text = f.source_text();
+    let column = |position: u32| {
+        let line = source_text.slice_to(position);
+        line.len() - line.rfind(['\n', '\r']).map_or(0, |index| index + 1)
+    };
+    let case_column = column(case.span.start);
+
+    let comments = f.context().comments();
+    let trailing_comments = comments.comments_before(next_case_start.unwrap_or(switch.span.end));
+    let count = trailing_comments
+        .iter()
+        .take_while(|comment| {
+            comment.preceded_by_newline() && column(comment.span.start) > case_column
+        })
+        .count();
+    let (comments, remaining_comments) = trailing_comments.split_at(count);
+
+    // The last case is followed by the closing brace, print the remaining comments after it
+    if count > 0 && next_case_start.is_none() {
+        (comments, remaining_comments)
+    } else {
+        (comments, &[])
+    }
+}
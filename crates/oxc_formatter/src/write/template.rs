@@ -20,6 +20,7 @@ use crate::{
     utils::{
         call_expression::is_test_each_pattern,
         format_node_without_trailing_comments::FormatNodeWithoutTrailingComments,
+        suppressed::FormatSuppressedNode,
     },
     write,
 };
@@ -28,6 +29,12 @@ use super::FormatWrite;
 
 impl<'a> FormatWrite<'a> for AstNode<'a, TemplateLiteral<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
+        if f.options().embedded_language_formatting.is_auto()
+            && has_graphql_comment(self, f)
+            && try_format_embedded_graphql(self, f)
+        {
+            return;
+        }
         let template = TemplateLike::TemplateLiteral(self);
         write!(f, template);
     }
@@ -67,7 +74,13 @@ impl<'a> FormatWrite<'a> for AstNode<'a, TaggedTemplateExpression<'a>> {
 
 impl<'a> FormatWrite<'a> for AstNode<'a, TemplateElement<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) {
-        write!(f, text(self.value.raw.as_str()));
+        if let Some(format_range) = f.context_mut().format_range_mut() {
+            format_range.check_template_element(self.span);
+        }
+        // The parser normalizes `\r\n` in `raw` to `\n`, but line breaks in template literals are part
+        // of the value and must be printed as they are in the source.
+        let raw = f.source_text().text_for(self);
+        write!(f, [source_position(self.span.start), verbatim_text(raw)]);
     }
 }
 
@@ -680,11 +693,22 @@ impl<'a> Format<'a> for EachTemplateTable<'a> {
 }
 
 /// Try to format a tagged template with the embedded formatter if supported.
-/// Returns `Some(result)` if formatting was attempted, `None` if not applicable.
+/// Returns `true` if the template was formatted.
 fn try_format_embedded_template<'a>(
     tagged: &AstNode<'a, TaggedTemplateExpression<'a>>,
     f: &mut Formatter<'_, 'a>,
 ) -> bool {
+    if f.options().embedded_language_formatting.is_off() {
+        return false;
+    }
+    if is_styled_components_tag(&tagged.tag) {
+        return try_format_embedded_css(tagged, f);
+    }
+    if matches!(&tagged.tag, Expression::Identifier(ident) if matches!(ident.name.as_str(), "gql" | "graphql"))
+    {
+        return try_format_embedded_graphql(tagged.quasi(), f);
+    }
+
     let quasi = &tagged.quasi;
     if !quasi.is_no_substitution_template() {
         return false;
@@ -710,20 +734,253 @@ fn try_format_embedded_template<'a>(
         return false;
     };
 
-    // Format with proper template literal structure:
-    // - Opening backtick
-    // - Hard line break (newline after backtick)
-    // - Indented content (each line will be indented)
-    // - Hard line break (newline before closing backtick)
-    // - Closing backtick
-    let format_content = format_with(|f: &mut Formatter<'_, 'a>| {
-        let content = f.context().allocator().alloc_str(&formatted);
-        for line in content.split('\n') {
-            write!(f, [text(line), hard_line_break()]);
+    let formatted = f.context().allocator().alloc_str(&formatted);
+    write!(
+        f,
+        FormatEmbeddedContent { text: formatted, expressions: EmbeddedExpressions::Formatted(&[]) }
+    );
+
+    true
+}
+
+/// Returns `true` for the tags of styled-components and similar CSS-in-JS libraries:
+///
+/// ```js
+/// styled.div``;
+/// styled(Component)``;
+/// styled.div.attrs({})``;
+/// styled(Component).attrs({})``;
+/// css``;
+/// css.global``;
+/// createGlobalStyle``;
+/// keyframes``;
+/// ```
+fn is_styled_components_tag(tag: &Expression) -> bool {
+    let is_styled = |expression: &Expression| expression.is_specific_id("styled");
+    match tag {
+        Expression::Identifier(ident) => {
+            matches!(
+                ident.name.as_str(),
+                "css" | "createGlobalStyle" | "keyframes" | "injectGlobal"
+            )
         }
-    });
+        Expression::StaticMemberExpression(member) => {
+            is_styled(&member.object)
+                || (member.object.is_specific_id("css")
+                    && matches!(member.property.name.as_str(), "global" | "resolve"))
+        }
+        Expression::CallExpression(call) => {
+            is_styled(&call.callee)
+                || call.callee.as_member_expression().is_some_and(|callee| match callee.object() {
+                    Expression::StaticMemberExpression(member) => is_styled(&member.object),
+                    Expression::CallExpression(call) => is_styled(&call.callee),
+                    _ => false,
+                })
+        }
+        _ => false,
+    }
+}
 
-    write!(f, ["`", block_indent(&format_content), "`"]);
+/// The prefix of the placeholders replacing the `${}` expressions in the text passed to the
+/// embedded formatter, followed by the index of the expression, e.g. `@prettier-placeholder-0`.
+/// CSS formatters like Prettier's accept them in place of values, selectors and declarations.
+const PLACEHOLDER_PREFIX: &str = "@prettier-placeholder-";
+
+/// Formats a CSS template of styled-components, see [`is_styled_components_tag`].
+///
+/// The expressions are replaced with placeholders and the text is formatted by the embedded formatter.
+/// Without one, or if it fails, the text is only re-indented. The expressions are formatted and
+/// printed in place of their placeholders.
+fn try_format_embedded_css<'a>(
+    tagged: &AstNode<'a, TaggedTemplateExpression<'a>>,
+    f: &mut Formatter<'_, 'a>,
+) -> bool {
+    let quasi = tagged.quasi();
+    let Some(text) = text_with_placeholders(quasi) else {
+        return false;
+    };
+
+    let expressions = quasi.expressions().iter().collect::<std::vec::Vec<_>>();
+    let formatted = f
+        .context()
+        .embedded_formatter()
+        .and_then(|embedded_formatter| embedded_formatter.format("css", &text).ok())
+        .filter(|formatted| has_placeholders_in_order(formatted, expressions.len()));
+
+    let text = f.context().allocator().alloc_str(formatted.as_deref().unwrap_or(&text));
+    write!(
+        f,
+        FormatEmbeddedContent { text, expressions: EmbeddedExpressions::Formatted(&expressions) }
+    );
 
     true
 }
+
+/// Returns `true` if the template literal is marked as GraphQL by a `/* GraphQL */` comment.
+fn has_graphql_comment(template: &AstNode<'_, TemplateLiteral<'_>>, f: &Formatter<'_, '_>) -> bool {
+    f.source_text().slice_to(template.span.start).trim_end().ends_with("/* GraphQL */")
+}
+
+/// Formats a GraphQL template, e.g. `gql\`...\`` or `/* GraphQL */ \`...\``.
+///
+/// Templates without expressions are formatted by the embedded formatter. Otherwise, or without one,
+/// the text is only re-indented, and the expressions are printed verbatim.
+fn try_format_embedded_graphql<'a>(
+    quasi: &AstNode<'a, TemplateLiteral<'a>>,
+    f: &mut Formatter<'_, 'a>,
+) -> bool {
+    let Some(text) = text_with_placeholders(quasi) else {
+        return false;
+    };
+
+    let formatted = if quasi.expressions.is_empty() {
+        f.context()
+            .embedded_formatter()
+            .and_then(|embedded_formatter| embedded_formatter.format("graphql", &text).ok())
+    } else {
+        None
+    };
+
+    let text = f.context().allocator().alloc_str(formatted.as_deref().unwrap_or(&text));
+    write!(
+        f,
+        FormatEmbeddedContent { text, expressions: EmbeddedExpressions::Verbatim(&quasi.quasis) }
+    );
+
+    true
+}
+
+/// Returns the text of the template with the expressions replaced by placeholders,
+/// or `None` if the placeholders would be ambiguous.
+fn text_with_placeholders(quasi: &TemplateLiteral<'_>) -> Option<String> {
+    let mut text = String::new();
+    for (index, element) in quasi.quasis.iter().enumerate() {
+        let raw = element.value.raw.as_str();
+        // A digit right after a placeholder would be read as part of its index.
+        if raw.contains(PLACEHOLDER_PREFIX)
+            || (index > 0 && raw.starts_with(|c: char| c.is_ascii_digit()))
+        {
+            return None;
+        }
+        if index > 0 {
+            text.push_str(PLACEHOLDER_PREFIX);
+            text.push_str(&(index - 1).to_string());
+        }
+        text.push_str(raw);
+    }
+    Some(text)
+}
+
+/// Returns `true` if `text` contains the placeholders of `count` expressions once each and in order.
+fn has_placeholders_in_order(text: &str, count: usize) -> bool {
+    let mut expected = 0;
+    for (start, _) in text.match_indices(PLACEHOLDER_PREFIX) {
+        let after = &text[start + PLACEHOLDER_PREFIX.len()..];
+        let digits = after.bytes().take_while(u8::is_ascii_digit).count();
+        if after[..digits].parse() != Ok(expected) {
+            return false;
+        }
+        expected += 1;
+    }
+    expected == count
+}
+
+/// Formats the text of an embedded template on its own indented lines between the backticks.
+///
+/// The text is re-indented: the common indentation of its lines is removed, as well as trailing
+/// whitespace and blank lines at the start and end. Consecutive blank lines are collapsed into one.
+/// The placeholders in the text are replaced with the `expressions`.
+struct FormatEmbeddedContent<'a, 'b> {
+    text: &'a str,
+    expressions: EmbeddedExpressions<'a, 'b>,
+}
+
+/// How the expressions of an embedded template are printed in place of their placeholders.
+enum EmbeddedExpressions<'a, 'b> {
+    /// The expressions are formatted.
+    Formatted(&'b [&'b AstNode<'a, Expression<'a>>]),
+    /// The expressions are printed as in the source text, located between the given template elements.
+    Verbatim(&'b [TemplateElement<'a>]),
+}
+
+impl<'a> Format<'a> for FormatEmbeddedContent<'a, '_> {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) {
+        let lines = dedent_lines(self.text);
+        if lines.is_empty() {
+            return write!(f, "``");
+        }
+
+        let content = format_with(|f: &mut Formatter<'_, 'a>| {
+            let mut after_blank_line = false;
+            for (index, line) in lines.iter().enumerate() {
+                if line.is_empty() {
+                    after_blank_line = true;
+                    continue;
+                }
+                if index > 0 {
+                    if after_blank_line {
+                        write!(f, empty_line());
+                    } else {
+                        write!(f, hard_line_break());
+                    }
+                }
+                after_blank_line = false;
+                self.write_line(line, f);
+            }
+        });
+
+        write!(f, ["`", block_indent(&content), "`"]);
+    }
+}
+
+impl<'a> FormatEmbeddedContent<'a, '_> {
+    fn write_line(&self, line: &'a str, f: &mut Formatter<'_, 'a>) {
+        let mut rest = line;
+        while let Some(start) = rest.find(PLACEHOLDER_PREFIX) {
+            if start > 0 {
+                write!(f, text(&rest[..start]));
+            }
+            let after = &rest[start + PLACEHOLDER_PREFIX.len()..];
+            let digits = after.bytes().take_while(u8::is_ascii_digit).count();
+            let index = after[..digits].parse::<usize>().unwrap();
+            match self.expressions {
+                EmbeddedExpressions::Formatted(expressions) => {
+                    let expression = TemplateExpression::Expression(expressions[index]);
+                    let options = FormatTemplateExpressionOptions::default();
+                    FormatTemplateExpression::new(&expression, options).fmt(f);
+                }
+                EmbeddedExpressions::Verbatim(quasis) => {
+                    let span = Span::new(quasis[index].span.end, quasis[index + 1].span.start);
+                    write!(f, FormatSuppressedNode(span));
+                }
+            }
+            rest = &after[digits..];
+        }
+        if !rest.is_empty() {
+            write!(f, text(rest));
+        }
+    }
+}
+
+/// Splits `text` into lines without trailing whitespace and their common indentation,
+/// and without blank lines at the start and end.
+/// The first line starts right after the opening backtick, so its indentation is removed entirely.
+fn dedent_lines(text: &str) -> std::vec::Vec<&str> {
+    let mut lines = text.split('\n').map(str::trim_end).collect::<std::vec::Vec<_>>();
+    lines[0] = lines[0].trim_start();
+
+    let indentation = |line: &str| line.len() - line.trim_start_matches([' ', '\t']).len();
+    let common_indentation =
+        lines.iter().skip(1).filter(|line| !line.is_empty()).map(|line| indentation(line)).min();
+    if let Some(common_indentation) = common_indentation {
+        for line in lines.iter_mut().skip(1).filter(|line| !line.is_empty()) {
+            *line = &line[common_indentation..];
+        }
+    }
+
+    let start = lines.iter().position(|line| !line.is_empty()).unwrap_or(lines.len());
+    let end = lines.iter().rposition(|line| !line.is_empty()).map_or(start, |end| end + 1);
+    lines.drain(end..);
+    lines.drain(..start);
+    lines
+}
@@ -6,7 +6,10 @@ use crate::{
     formatter::{
         Formatter,
         prelude::*,
-        trivia::{FormatLeadingComments, FormatTrailingComments},
+        trivia::{
+            DanglingIndentMode, FormatDanglingComments, FormatLeadingComments,
+            FormatTrailingComments,
+        },
     },
     write,
 };
@@ -22,38 +25,81 @@ impl<'a> FormatWrite<'a> for AstNode<'a, TryStatement<'a>> {
 
         // Use `write` rather than `write!` in order to avoid printing leading comments for `block`.
         block.write(f);
+        let mut previous_end = block.span.end;
         if let Some(handler) = handler {
-            write!(f, [space(), handler]);
+            write_comments_before_keyword(previous_end, f);
+            write!(f, handler);
+            previous_end = handler.span.end;
         }
         if let Some(finalizer) = finalizer {
-            write!(f, [space(), "finally", space()]);
+            write_comments_before_keyword(previous_end, f);
+            write!(f, ["finally", space()]);
             finalizer.write(f);
         }
     }
 }
 
-impl<'a> FormatWrite<'a> for AstNode<'a, CatchClause<'a>> {
-    fn write(&self, f: &mut Formatter<'_, 'a>) {
-        let comments = f.context().comments();
-        let leading_comments = comments.comments_before(self.span.start);
-        let has_line_comment = leading_comments.iter().any(|comment| comment.has_newlines_around());
-
-        if has_line_comment {
-            // `try {} /* comment */\n catch (e) {}`
-            // should be formatted like:
-            // `try {} catch (e) { /* comment */ }`
-            //
-            // Comments before the catch clause should be printed in the block statement.
-            // We cache them here to avoid the `params` printing them accidentally.
-            let printed_comments = f.intern(&FormatLeadingComments::Comments(leading_comments));
-            if let Some(comments) = printed_comments {
-                f.context_mut().cache_element(&self.span, comments);
+/// Writes the comments between a closing `}` that ends at `previous_end` and the following
+/// `catch` or `finally` keyword, and the separator before the keyword.
+///
+/// Comments on the same line as the `}` stay after it, while own-line comments stay on their
+/// own lines and move the keyword onto the next line, like comments before `else`.
+///
+/// ```js
+/// try {
+/// } // trailing
+/// catch {
+/// }
+/// // own line
+/// finally {
+/// }
+/// ```
+fn write_comments_before_keyword(previous_end: u32, f: &mut Formatter<'_, '_>) {
+    let source_text = f.source_text();
+    let mut end = previous_end;
+    let unprinted_comments = f.context().comments().unprinted_comments();
+    let count = unprinted_comments
+        .iter()
+        .take_while(|comment| {
+            let is_before_keyword =
+                source_text.slice_range(end, comment.span.start).trim().is_empty();
+            end = comment.span.end;
+            is_before_keyword
+        })
+        .count();
+    let comments = &unprinted_comments[..count];
+
+    let own_line_index = comments.iter().position(|comment| comment.preceded_by_newline());
+    let (trailing_comments, own_line_comments) =
+        comments.split_at(own_line_index.unwrap_or(comments.len()));
+
+    write!(f, FormatTrailingComments::Comments(trailing_comments));
+
+    if let Some(first_comment) = own_line_comments.first() {
+        if f.source_text().get_lines_before(first_comment.span, f.comments()) > 1 {
+            write!(f, empty_line());
+        } else {
+            write!(f, hard_line_break());
+        }
+        write!(
+            f,
+            FormatDanglingComments::Comments {
+                comments: own_line_comments,
+                indent: DanglingIndentMode::None
             }
-        } else if !leading_comments.is_empty() {
-            // otherwise, print them before `catch`
-            write!(f, [FormatTrailingComments::Comments(leading_comments), space()]);
+        );
+    }
+
+    match comments.last() {
+        Some(last_comment) if last_comment.is_line() || last_comment.followed_by_newline() => {
+            write!(f, hard_line_break());
         }
+        _ => write!(f, space()),
+    }
+}
 
+impl<'a> FormatWrite<'a> for AstNode<'a, CatchClause<'a>> {
+    fn write(&self, f: &mut Formatter<'_, 'a>) {
         write!(f, ["catch", space(), self.param(), space()]);
 
         // Use `write` rather than `write!` in order to avoid printing leading comments for `block`.
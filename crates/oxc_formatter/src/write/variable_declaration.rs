@@ -79,18 +79,40 @@ impl<'a> Format<'a> for AstNode<'a, Vec<'a, VariableDeclarator<'a>>> {
             };
         }
 
-        write!(
-            f,
-            indent(&format_once(|f| {
-                write!(f, first_declarator);
+        let format_declarators = format_once(|f| {
+            write!(f, first_declarator);
 
-                if length > 1 {
-                    write!(f, format_separator);
-                }
+            if length > 1 {
+                write!(f, format_separator);
+            }
 
-                f.join_with(format_separator).entries(declarators);
-            }))
-        );
+            f.join_with(format_separator).entries(declarators);
+        });
+
+        // Outside of `for` heads, continuation lines are aligned after the keyword:
+        // ```js
+        // const a = 1,
+        //       b = 2;
+        // ```
+        if !is_parent_for_loop
+            && length > 1
+            && f.options().indent_style.is_space()
+            && let AstNodes::VariableDeclaration(declaration) = self.parent
+        {
+            let export_width = if matches!(declaration.parent, AstNodes::ExportNamedDeclaration(_))
+            {
+                "export ".len()
+            } else {
+                0
+            };
+            let declare_width = if declaration.declare { "declare ".len() } else { 0 };
+            #[expect(clippy::cast_possible_truncation)]
+            let keyword_width =
+                (export_width + declare_width + declaration.kind.as_str().len() + 1) as u8;
+            write!(f, align(keyword_width, &format_declarators));
+        } else {
+            write!(f, indent(&format_declarators));
+        }
     }
 }
 
@@ -0,0 +1,121 @@
+use oxc_allocator::Allocator;
+use oxc_formatter::{FormatOptions, best_fitting, format, format_args, prelude::*, write};
+use oxc_span::SourceType;
+
+// The example of `oxc_formatter::prelude`, the crate's doctests are disabled.
+
+/// A list formatted like `[a, b, c]`, or with an item per line if it doesn't fit on one line.
+struct List<'a>(&'a [&'a str]);
+
+impl<'a> Format<'a> for List<'a> {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) {
+        let items = format_with(|f| {
+            f.join_with(&format_args!(token(","), soft_line_break_or_space()))
+                .entries(self.0.iter().map(|item| text(item)));
+        });
+        write!(
+            f,
+            [group(&format_args!(
+                token("["),
+                soft_block_indent(&format_args!(items, if_group_breaks(&token(",")))),
+                token("]")
+            ))]
+        );
+    }
+}
+
+fn print<'a>(allocator: &'a Allocator, content: &dyn Format<'a>, line_width: u16) -> String {
+    let options =
+        FormatOptions { line_width: line_width.try_into().unwrap(), ..FormatOptions::default() };
+    let context = FormatContext::new("", SourceType::mjs(), &[], allocator, options, None);
+    format!(context, [content]).print().unwrap().into_code()
+}
+
+#[test]
+fn custom_format() {
+    let allocator = Allocator::default();
+    assert_eq!(print(&allocator, &List(&["a", "b", "c"]), 80), "[a, b, c]");
+    assert_eq!(
+        print(&allocator, &List(&["first", "second", "third"]), 10),
+        "[\n  first,\n  second,\n  third,\n]"
+    );
+}
+
+#[test]
+fn dynamic_text() {
+    let allocator = Allocator::default();
+    let content = format_with(|f| {
+        let name = f.allocator().alloc_str(&["a", "b"].concat());
+        write!(f, [token("let"), space(), text(name), token(";")]);
+    });
+    assert_eq!(print(&allocator, &content, 80), "let ab;");
+}
+
+#[test]
+fn group_ids() {
+    let allocator = Allocator::default();
+    let content = format_with(|f| {
+        let id = f.group_id("list");
+        write!(
+            f,
+            [
+                group(&List(&["first", "second"])).with_group_id(Some(id)),
+                if_group_breaks(&token(" // broken")).with_group_id(Some(id)),
+                if_group_fits_on_line(&token(" // flat")).with_group_id(Some(id)),
+                hard_line_break(),
+                indent(&format_args!(token("end"), hard_line_break(), token("done")))
+            ]
+        );
+    });
+    assert_eq!(print(&allocator, &content, 80), "[first, second] // flat\nend\n  done");
+    assert_eq!(print(&allocator, &content, 10), "[\n  first,\n  second,\n] // broken\nend\n  done");
+}
+
+#[test]
+fn best_fitting() {
+    let allocator = Allocator::default();
+    let content = format_with(|f| {
+        write!(
+            f,
+            [best_fitting!(
+                format_args!(token("call("), text("argument"), token(")")),
+                format_args!(token("call("), block_indent(&text("argument")), token(")"))
+            )]
+        );
+    });
+    assert_eq!(print(&allocator, &content, 80), "call(argument)");
+    assert_eq!(print(&allocator, &content, 10), "call(\n  argument\n)");
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Heading;
+
+impl Label for Heading {
+    fn id(&self) -> u64 {
+        0
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "Heading"
+    }
+}
+
+#[test]
+fn labelled_content() {
+    let allocator = Allocator::default();
+    let context =
+        FormatContext::new("", SourceType::mjs(), &[], &allocator, FormatOptions::default(), None);
+    let formatted = format!(
+        context,
+        [
+            token("before"),
+            hard_line_break(),
+            labelled(LabelId::of(Heading), &List(&["a", "b"])),
+            hard_line_break(),
+            token("after")
+        ]
+    );
+    assert_eq!(formatted.print().unwrap().as_code(), "before\n[a, b]\nafter");
+    let printed = formatted.print_labelled(LabelId::of(Heading), None).unwrap();
+    assert_eq!(printed.as_deref(), Some("[a, b]"));
+}
@@ -0,0 +1,51 @@
+use oxc_allocator::Allocator;
+use oxc_formatter::{CheckResult, FormatOptions, Formatter, get_parse_options};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+fn check(code: &str) -> CheckResult {
+    let allocator = Allocator::new();
+    let source_type = SourceType::from_path("dummy.tsx").unwrap();
+    let ret = Parser::new(&allocator, code, source_type).with_options(get_parse_options()).parse();
+    if let Some(error) = ret.errors.first() {
+        panic!("💥 Parser error: {}", error.message);
+    }
+
+    Formatter::new(&allocator, FormatOptions::default()).check(&ret.program).unwrap()
+}
+
+#[test]
+fn formatted() {
+    assert_eq!(check("const a = 1;\n\nfoo(a, \"b\");\n"), CheckResult::Formatted);
+    assert_eq!(check(""), CheckResult::Formatted);
+}
+
+#[test]
+fn first_difference() {
+    let code = "const a = 1;\nfoo(a,  \"b\");\n";
+    assert_eq!(check(code), CheckResult::Unformatted { offset: 20 });
+    assert_eq!(&code[20..], " \"b\");\n");
+
+    assert_eq!(check("const a = 'b';\n"), CheckResult::Unformatted { offset: 10 });
+}
+
+#[test]
+fn missing_or_extra_code_at_end() {
+    assert_eq!(check("const a = 1;"), CheckResult::Unformatted { offset: 12 });
+    assert_eq!(check("const a = 1;\n\n"), CheckResult::Unformatted { offset: 13 });
+}
+
+#[test]
+fn offset_after_non_ascii_text() {
+    assert_eq!(check("const é = 'é';\n"), CheckResult::Unformatted { offset: 11 });
+}
+
+#[test]
+fn large_file() {
+    let formatted = "foo(a, \"b\");\n".repeat(10_000);
+    assert_eq!(check(&formatted), CheckResult::Formatted);
+
+    let code = format!("{formatted}foo(a,  \"b\");\n");
+    let offset = u32::try_from(formatted.len()).unwrap() + 7;
+    assert_eq!(check(&code), CheckResult::Unformatted { offset });
+}
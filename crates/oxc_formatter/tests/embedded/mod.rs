@@ -0,0 +1,149 @@
+use std::{
+    fmt::Write,
+    sync::{Arc, Mutex},
+};
+
+use oxc_allocator::Allocator;
+use oxc_formatter::{
+    EmbeddedFormatter, EmbeddedFormatterCallback, EmbeddedLanguageFormatter,
+    EmbeddedLanguageFormatting, FormatOptions, Formatter, get_parse_options,
+};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+fn options() -> FormatOptions {
+    FormatOptions {
+        embedded_language_formatting: EmbeddedLanguageFormatting::Auto,
+        ..FormatOptions::default()
+    }
+}
+
+fn format_with_embedded(code: &str, callback: EmbeddedFormatterCallback) -> String {
+    let allocator = Allocator::new();
+    let source_type = SourceType::from_path("dummy.js").unwrap();
+    let ret = Parser::new(&allocator, code, source_type).with_options(get_parse_options()).parse();
+    if let Some(error) = ret.errors.first() {
+        panic!("💥 Parser error: {}", error.message);
+    }
+
+    Formatter::new(&allocator, options())
+        .format_with_embedded(&ret.program, EmbeddedFormatter::new(callback))
+        .print()
+        .unwrap()
+        .into_code()
+}
+
+#[test]
+fn css_with_placeholders() {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let callback: EmbeddedFormatterCallback = {
+        let received = Arc::clone(&received);
+        Arc::new(move |tag: &str, code: &str| {
+            received.lock().unwrap().push((tag.to_string(), code.to_string()));
+            Ok(code
+                .split(';')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .collect::<Vec<_>>()
+                .join(";\n")
+                + ";\n")
+        })
+    };
+
+    let code = "const Button = styled.button`color:red;  margin: ${ ( props ) => props.margin }px;border: ${border}`;\n";
+    assert_eq!(
+        format_with_embedded(code, callback),
+        "const Button = styled.button`\n  color:red;\n  margin: ${(props) => props.margin}px;\n  border: ${border};\n`;\n"
+    );
+    assert_eq!(
+        *received.lock().unwrap(),
+        [(
+            "css".to_string(),
+            "color:red;  margin: @prettier-placeholder-0px;border: @prettier-placeholder-1"
+                .to_string()
+        )]
+    );
+}
+
+#[test]
+fn css_without_placeholders_falls_back_to_indentation() {
+    let callback: EmbeddedFormatterCallback =
+        Arc::new(|_: &str, _: &str| Ok("color: red;".to_string()));
+    let code = "const a = css`\n        width: ${width};\n          height: 1px;\n`;\n";
+    assert_eq!(
+        format_with_embedded(code, callback),
+        "const a = css`\n  width: ${width};\n    height: 1px;\n`;\n"
+    );
+}
+
+#[test]
+fn css_formatter_error_falls_back_to_indentation() {
+    let callback: EmbeddedFormatterCallback = Arc::new(|_: &str, _: &str| Err("error".to_string()));
+    let code = "const a = styled(Link)`\n\n    color: red;   \n\n\n    &:hover {\n      color: blue;\n    }\n\n`;\n";
+    assert_eq!(
+        format_with_embedded(code, callback),
+        "const a = styled(Link)`\n  color: red;\n\n  &:hover {\n    color: blue;\n  }\n`;\n"
+    );
+}
+
+/// Formats GraphQL by trimming every line and indenting selections by two spaces per level.
+struct GraphQLFormatter;
+
+impl EmbeddedLanguageFormatter for GraphQLFormatter {
+    fn format(&self, tag_name: &str, code: &str) -> Result<String, String> {
+        if tag_name != "graphql" {
+            return Err(format!("unsupported tag {tag_name}"));
+        }
+        let mut level: usize = 0;
+        let mut formatted = String::new();
+        for line in code.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            if line.starts_with('}') {
+                level = level.saturating_sub(1);
+            }
+            writeln!(formatted, "{}{line}", "  ".repeat(level)).unwrap();
+            if line.ends_with('{') {
+                level += 1;
+            }
+        }
+        Ok(formatted)
+    }
+}
+
+fn format_with_language_formatter(code: &str) -> String {
+    let allocator = Allocator::new();
+    let source_type = SourceType::from_path("dummy.js").unwrap();
+    let ret = Parser::new(&allocator, code, source_type).with_options(get_parse_options()).parse();
+    if let Some(error) = ret.errors.first() {
+        panic!("💥 Parser error: {}", error.message);
+    }
+
+    Formatter::new(&allocator, options())
+        .with_embedded_language_formatter(Arc::new(GraphQLFormatter))
+        .build(&ret.program)
+}
+
+#[test]
+fn graphql_language_formatter() {
+    let code = "const a = gql`\n      query {\n  user {\n        name\n      }\n  }\n`;\nconst b = /* GraphQL */ `{ viewer { id\n} }`;\n";
+    assert_eq!(
+        format_with_language_formatter(code),
+        "const a = gql`\n  query {\n    user {\n      name\n    }\n  }\n`;\nconst b = /* GraphQL */ `\n  { viewer { id\n  } }\n`;\n"
+    );
+}
+
+#[test]
+fn graphql_expressions_are_printed_verbatim() {
+    let code = "const a = graphql`\n      query { ...F }\n      ${ fragment }\n`;\n";
+    assert_eq!(
+        format_with_language_formatter(code),
+        "const a = graphql`\n  query { ...F }\n  ${ fragment }\n`;\n"
+    );
+}
+
+#[test]
+fn off_by_default() {
+    let code = "const a = css`\n        color: red;\n`;\nconst b = gql`\n        { id }\n`;\n";
+    let allocator = Allocator::new();
+    let ret = Parser::new(&allocator, code, SourceType::mjs()).parse();
+    assert_eq!(Formatter::new(&allocator, FormatOptions::default()).build(&ret.program), code);
+}
@@ -0,0 +1,26 @@
+describe("some test suite that has a description", () => { it("works", () => { expect(1).toBe(1); }); });
+
+configure({ long: "object", with: "many", keys: 1, andMore: "values", toOverflow: true, yes: 1 });
+
+foo(bar, [aaaaaaaaaaaaaaaaaaaaaaa, bbbbbbbbbbbbbbbbbbbbbbbbbbbbbb, cccccccccccccccccccccccccccc, ddd]);
+
+setTimeout(function () { thing(); }, 500);
+
+useEffect(() => { doSomething(); }, [dependencyOne, dependencyTwo, dependencyThree, dependencyFour]);
+
+test(({ aaaaaaaaaaaa, bbbbbbbbbbbbbbbbb, ccccccccccccccccc, dddddddddddddddd }) => { run(); });
+
+app.get("/", ({ query, params }) => { return handle(query, params, someOtherLongArgument); });
+
+call(a, () => {
+  body();
+}); // trailing comment on the call
+
+call(a, () => {
+  body();
+} /* trailing comment on the last argument */);
+
+call(a, {
+  key: value,
+} // line comment after the last argument
+);
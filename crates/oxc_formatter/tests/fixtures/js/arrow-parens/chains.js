@@ -0,0 +1,4 @@
+const curried = (a) => (b) => (c) => a + b + c;
+const curriedLong = (argumentNumberOne) => (argumentNumberTwo) => (argumentNumberThree) => argumentNumberOne;
+const mixed = (a) => ({ b }) => (c = 1) => a + b + c;
+app.use((req) => (res) => (next) => next());
@@ -0,0 +1,17 @@
+const identity = (x) => x;
+const noParens = x => x;
+const withDefault = (x = 1) => x;
+const destructured = ({ a }) => a;
+const array = ([a]) => a;
+const rest = (...args) => args;
+const none = () => 1;
+const two = (a, b) => a + b;
+const asyncArrow = async (x) => x;
+const asyncNoParens = async x => x;
+const commentInside = (/* c */ x) => x;
+const commentAfter = (x /* c */) => x;
+const lineComment = (
+  // c
+  x
+) => x;
+promise.then((result) => result.value);
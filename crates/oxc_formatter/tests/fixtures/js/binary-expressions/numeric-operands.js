@@ -0,0 +1,6 @@
+const total = firstValueWithLongName + secondValueWithLongName + 1 + thirdValueWithLongName + 2;
+const offset = baseOffsetWithAVeryLongName - 1 - paddingWithAVeryLongNameToBreak - marginWithLongName;
+const label = "Item number " + index + 1 + " of the list with a very long description text that breaks";
+const product = widthWithAVeryLongName * 2 * heightWithAVeryLongName * depthWithAVeryLongName * 4;
+const sum = firstValueWithLongName + secondValueWithLongName + thirdValueWithLongName + fourth;
+const short = a + 1 + b;
@@ -0,0 +1,41 @@
+class A {
+  static {
+    // only a comment
+  }
+}
+class B {
+  static {
+
+
+    foo();
+
+
+    bar();
+  }
+  static {}
+  static {
+
+  }
+}
+class C {
+  // comment
+}
+class D {}
+class E {
+
+}
+class F { /* block */ }
+class G extends H {
+  // dangling
+}
+const I = class {
+  // comment
+};
+class J {
+  static {
+    /* a */
+    /* b */
+  }
+  static { // trailing
+  }
+}
@@ -0,0 +1,52 @@
+if (a) {
+  b();
+} // why
+else {
+  c();
+}
+if (a) {
+  b();
+} /* note */ else if (d) {
+  c();
+}
+if (a) {
+  b();
+}
+// own line
+else {
+  c();
+}
+try {
+  a();
+} // after try
+catch (e) {
+  b();
+} /* before finally */ finally {
+  c();
+}
+try {
+  a();
+}
+// own line before catch
+catch {
+  b();
+}
+// own line before finally
+finally {
+  c();
+}
+try {
+  a();
+} // trailing
+// own line
+/* own line block */ catch (e) {
+  b();
+}
+try {
+  a();
+}
+
+/* own line block before finally */
+finally {
+  c();
+}
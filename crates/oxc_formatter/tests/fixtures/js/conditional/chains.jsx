@@ -0,0 +1,10 @@
+const animal = isBird ? "bird" : isCat ? "cat" : isDog ? "dog" : isFishOrSomethingElse ? "fish" : "unknown";
+const el = <div>{isLoading ? <Spinner size="large" color="blue" /> : hasError ? <ErrorMessage error={error} /> : <Content data={data} />}</div>;
+const x = (condition ? someObjectWithLongName : someOtherObjectWithLongName).someProperty.method();
+const y = (a ? b : c).d;
+const msg = isError ? "An error occurred while processing your request" : "Your request was processed";
+const z = (isBird ? birdSound : isCat ? catSound : isDogOrAnotherAnimal ? dogSound : unknownSound).play();
+callback(isBird ? "bird" : isCat ? "cat" : isDog ? "dog" : isFishOrSomethingElse ? "fish" : "unknown");
+const message = i % 3 === 0 && i % 5 === 0 ? "fizzbuzz" : i % 3 === 0 ? "fizz" : i % 5 === 0 ? "buzz" : String(i);
+const paymentMessage = state == "success" ? "Payment completed successfully" : state == "processing" ? "Payment processing" : state == "invalid_cvc" ? "There was an issue with your CVC number" : "There was an issue with the payment.  Please contact support.";
+const nested = isBird ? (isBlue ? "blue bird with a very long description" : "other bird") : isCat ? "cat" : "unknown";
@@ -0,0 +1,9 @@
+class A {
+      /**
+       * CRLF doc  
+       * ```js
+       * const re = /a*\/;
+       * ```
+       */
+  method() {}
+}
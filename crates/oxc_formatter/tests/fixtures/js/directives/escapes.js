@@ -0,0 +1,6 @@
+'use strict';
+'it\'s';
+'say "hi"';
+"both ' and \"";
+'\u0041';
+foo();
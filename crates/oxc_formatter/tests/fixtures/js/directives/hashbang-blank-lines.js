@@ -0,0 +1,7 @@
+#!/usr/bin/env node
+
+
+"use strict";
+
+
+import a from "a";
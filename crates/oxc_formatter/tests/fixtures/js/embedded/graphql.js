@@ -0,0 +1,18 @@
+const query = gql`
+
+        query User($id: ID!) {
+          user(id: $id) {
+            name   
+          }
+        }
+
+
+        ${  UserFragment }
+`;
+function f() {
+  return /* GraphQL */ `
+  {
+    viewer { id }
+  }`;
+}
+const empty = graphql`  `;
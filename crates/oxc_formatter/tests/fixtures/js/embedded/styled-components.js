@@ -0,0 +1,23 @@
+const Button = styled.a`
+      display: inline-block;
+      border-radius: 3px;   
+      padding: 0.5rem 0;
+
+
+      ${props => props.primary && css`
+        background: white;
+          color: black;
+      `}
+      width: ${ width }px;
+    `;
+const Title = styled(Base).attrs({ x: 1 })`color: red;`;
+const g = createGlobalStyle``;
+function f() {
+  if (a) {
+    return keyframes`
+  from { opacity: 0; }
+  to { opacity: 1; }
+`;
+  }
+}
+const x = css`${a}1px`;
@@ -0,0 +1,14 @@
+const message =
+  i % 3 === 0 && i % 5 === 0 ? "fizzbuzz" : i % 3 === 0 ? "fizz" : i % 5 === 0 ? "buzz" : String(i);
+
+const paymentMessage = state == "success" ? "Payment completed successfully" : state == "processing" ? "Payment processing" : state == "invalid_cvc" ? "There was an issue with your CVC number" : state == "invalid_expiry" ? "Expiry must be sometime in the past." : "There was an issue with the payment.  Please contact support.";
+
+const short = isBird ? "bird" : "unknown";
+
+const animalName = pet.canSqueak() ? "mouse" : pet.canBark() ? "dog" : pet.canMeow() ? "cat" : "probably a bunny";
+
+const typeofExample = definition.encode ? definition.encode(typeof row[field] !== "undefined" ? row[field] : definition.default !== undefined ? definition.default : null) : typeof row[field] !== "undefined" ? row[field] : definition.default !== undefined ? definition.default : null;
+
+foo(someVeryLongConditionThatIsLong && anotherCondition ? someValueThatIsLong : someOtherValue);
+
+const value = (bifornCringerMoshedPerplexed ? a : b) ? something : somethingElse + somethingElseAgain;
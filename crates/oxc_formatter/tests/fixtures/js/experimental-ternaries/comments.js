@@ -0,0 +1,10 @@
+const y = test /* c1 */ ? cons // c2
+  : alt;
+
+const z =
+  // leading
+  isBird
+    ? "bird" // a bird
+    : isCat
+      ? "cat" // a cat
+      : "unknown"; // nothing
@@ -0,0 +1,10 @@
+const el = cond ? <div><span>hello world this is long</span><span>another long child here</span></div> : <Other />;
+
+const message =
+  i % 3 === 0 && i % 5 === 0 ? <div>fizzbuzz</div> : i % 3 === 0 ? <div>fizz</div> : i % 5 === 0 ? <div>buzz</div> : <div>{i}</div>;
+
+function App() {
+  return <div>{isLoading ? <Spinner size="large" color="blue" label="Loading..." /> : <Content items={items} />}</div>;
+}
+
+const nullable = isVisible ? <Modal title="A long title for the modal" onClose={handleClose} /> : null;
@@ -0,0 +1,5 @@
+const x = (aaaaaaaaaaaaaaaaaaaaaaaaa ? bbbbbbbbbbbbbbbbbbbbbbbbbbbbbb : ccccccccccccccccccccccccccccccccc).prop;
+
+(isCustomElement ? createCustomElementWithALongName : createElementWithAnotherLongName)(tag, props);
+
+const result = await (useCache ? readFromCacheWithALongName(key) : fetchFromNetworkWithAnotherLongName(key));
@@ -0,0 +1,7 @@
+const animalName = pet.canBark() ? pet.isScary() ? "wolf" : "dog" : pet.canMeow() ? "cat" : "probably a bunny";
+
+const x = a ? b ? c : d : e;
+
+const longNested = someCondition ? anotherVeryLongCondition ? resultNumberOneIsLong : resultNumberTwoIsLonger : fallbackValue;
+
+const reason = error.isNetworkError() ? error.isTimeout() ? "timeout" : error.isOffline() ? "offline" : "network" : "unknown";
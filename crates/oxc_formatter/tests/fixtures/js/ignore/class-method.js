@@ -0,0 +1,13 @@
+class A {
+  // prettier-ignore
+  method(  a,b  ) {
+    return    a+b;
+  }
+
+  other(  a,b  ) {
+    return    a+b;
+  }
+
+  // oxc-format-ignore
+  static   field   =   1;
+}
@@ -0,0 +1,10 @@
+const a = (
+  <div>
+    {/* prettier-ignore */}
+    <span     a = "1"   b="2"  />
+    <span     a = "1"   b="2"  />
+  </div>
+);
+
+// prettier-ignore
+const b = <div   a = "1" >  text  </div>;
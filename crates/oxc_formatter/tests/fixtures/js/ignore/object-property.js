@@ -0,0 +1,11 @@
+const matrix = {
+  // prettier-ignore
+  identity: [
+    1, 0, 0,
+    0, 1, 0,
+    0, 0, 1,
+  ],
+  other:    [1,2,3],
+  // oxc-format-ignore
+  'quoted-key'  :   {  a:1  },
+};
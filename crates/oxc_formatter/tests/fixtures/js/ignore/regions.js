@@ -0,0 +1,24 @@
+const   before   =   1;
+
+// prettier-ignore-start
+const LOOKUP = {
+  a:   [ 1,  2,    3 ],
+  bb:  [ 10, 20,   30 ],
+};
+
+const   x   =   1; // trailing
+const   yy  =   22;
+// prettier-ignore-end
+
+const   after   =   2;
+
+function f() {
+  // oxc-format-ignore-start
+  const   a   =   1;
+  // prettier-ignore-start
+  const   b   =   2;
+  // prettier-ignore-end
+  const   c   =   3;
+  // oxc-format-ignore-end
+  const   d   =   4;
+}
@@ -0,0 +1,10 @@
+// oxc-format-ignore
+const   table = [
+  [1,    2],
+  [10,  20],
+];
+
+/* oxc-format-ignore */
+if   (a)   {   b()   }
+
+const   formatted   =   1;
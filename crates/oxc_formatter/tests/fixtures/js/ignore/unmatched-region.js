@@ -0,0 +1,7 @@
+const   formatted   =   1;
+
+// prettier-ignore-start
+const   a   =   [1,2,
+                 3,4];
+
+function   f( ) {   return   1   }
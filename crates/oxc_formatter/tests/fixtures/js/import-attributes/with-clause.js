@@ -0,0 +1,14 @@
+// Fits on one line
+import data from "./data.json" with { type: "json" };
+export { data } from "./data.json" with { type: "json" };
+
+// The clause moves to its own line
+import { aVeryLongSpecifierName } from "./a-very-long-module-specifier-for-the-json-data" with { type: "json" };
+export { aVeryLongSpecifierName } from "./a-very-long-module-specifier-for-the-json-data" with { type: "json" };
+import {
+  aVeryLongSpecifierName,
+  anotherLongSpecifierName,
+} from "./a-very-long-module-specifier-path" with { type: "json", integrity: "sha384-abcdefghijklmnopqrstuvwxyz" };
+
+// The entries break when the clause doesn't fit on its own line either
+import styles from "./styles.css" with { type: "css", integrity: "sha384-abcdefghijklmnopqrstuvwxyz0123456789ABCDEFGHIJ" };
@@ -0,0 +1,15 @@
+const short = <div a="1" b={2}>text</div>;
+
+const single = <div className="a-single-attribute-that-is-long-enough-to-break-the-line">text</div>;
+
+const long = <div className="container" id="main-container" onClick={handleClick} data-value={value}>text</div>;
+
+const selfClosing = <Input type="text" value={value} onChange={handleChange} placeholder="Type here" />;
+
+const selfClosingShort = <Input a b />;
+
+const nested = (
+  <Outer first="first" second="second">
+    <Inner value={someValueThatIsLong} other={anotherValueThatIsLong} third={third} />
+  </Outer>
+);
@@ -0,0 +1,8 @@
+const a = <Foo prop={condition ? <SomeLongComponentName value={1} /> : <OtherLongComponentName value={2} />} />;
+const b = <List items={items.map((item) => <Item key={item.id} value={item.value} label={item.label} />)} />;
+const c = <Foo style={{ color: "red", backgroundColor: "blue", fontSize: 12, padding: "10px 20px" }} />;
+const d = <Foo onClick={() => { handleTheClickEventWithAVeryLongFunctionName(event, someOtherArgument); }} />;
+const e = <Foo value={someFunctionCall(argumentNumberOne, argumentNumberTwo, argumentNumberThree)} />;
+const f = <Foo visible={isSomethingVisible && isSomethingElseAlsoVisible && isTheThirdConditionMet} />;
+const g = <Foo a="1" b="2" render={(props) => <Bar {...props} someLongPropName="someLongPropValue" />} c={3} />;
+const h = <Foo a={cond ? 1 : 2} />;
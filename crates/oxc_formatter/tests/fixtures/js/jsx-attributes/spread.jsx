@@ -0,0 +1,7 @@
+const spreadOnly = <Component {...props}>text</Component>;
+
+const spreads = <Component {...props} {...rest}>text</Component>;
+
+const mixed = <Component {...defaultProps} className="component" {...overrides} onClick={handleClick}>text</Component>;
+
+const selfClosing = <Component {...props} key={item.id} {...item} />;
@@ -0,0 +1,9 @@
+const plain = <div className="plain" title='single'>{"text"}</div>;
+
+const apostrophe = <div title="It's fine" alt='Don&apos;t'>{"It's"}</div>;
+
+const doubleQuotes = <div title='Say "hi"' alt="&quot;quoted&quot;">{'Say "hi"'}</div>;
+
+const both = <div title='It&apos;s "both"' alt="It's &quot;both&quot;" />;
+
+const expression = <div title={"in an expression"} onClick={() => alert('clicked')} />;
@@ -0,0 +1,10 @@
+function A() { return <div className="some-class-name" id="some-identifier"><span>Hello world</span></div>; }
+function B() { return a, b; }
+function C() { throw <div className="some-class-name" id="some-identifier"><span>Hello world</span></div>; }
+function D() { return someVeryLongFunctionName(argumentOne), someOtherVeryLongFunctionName(argumentTwo); }
+const E = () => <div className="some-class-name" id="some-identifier"><span>Hello world</span></div>;
+function F() { throw a, b; }
+function G() { return (
+  // comment
+  a
+); }
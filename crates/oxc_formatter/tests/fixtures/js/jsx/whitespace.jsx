@@ -0,0 +1,20 @@
+const a = <div>Some text that is long enough to need wrapping across multiple lines <b>bold</b> and <i>italic</i> text here.</div>;
+const b = <div>  leading and trailing spaces  </div>;
+const c = <span>first <a href="https://example.com/a/very/long/url/that/goes/on">link text</a> second</span>;
+const d = <div>{" "}<b>a</b>{" "}</div>;
+const e = <div>
+  {/* a comment child */}
+  <span>x</span>
+</div>;
+const f = <div>text{" "}{value}{" "}more text that should wrap because it is long enough to exceed width</div>;
+const g = <div>
+  before
+
+  after
+</div>;
+const h = <p>
+  a{" "}
+  {b}
+</p>;
+const i = <div>{" "}</div>;
+const j = <div>{"  "}</div>;
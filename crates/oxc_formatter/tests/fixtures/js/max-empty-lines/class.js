@@ -0,0 +1,19 @@
+class A {
+
+
+  a = 1;
+
+
+  b = 2;
+
+
+
+  // comment
+
+
+
+  method() {}
+  other() {}
+
+
+}
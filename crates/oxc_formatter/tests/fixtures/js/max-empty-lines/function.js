@@ -0,0 +1,23 @@
+function foo() {
+
+
+
+  const a = 1;
+
+
+  const b = 2;
+
+
+
+  if (a) {
+
+
+    return b;
+
+
+  }
+  return a;
+
+
+
+}
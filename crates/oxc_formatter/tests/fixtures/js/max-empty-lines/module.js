@@ -0,0 +1,24 @@
+#!/usr/bin/env node
+
+
+"use strict";
+
+
+
+import a from "a";
+import b from "b";
+
+
+const x = 1;
+
+
+
+// A comment followed by empty lines
+
+
+function foo() {}
+/* block */
+
+
+
+const y = 2;
@@ -0,0 +1,13 @@
+class A {
+  static {
+
+
+    a();
+
+
+
+    b();
+
+
+  }
+}
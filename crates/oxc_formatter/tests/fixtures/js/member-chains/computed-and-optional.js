@@ -0,0 +1,11 @@
+const value = object.items[index].children.filter((child) => child.visible).map((child) => child.id);
+
+const maybe = response?.data?.items?.filter((item) => item.enabled)?.map((item) => item.name);
+
+wrapper.find('SomeSelector').prop('children')(defaultValue).props.onChange(value => value);
+
+const html = this.template.render`<div>${content}</div>`.trim().split("\n").map((line) => line.trim());
+
+short.a(x => x).b();
+
+items[0].a().b().c();
@@ -0,0 +1,9 @@
+promise.then((result) => result.value).catch(handleError);
+
+const names = users.filter((user) => user.active).map((user) => user.name);
+
+object.foo.bar.baz.qux();
+
+z.object({ name: z.string() }).strict().optional();
+
+fetchData().then((response) => response.json()).then((data) => console.log(data)).finally(done);
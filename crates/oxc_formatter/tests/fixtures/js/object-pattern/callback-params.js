@@ -0,0 +1,8 @@
+useEffect(({ a, b, c }) => { doSomething(a); doSomethingElse(b); });
+useEffect(function ({ a, b, c }) { doSomething(a); doSomethingElse(b); });
+useEffect(async ({ a, b, c, d }) => { await doSomething(a, b, c, d); });
+foo(bar, ({ a: { b }, c }) => { return b + c; });
+foo(bar, function ({ a: { b }, c }) { return b + c; });
+
+useEffect(({ aaaaaaaaaaaa, bbbbbbbbbbbbbbb, cccccccccccccccc, dddddddddddd, eeeeeeeeeeeee }) => { doSomething(a); });
+useEffect(function ({ aaaaaaaaaaaa, bbbbbbbbbbbbbbb, cccccccccccccccc, dddddddddddd, eeeeeee }) { doSomething(a); });
@@ -0,0 +1,11 @@
+try {} catch ({ message, stack }) {}
+try {} catch ({ message, stack, code, errno, syscall, path, address, port, info }) {}
+try {} catch ({ message, stack, code, errno, syscall, path, address, port, info, x }) {}
+
+// Defaults
+try {} catch ({ code = 0 }) {}
+try {} catch ({ code = 0, message = "unknown error message", stack = "no stack trace at all" }) {}
+
+// Nested patterns
+try {} catch ({ response: { status, data }, message }) {}
+try {} catch ({ response: { status, data, headers, config, request }, message, stack, name }) {}
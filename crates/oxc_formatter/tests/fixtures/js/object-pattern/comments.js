@@ -0,0 +1,48 @@
+// Leading
+const {
+  // user fields
+  name,
+  age
+} = obj;
+const {
+  /* user fields */
+  name,
+  age
+} = obj;
+
+// Between properties
+const {
+  name, // the name
+  age
+} = obj;
+const {
+  name,
+  /* the age */
+  age
+} = obj;
+
+// Trailing
+const {
+  name,
+  age
+  // trailing
+} = obj;
+const { name, age /* trailing */ } = obj;
+const { /* inline */ name, age } = obj;
+
+// Dangling
+const {
+  // empty
+} = obj;
+
+// Assignment targets and parameters
+({
+  /* comment */
+  a,
+  b
+} = obj);
+function f({
+  /* comment */
+  a,
+  b
+}) {}
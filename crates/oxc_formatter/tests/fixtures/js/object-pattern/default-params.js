@@ -0,0 +1,18 @@
+function f({a, b, c} = {}) {}
+function g({ aaaaaaaaaaaaaa, bbbbbbbbbbbbbbbbb, cccccccccccccccccc, dddddddddddddddddd } = {}) {}
+function h(x, {a, b, c} = {}) {}
+function i(xxxxxxxxxxxxxxxxxxxxxx, { aaaaaaaaaaaaaa, bbbbbbbbbbbbbbbbb, cccccccccccccccccc } = {}) {}
+
+const j = ({a, b, c} = {}) => {};
+const k = ({ aaaaaaaaaaaaaa, bbbbbbbbbbbbbbbbb, cccccccccccccccccc, dddddddddddddddddd } = {}) => {};
+const l = async ({a, b, c} = defaults) => a + b + c;
+
+class A {
+  m({a, b, c} = {}) {}
+  n({ aaaaaaaaaaaaaa, bbbbbbbbbbbbbbbbb, cccccccccccccccccc, dddddddddddddddddd } = {}) {}
+}
+
+const o = {
+  m({a, b, c} = {}) {},
+  n({ aaaaaaaaaaaaaa, bbbbbbbbbbbbbbbbb, cccccccccccccccccc, dddddddddddddddd } = {}) {},
+};
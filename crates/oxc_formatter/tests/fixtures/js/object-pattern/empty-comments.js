@@ -0,0 +1,36 @@
+// Block comments
+function a({} /* why */) {}
+function b({ /* nothing to pick */ }) {}
+const { /* nothing */ } = x;
+const c = { /* nothing */ };
+
+// Line comments
+function d({
+  // nothing to pick
+}) {}
+function e({ // nothing to pick
+}) {}
+const {
+  // nothing
+} = x;
+({
+  // nothing
+} = x);
+const f = {
+  // nothing
+};
+call({
+  // nothing
+});
+
+// Multiple comments
+function g({ /* a */ /* b */ }) {}
+function h({
+  // one
+  /* two */
+  // three
+}) {}
+const i = {
+  // one
+  // two
+};
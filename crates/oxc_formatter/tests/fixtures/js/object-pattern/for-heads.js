@@ -0,0 +1,12 @@
+for (const { a: { b } } of xs) {}
+for (const { a: [b, c] } of xs) {}
+for (let { a: { b }, ...rest } in xs) {}
+for await (const { a: { b }, c } of xs) {}
+for ({ a: { b } } of xs) {}
+
+for (const { aaaaaaaaaaaaaaaaa: { bbbbbbbbbbbbbbbbbbbbbb }, cccccccccccccccccccccc } of xxxxxxxxxxxxxxxxxx) {}
+for ({ aaaaaaaaaaaaaaaaa: { bbbbbbbbbbbbbbbbbbbbbb }, ...cccccccccccccccccccccc } in xxxxxxxxxxxxxxxxxx) {}
+
+// Patterns outside of `for...in` and `for...of` heads still break on nested patterns
+for (x of xs) var { a: { b } } = c;
+for (const { a: { b } } = c; ; ) {}
@@ -0,0 +1,35 @@
+// Before the `...`
+const { a, /* rest of it */ ...rest } = x;
+const { a /* after a */, ...rest } = x;
+({ a, /* rest of it */ ...rest } = x);
+function f({ a, /* rest of it */ ...rest }) {}
+
+// After the rest identifier
+const { a, ...rest /* after */ } = x;
+({ a, ...rest /* after */ } = x);
+
+// In patterns that break
+const {
+  aaaaaaaaaaaaaaaaaaaaaa,
+  bbbbbbbbbbbbbbbbbbbbbbbbbbbb,
+  /* before rest */ ...rest // trailing rest
+} = x;
+const {
+  aaaaaaaaaaaaaaaaaaaaaa,
+  bbbbbbbbbbbbbbbbbbbbbbbbbbbb,
+  // before rest
+  ...rest
+  // after rest
+} = x;
+({
+  aaaaaaaaaaaaaaaaaaaaaa,
+  bbbbbbbbbbbbbbbbbbbbbbbbbbbb,
+  /* before rest */ ...rest // trailing rest
+} = x);
+({
+  aaaaaaaaaaaaaaaaaaaaaa,
+  bbbbbbbbbbbbbbbbbbbbbbbbbbbb,
+  // before rest
+  ...rest
+  // after rest
+} = x);
@@ -0,0 +1,15 @@
+const single = {
+  a: 1 };
+
+const inline = { a: 1, b: 2 };
+
+const nested = {
+  inner: { a: 1, b: 2 },
+  other: { c: 3 } };
+
+const newlineAfterFirstProperty = { a: 1,
+  b: 2 };
+
+// Patterns follow Prettier and ignore the original line breaks
+const {
+  a, b } = obj;
@@ -0,0 +1,9 @@
+const withTrailing = firstConditionIsSatisfied && // first
+  secondConditionIsSatisfied && // second
+  thirdConditionIsSatisfied;
+
+const withOwnLine = firstConditionIsSatisfied &&
+  // explains the second condition
+  secondConditionIsSatisfied;
+
+const withBlock = firstConditionIsSatisfied && /* inline */ secondConditionIsSatisfied && third;
@@ -0,0 +1,21 @@
+const isValid = firstConditionIsSatisfied && secondConditionIsSatisfied && thirdConditionIsSatisfied;
+
+const fallback = someValueThatMightBeMissing || anotherValueThatMightBeMissing || "default value";
+
+if (someCondition && anotherCondition || yetAnotherCondition && theLastConditionInTheChain) {
+  run();
+}
+
+while (iteratorHasMoreElements && elementIsNotTheOneWeAreLookingFor && counter < limit) {}
+
+function check() {
+  return firstConditionIsSatisfied && secondConditionIsSatisfied && thirdConditionIsSatisfied;
+}
+
+const inline = someConditionThatIsLongEnoughToBreakTheLine && { key: "value", other: "value" };
+
+const result = (firstOperandValue + secondOperandValue) * (thirdOperandValue - fourthOperandValue) / divisor;
+
+const mixed = firstNumber * secondNumber + thirdNumber * fourthNumber - fifthNumber * sixthNumber;
+
+const nullish = (someValueThatMightBeMissing ?? anotherValueThatMightBeMissing) || defaultValue;
@@ -0,0 +1,6 @@
+const s = "aaaaaaaaaaaaaaa" + variable + "bbbbbbbbbbbbbbbbbbbbbbbbb" + anotherVariable + "ccccccccccccccc";
+const t = `template ${x}` + variable + "bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb" + `another ${y} template` + "c";
+const n = 1 + variable + "bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb" + 2;
+const c = "aaaaaaaaaaaaaaa" + /* first */ variable + // second
+  "bbbbbbbbbbbbbbbbbbbbbbbbb" + anotherVariable + "ccccccccccccccc";
+throw new Error("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa" + variable + "bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb");
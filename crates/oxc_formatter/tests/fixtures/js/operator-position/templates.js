@@ -0,0 +1,3 @@
+const message = `The first part of the message ${first}` + `the second part of the message ${second}` + suffix;
+
+const url = baseUrlOfTheService + `/api/v1/resources/${resourceIdentifier}/children` + queryString;
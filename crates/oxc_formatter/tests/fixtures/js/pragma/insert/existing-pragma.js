@@ -0,0 +1,7 @@
+/**
+ * Utilities.
+ *
+ * @format
+ */
+
+const  a = 1
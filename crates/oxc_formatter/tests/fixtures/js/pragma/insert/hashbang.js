@@ -0,0 +1,3 @@
+#!/usr/bin/env node
+"use strict"
+const  a = 1
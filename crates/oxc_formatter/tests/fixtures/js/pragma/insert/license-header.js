@@ -0,0 +1,7 @@
+/*
+ * Copyright (c) Example, Inc.
+ * Licensed under the MIT license.
+ */
+
+import {b} from "b"
+export default b
@@ -0,0 +1,3 @@
+#!/usr/bin/env node
+/** @format */
+foo( a )
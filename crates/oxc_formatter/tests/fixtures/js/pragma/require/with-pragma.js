@@ -0,0 +1,4 @@
+/**
+ * @prettier
+ */
+const  a = {b:1}
@@ -0,0 +1,6 @@
+/**
+ * Utilities, see @format in the docs.
+ */
+const  a = {b:1}
+/** @format */
+foo( a )
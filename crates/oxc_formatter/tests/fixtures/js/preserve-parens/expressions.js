@@ -0,0 +1,29 @@
+if ((isEnabled && hasPermission) || isAdmin) {
+  run();
+}
+
+if ((someVeryLongConditionName && anotherVeryLongConditionName && yetAnotherCondition) || fallback) {
+  run();
+}
+
+const canEdit = (user.isOwner && document.isDraft) || (user.isAdmin && !document.isLocked) || (user.role === "editor");
+
+const total = (price * quantity) + (shipping - discount);
+const mixed = (a ?? b) || c;
+const nested = ((value));
+const sequence = (first(), second());
+const object = ({ a: 1 });
+const arrow = () => ({ a: 1 });
+const negated = !(isReady);
+
+const result = (someObject.someVeryLongMethodName(firstArgument) || someOtherObject.anotherMethod(secondArgument));
+
+(function () {})();
+(async () => {
+  await (promise);
+})();
+
+const value = (condition) ? (consequent) : (alternate);
+const typed = /** @type {Foo} */ (bar);
+const kept = (/* comment */ a + b) * c;
+const label = (isActive ? "some long label for the active state" : "some long label for the inactive state").trim();
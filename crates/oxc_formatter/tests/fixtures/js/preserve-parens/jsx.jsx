@@ -0,0 +1,21 @@
+const single = (<div />);
+
+const multi = (
+  <div className="some-class-name" id="some-identifier">
+    <span>Hello world</span>
+  </div>
+);
+
+const Component = () => (
+  <div className="some-class-name" id="some-identifier">
+    <span>Hello world</span>
+  </div>
+);
+
+const conditional = isLoading ? (<Spinner size="large" color="blue" />) : (<Content data={data} loading={false} />);
+
+const logical = (isVisible && isEnabled) && (
+  <div className="some-class-name" id="some-identifier">
+    <span>Hello world</span>
+  </div>
+);
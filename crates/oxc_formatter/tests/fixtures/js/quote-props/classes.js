@@ -39,3 +39,19 @@ class G {
   accessor prop1 = 1;
   accessor 'prop-2' = 2;
 }
+
+// Private members are never quoted and don't affect the other keys
+class H {
+  'h-1' = 1;
+  #h2 = 2;
+}
+
+class I {
+  #i1 = 1;
+  #i2() {}
+  get #i3() { return 3; }
+  static #i4 = 4;
+  accessor #i5 = 5;
+  i6 = 6;
+  'i-7'() {}
+}
@@ -0,0 +1,20 @@
+// Comments attached to keys must survive when the keys get quoted
+a = {
+  // leading line comment
+  foo: 1,
+  bar /* trailing block comment */: 2,
+  /* leading block comment */ baz: 3,
+  qux: 4, // trailing line comment
+  /* numeric leading */ 1: 5,
+  2 /* numeric trailing */: 6,
+  "a-b": 7,
+};
+
+class A {
+  // leading line comment
+  foo = 1;
+  bar /* trailing block comment */ = 2;
+  "qux" /* string key trailing block comment */ = 4;
+  /* leading block comment */ baz() {}
+  "a-b" = 3;
+}
@@ -0,0 +1,31 @@
+// Keys with escapes that cook to identifiers
+a = {
+  "foo": 1,
+  "\u{62}ar": 2,
+  "\x62az": 3,
+};
+
+// Keys with escapes that still require quotes
+a = {
+  "a\"b": 1,
+  'c\'d': 2,
+  "a-b": 3,
+};
+
+// Escaped keys that cook to identifiers don't trigger consistent quoting
+a = {
+  "\u0066oo": 1,
+  bar: 2,
+};
+
+class A {
+  "foo" = 1;
+  "\x62ar"() {}
+}
+
+// Escaped identifier keys are printed with their cooked name, also when they get quoted
+a = {
+  a: 1,
+  \u{62}c: 2,
+  "d-e": 3,
+};
@@ -0,0 +1,14 @@
+// Keys containing quotes and backslashes
+a = {
+  "a\"b": 1,
+  'c\'d': 2,
+  "e'f": 3,
+  'g"h': 4,
+  "i\\j": 5,
+  k: 6,
+};
+
+class A {
+  'a"b' = 1;
+  c = 2;
+}
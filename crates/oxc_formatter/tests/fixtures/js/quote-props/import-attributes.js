@@ -0,0 +1,9 @@
+import json from "./data.json" with {type:"json"};
+import quoted from "./data.json" with { "type": "json" };
+import mixed from "./data.json" with { type: "json", "other-key": "x" };
+import legacy from "./data.json" assert { "type": "json" };
+export { default } from "./data.json" with { type: "json" };
+export * from "./data.json" with { type: "json", "content-type": "application/json" };
+const module = await import("./data.json", { with: { "type": "json" } });
+import { aaaaaaaaaaaaaa, bbbbbbbbbbbbbbbbbbbbb, ccccccccccccccccc } from "./some-data.json" with { type: "json" };
+import data from "./some/very/long/path/to/some/data/file/that/is/long.json" with { type: "json" };
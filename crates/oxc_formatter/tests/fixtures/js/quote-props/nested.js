@@ -0,0 +1,32 @@
+// Inner object inside an object that needs quotes - inner keys stay unquoted
+a = {
+  "a-b": {
+    c: 1,
+    d: [{ e: 1 }],
+  },
+  f: 1,
+};
+
+// Outer object without quotes around an inner object that needs quotes
+a = {
+  b: 1,
+  c: {
+    "d-e": 1,
+    f: 2,
+  },
+};
+
+// Patterns nested in object-like nodes that need quotes don't inherit the state
+a = {
+  "a-b": 1,
+  c() {
+    const { "d": e, f } = g;
+  },
+};
+
+class A {
+  "a-b" = 1;
+  c({ "d": e }) {
+    return { f: e };
+  }
+}
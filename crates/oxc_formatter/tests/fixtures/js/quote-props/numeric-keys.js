@@ -0,0 +1,57 @@
+// Numeric keys never require quotes
+a = {
+  1: "a",
+  1.5: "b",
+};
+
+// Numeric keys are quoted when a string key requires quotes
+a = {
+  1: "a",
+  2: "b",
+  "a-b": 1,
+  c: 2,
+};
+
+// Numeric keys that can't be quoted safely keep the whole object unquoted
+a = {
+  1e2: "a",
+  "a-b": 1,
+  c: 2,
+};
+
+a = {
+  0x1: "a",
+  "a-b": 1,
+  c: 2,
+};
+
+a = {
+  1.50: "a",
+  "a-b": 1,
+  c: 2,
+};
+
+a = {
+  999999999999999999: "a",
+  "a-b": 1,
+  c: 2,
+};
+
+// Numeric values are untouched
+a = {
+  "a-b": 1,
+  c: 2,
+};
+
+// BigInt keys are never quoted and keep the whole object unquoted
+a = {
+  1n: "a",
+  "a-b": 1,
+  c: 2,
+};
+
+a = {
+  "a-b": 1,
+  0x10n: "a",
+  2: "b",
+};
@@ -0,0 +1,17 @@
+// Plain safe integers can be unquoted
+a = {
+  "0": 1,
+  "1": 2,
+  "9007199254740991": 3,
+};
+
+// Everything else stays quoted
+a = {
+  "999999999999999999": 1,
+  "9007199254740992": 2,
+  "1e21": 3,
+  "0.5": 4,
+  "-1": 5,
+  "00": 6,
+  "01": 7,
+};
@@ -0,0 +1,22 @@
+// `"__proto__"` keeps its quotes in object literals
+a = {
+  "__proto__": null,
+};
+
+a = {
+  __proto__: null,
+};
+
+a = {
+  "__proto__": null,
+  b: 1,
+};
+
+a = {
+  '__proto__': null,
+};
+
+// No special case for class bodies
+class A {
+  "__proto__" = 1;
+}
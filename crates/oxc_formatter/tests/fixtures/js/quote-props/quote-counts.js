@@ -0,0 +1,9 @@
+'use "strict"';
+a = 'say "hi"';
+b = "it's";
+c = 'it\'s';
+d = 'both " and \'';
+e = 'both "" and \'';
+f = "both ' and \"";
+g = '\d\e';
+o = { 'say "hi"': 1, 'it\'s': 2 };
@@ -0,0 +1,25 @@
+// Shorthand properties are expanded when another key needs quotes
+a = {
+  "a-b": 1,
+  x,
+  /* comment */ y,
+};
+
+// Shorthand properties are kept when no key needs quotes
+a = {
+  b: 1,
+  x,
+};
+
+// Shorthand methods only get their names quoted
+a = {
+  "a-b": 1,
+  method() {},
+  async *generator() {},
+  get getter() {
+    return 1;
+  },
+};
+
+// Shorthand with default values in assignment targets are untouched
+({ "a-b": a, x = 1 } = y);
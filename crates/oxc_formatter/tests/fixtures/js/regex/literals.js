@@ -0,0 +1,5 @@
+const result = someFunction(argumentOne, /^(?:[a-z0-9!#$%&'*+/=?^_`{|}~-]+(?:\.[a-z0-9!#$%&'*+/=?^_`{|}~-]+)*)@(?:example|test)\.com$/giu, argumentTwo);
+const patterns = { email: /^(?:[a-z0-9!#$%&'*+/=?^_`{|}~-]+(?:\.[a-z0-9!#$%&'*+/=?^_`{|}~-]+)*)@(?:example|test)\.com$/giu, short: /a/ };
+const flags = /abc/yigsmu;
+const v = /[\p{L}--\p{Ll}]/v;
+str.replace(/\s+/g, " ").replace(/^(?:[a-z0-9!#$%&'*+/=?^_`{|}~-]+(?:\.[a-z0-9!#$%&'*+/=?^_`{|}~-]+)*)$/gu, "");
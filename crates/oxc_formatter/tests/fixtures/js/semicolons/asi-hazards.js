@@ -0,0 +1,11 @@
+const a = b
+;(function () {})()
+;[1, 2].forEach(fn)
+;`template`.length
+;+value
+;-value
+;/regex/.test(str)
+let x = y
+;(z)
+const c = d;
+(e || f).call();
@@ -0,0 +1,31 @@
+class A {
+  a = 0;
+  [b](){}
+
+  c = 0;
+  *d(){}
+
+  e = 0;
+  [f] = 0
+
+  // none of the semicolons above this comment can be omitted.
+  // none of the semicolons below this comment are necessary.
+
+  q() {};
+  [h](){}
+
+  p() {};
+  *i(){}
+
+  a = 1;
+  get ['y']() {}
+
+  a = 1;
+  static ['y']() {}
+
+  s = function () {};
+  *g() {}
+
+  static;
+  x
+}
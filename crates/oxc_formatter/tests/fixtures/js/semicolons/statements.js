@@ -0,0 +1,15 @@
+for (;;) {}
+while (a);
+if (a);
+do {
+  a++
+} while (a < 10)
+do x++; while (x < 5)
+label: for (;;) break label
+;
+var a = 1, b = 2
+export default function () {}
+export const e = 1
+import f from "f"
+throw new Error("x")
+debugger
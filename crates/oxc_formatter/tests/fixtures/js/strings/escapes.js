@@ -0,0 +1,6 @@
+a = '\u2028 \u2029';
+b = '\uD800';
+c = '\0';
+d = '\012 \7';
+e = '\x41 \u{1F600}';
+f = 'raw   sep';
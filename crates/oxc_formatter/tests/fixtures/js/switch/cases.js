@@ -0,0 +1,40 @@
+switch (value) {
+  case 1:
+  case 2:
+    doSomething();
+    // fallthrough
+
+  case 3: {
+    const x = 1;
+    break;
+  }
+
+  default:
+    other();
+    break;
+  case 4:
+    // only a comment
+  case 5:
+    last();
+    // trailing comment on the last case
+}
+switch (x) {
+  // dangling
+}
+switch (x) {}
+switch (x) { /* block */ }
+switch (/* d */ x) {}
+switch (value) {
+  case 1:
+    a();
+    // fallthrough
+  // leading comment of case 2
+  case 2:
+    b();
+
+    // after a blank line
+  default:
+    c();
+    // trailing comment of the consequent
+  // trailing comment of the last case
+}
@@ -0,0 +1,14 @@
+function f() {
+  const message = `Hello ${someVeryLongFunctionName(argumentNumberOne, argumentNumberTwo, three)} world`;
+  const nested = `outer ${`inner ${someVeryLongFunctionName(argumentNumberOne, argumentNumberTwo)} text`} end`;
+  const ternary = `value: ${condition ? someVeryLongConsequentExpression : someVeryLongAlternateExpression}`;
+  const chain = `items: ${items.filter((item) => item.enabled).map((item) => item.name).join(", ")}`;
+  const obj = `x ${{ a: 1, b: 2, ccccccccccccccccccccccc: 3, dddddddddddddddddddddddd: 4, e: 5 }}`;
+}
+const multiline = `
+  <div>
+    ${condition
+      ? someVeryLongConsequentExpressionThatIsLong
+      : someVeryLongAlternateExpressionThatIsLong}
+  </div>
+`;
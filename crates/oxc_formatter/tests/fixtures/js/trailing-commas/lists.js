@@ -0,0 +1,16 @@
+const object = { firstProperty: firstValue, secondProperty: secondValue, third: thirdValue };
+const array = [firstElementOfTheArray, secondElementOfTheArray, thirdElementOfTheArray];
+
+const { firstProperty, secondProperty, thirdProperty, fourthProperty, ...restOfTheProperties } = object;
+const { firstProperty: { nestedProperty }, secondProperty = defaultValue, ...restProperties } = object;
+const [firstElementOfTheArr, secondElementOfTheArr, thirdElementOfTheArr, ...rest] = array;
+({ firstProperty, secondProperty, thirdPropertyAssigned, fourthPropertyAssigned } = object);
+[firstElementAssigned, secondElementAssigned, thirdElementAssigned, fourthElement] = array;
+
+function parameters(firstParameterName, secondParameterName, thirdParameterName) {}
+function restParameters(firstParameterName, secondParameterName, ...restParameterName) {}
+const arrow = (firstParameterName, secondParameterName, thirdParameterName, fourth) => {};
+
+callSomething(firstArgumentToTheCall, secondArgumentToTheCall, thirdArgumentToTheCall);
+new Something(firstArgumentToTheCall, secondArgumentToTheCall, thirdArgumentToTheCall);
+callSomething(...firstSpreadArgument, ...secondSpreadArgumentToTheCall, ...thirdSpread);
@@ -0,0 +1,5 @@
+import { firstImportedName, secondImportedName, thirdImportedName } from "module-name";
+import defaultName, { firstImportedBinding, secondImportedBinding } from "another-module";
+
+export { firstExportedName, secondExportedName, thirdExportedName } from "module-name";
+export { firstLocalExportedName, secondLocalExportedName, thirdLocalExported };
@@ -2,9 +2,10 @@ use std::{env::current_dir, fs, path::Path};
 
 use oxc_allocator::Allocator;
 use oxc_formatter::{
-    ArrowParentheses, BracketSameLine, BracketSpacing, FormatOptions, Formatter, IndentStyle,
-    IndentWidth, LineEnding, LineWidth, QuoteProperties, QuoteStyle, Semicolons, TrailingCommas,
-    get_parse_options,
+    ArrowParentheses, AttributePosition, BracketSameLine, BracketSpacing,
+    EmbeddedLanguageFormatting, Expand, FormatOptions, Formatter, IndentStyle, IndentWidth,
+    LineEnding, LineWidth, OperatorPosition, QuoteProperties, QuoteStyle, Semicolons,
+    TrailingCommas, get_parse_options_with,
 };
 use oxc_parser::Parser;
 use oxc_span::SourceType;
@@ -12,7 +13,7 @@ use oxc_span::SourceType;
 type OptionSet = serde_json::Map<String, serde_json::Value>;
 
 /// Resolve format options for a test file by walking up the directory tree
-fn resolve_options(test_file: &Path) -> Vec<OptionSet> {
+pub fn resolve_options(test_file: &Path) -> Vec<OptionSet> {
     let mut current_dir = test_file.parent();
 
     // Walk up the directory tree looking for options.json
@@ -40,7 +41,7 @@ fn resolve_options(test_file: &Path) -> Vec<OptionSet> {
 }
 
 /// Parse JSON options into FormatOptions
-fn parse_format_options(json: &OptionSet) -> FormatOptions {
+pub fn parse_format_options(json: &OptionSet) -> FormatOptions {
     let mut options = FormatOptions::default();
 
     for (key, value) in json {
@@ -109,12 +110,19 @@ fn parse_format_options(json: &OptionSet) -> FormatOptions {
                     options.bracket_same_line = BracketSameLine::from(b);
                 }
             }
+            "singleAttributePerLine" => {
+                if let Some(b) = value.as_bool() {
+                    options.attribute_position =
+                        if b { AttributePosition::Multiline } else { AttributePosition::Auto };
+                }
+            }
             "endOfLine" => {
                 if let Some(s) = value.as_str() {
                     options.line_ending = match s {
                         "lf" => LineEnding::Lf,
                         "crlf" => LineEnding::Crlf,
                         "cr" => LineEnding::Cr,
+                        "auto" => LineEnding::Auto,
                         _ => LineEnding::default(),
                     };
                 }
@@ -129,6 +137,71 @@ fn parse_format_options(json: &OptionSet) -> FormatOptions {
                     };
                 }
             }
+            "experimentalOperatorPosition" => {
+                if let Some(s) = value.as_str() {
+                    options.experimental_operator_position = match s {
+                        "start" => OperatorPosition::Start,
+                        _ => OperatorPosition::End,
+                    };
+                }
+            }
+            "objectWrap" => {
+                if let Some(s) = value.as_str() {
+                    options.expand = match s {
+                        "preserve" => Expand::Auto,
+                        "collapse" => Expand::Never,
+                        _ => Expand::default(),
+                    };
+                }
+            }
+            "maxEmptyLines" => {
+                if let Some(n) = value.as_u64() {
+                    options.max_empty_lines = u8::try_from(n).unwrap();
+                }
+            }
+            "decoratorsSameLine" => {
+                if let Some(b) = value.as_bool() {
+                    options.decorators_same_line = b;
+                }
+            }
+            "preserveParens" => {
+                if let Some(b) = value.as_bool() {
+                    options.preserve_parens = b;
+                }
+            }
+            "formatDocComments" => {
+                if let Some(b) = value.as_bool() {
+                    options.format_doc_comments = b;
+                }
+            }
+            "memberChainMinCalls" => {
+                if let Some(n) = value.as_u64() {
+                    options.member_chain_min_calls = u8::try_from(n).unwrap();
+                }
+            }
+            "requirePragma" => {
+                if let Some(b) = value.as_bool() {
+                    options.require_pragma = b;
+                }
+            }
+            "insertPragma" => {
+                if let Some(b) = value.as_bool() {
+                    options.insert_pragma = b;
+                }
+            }
+            "experimentalTernaries" => {
+                if let Some(b) = value.as_bool() {
+                    options.experimental_ternaries = b;
+                }
+            }
+            "embeddedLanguageFormatting" => {
+                if let Some(s) = value.as_str() {
+                    options.embedded_language_formatting = match s {
+                        "auto" => EmbeddedLanguageFormatting::Auto,
+                        _ => EmbeddedLanguageFormatting::Off,
+                    };
+                }
+            }
             _ => {}
         }
     }
@@ -137,7 +210,7 @@ fn parse_format_options(json: &OptionSet) -> FormatOptions {
 }
 
 /// Format options to a readable string for snapshot display
-fn format_options_display(json: &OptionSet) -> String {
+pub fn format_options_display(json: &OptionSet) -> String {
     if json.is_empty() {
         return "{}".to_string();
     }
@@ -150,8 +223,9 @@ fn format_options_display(json: &OptionSet) -> String {
 /// Format a source file with given options
 fn format_source(source_text: &str, source_type: SourceType, options: FormatOptions) -> String {
     let allocator = Allocator::default();
-    let ret =
-        Parser::new(&allocator, source_text, source_type).with_options(get_parse_options()).parse();
+    let ret = Parser::new(&allocator, source_text, source_type)
+        .with_options(get_parse_options_with(&options))
+        .parse();
     assert!(ret.errors.is_empty());
 
     let formatter = Formatter::new(&allocator, options);
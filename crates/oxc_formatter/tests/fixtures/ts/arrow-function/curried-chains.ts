@@ -0,0 +1,6 @@
+const logger = store => next => action => { console.log(action); return next(action); };
+const thunk = ({ dispatch, getState }) => (next) => async (action) => { if (typeof action === "function") { return action(dispatch, getState); } return next(action); };
+const mw = <S,>(api: MiddlewareAPI<S>) => (next: Dispatch) => (action: AnyAction): AnyAction => next(action);
+const selector = (state) => (id) => ({ id, value: state.values[id], label: state.labels[id], extra: 1 });
+const curried = a => b => c => d => e => f => g => h => i => j => k => l => m => n => o => p => q => r;
+const withAsync = (config) => async (request) => (response) => handleTheResponseWithSomething(config, request, response);
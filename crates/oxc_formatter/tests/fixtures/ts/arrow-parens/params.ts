@@ -0,0 +1,5 @@
+const typed = (x: number) => x;
+const returnType = (x): number => x;
+const generic = <T,>(x) => x;
+const optional = (x?) => x;
+const simple = (x) => x;
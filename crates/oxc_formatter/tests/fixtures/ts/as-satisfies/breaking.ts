@@ -0,0 +1,8 @@
+const config = { name: "app", version: 1, features: ["a", "b", "c"] } satisfies SomeLongGenericConfigType<WithArgument>;
+const routes = ["home", "about", "contact", "blog", "careers", "press", "legal"] as const;
+const value = someFunctionCallWithLongName(argumentNumberOne) as unknown as SomeOtherType<Generic>;
+const short = x as unknown as Foo;
+({ a: 1 }) satisfies A;
+({ a: 1 } as A).b;
+const obj = someValueExpressionThatIsLong satisfies { propertyOne: string; propertyTwo: number; three: boolean };
+export default { name: "component", data() { return {}; } } satisfies ComponentOptions;
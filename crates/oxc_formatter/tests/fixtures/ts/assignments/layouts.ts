@@ -0,0 +1,15 @@
+const someLongVariableName = someFunctionCallWithALongName(argumentNumberOne, argumentTwo);
+someObject.someProperty.nested = condition ? someValueWhenTrueThatIsLong : someOtherValueWhenFalse;
+const handler = async (eventWithLongName, contextWithLongName, callbackWithLongName) => { run(); };
+const Klass = class extends SomeVeryLongBaseClassNameThatGoesOnAndOnAndOn<WithGenerics> {};
+const fn = function namedFunctionExpressionWithLongName(parameterOne, parameterTwo) {};
+a = b = c = someVeryLongFunctionName(argumentNumberOne, argumentNumberTwo, argumentThree);
+window.FooClient.setVars({ locale: getFooLocale({ page }), authorizationToken: data.token });
+class A { private readonly someVeryLongPropertyName: Map<string, number> = new Map<string, number>(); }
+type SomeVeryLongTypeAliasName<T> = SomeOtherGenericTypeWithLongName<T, AnotherTypeArgument, Third>;
+const { aaaaaaaaaaa, bbbbbbbbbbbbbbbbb, cccccccccccccccc } = someObjectWithLongName.property;
+this.someInstanceVariable.withAProperty = await someAsyncFunctionCall(withArgs, andMore1);
+const bifornCringerMoshedPerplexed = "bifornCringerMoshedPerplexedbifornCringerMoshedPerplexed";
+const isPartOfPackageJSON = dependenciesArray.indexOf(packageJsonDependencyName) !== -1;
+someReallyLongVariableName.someReallyLongPropertyName.another = require("some-module-name-here");
+loooooooooooooooooooooooooong1 = looooooooooooooooooooooooooooooooooooooooooooooooooooooooooong2 = 1;
@@ -0,0 +1,9 @@
+// The left side fits: break after the `=`
+const { aLongName, anotherLongName }: SomeVeryLongGenericType<Foo> = value;
+const { aLongName, anotherLongName }: SomeVeryLongGenericType<FooBarBaz> = value;
+const { aLongName, anotherLongName }: SomeVeryLongGenericType<FooBarBazQuxQuu> = someValue;
+const { aLongName, anotherLongName, yetAnotherLongName }: SomeType = someFunctionCall(arg);
+
+// The left side doesn't fit: break inside the pattern
+const { aLongName, anotherLongName }: SomeVeryLongGenericType<FooBarBazQuxQuux> = someValue;
+const { aLongName, anotherLongName, yetAnotherLongName, andOneMore, andAnotherOne }: T = value;
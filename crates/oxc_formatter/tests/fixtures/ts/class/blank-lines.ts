@@ -0,0 +1,29 @@
+class A {
+
+
+  a = 1;
+  b = 2;
+
+
+
+  get value() { return 1; }
+
+  set value(v) {}
+  overload(a: string): void;
+
+  overload(a: number): void;
+  overload(a: any) {}
+
+  // comment in the gap
+
+  @dec
+  decorated() {}
+  /** doc */
+
+  afterDoc() {}
+
+
+}
+function f(a: string): void;
+
+function f(a: any) {}
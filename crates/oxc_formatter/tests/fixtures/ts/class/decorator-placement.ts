@@ -0,0 +1,20 @@
+@Component({ selector: "app-root", templateUrl: "./app.component.html", styleUrls: ["./app.component.css"] })
+export class AppComponent {
+  @Input() name: string;
+  @Input()
+  value: string;
+  @Input() @Required() both: string;
+  @HostListener("window:resize", ["$event"]) onResize(event) {}
+  @Output()
+  @Other()
+  change = new EventEmitter();
+  constructor(@Inject(TOKEN) private readonly token: string, @Optional()
+  other: Service) {}
+}
+export @decorator class Foo {}
+@decorator export class Bar {}
+@dec class Baz {}
+const e = @dec class {};
+class Long {
+  @Column({ type: "varchar", length: 255, nullable: true, default: null, comment: "x" }) description: string;
+}
@@ -0,0 +1,12 @@
+class VeryLongClassNameForTesting extends SomeBaseClass<WithGenerics> implements A, B, C {
+  method() {}
+}
+class VeryLongClassNameForTesting2 extends SomeBase implements InterfaceNumberOne, InterfaceNumberTwo, InterfaceNumberThree, Four {}
+@Component({ selector: "app" })
+export class MyVeryLongComponentName extends BaseComponentWithLongName implements OnInit, OnDestroy {
+  x = 1;
+}
+class A // comment after name
+  extends B {}
+class LoooooooooooooooooooooooooongClassName extends Baaaaaaaaaaaaaaaaaaaaaaaaaaaaaase {}
+class Short extends Base<VeryLongGenericTypeArgumentNumberOne, VeryLongGenericTypeArgumentTwo> {}
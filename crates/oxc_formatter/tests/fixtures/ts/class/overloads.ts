@@ -0,0 +1,26 @@
+class Overloads {
+  constructor(a: string);
+
+  constructor(a: number);
+
+  constructor(a: any) {}
+
+  static create(a: string): Overloads;
+
+  static create(a: any): Overloads {
+    return new Overloads(a);
+  }
+
+  method(a: string): void;
+
+  // The implementation
+  method(a: any) {}
+
+  other(): void;
+
+  method2(): void {}
+
+  abstractLike(): void;
+
+  static abstractLike(): void {}
+}
@@ -0,0 +1,31 @@
+const o = {
+  a: 1,
+  // trailing note
+};
+const o2 = {
+  a: 1
+  // no trailing comma
+};
+const arr = [
+  1,
+  // trailing note
+];
+const { a,
+  // pattern note
+} = o;
+class A {
+  a = 1;
+  // trailing note
+}
+enum E {
+  A,
+  // trailing note
+}
+interface I {
+  a: string;
+  // trailing note
+}
+call(
+  a,
+  // trailing arg note
+);
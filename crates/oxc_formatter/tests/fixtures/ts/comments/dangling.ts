@@ -0,0 +1,28 @@
+function f(/* no args */) {}
+function g(
+  // no args
+) {}
+foo(/* TODO */);
+foo(
+  // TODO
+);
+{ /* empty */ }
+{
+  // empty
+}
+class A { /* nothing */ }
+class B {
+  // nothing
+}
+const o = { /* empty */ };
+const p = {
+  // empty
+};
+const [] = [/* empty */];
+const {} = { /* x */ };
+function h() { /* body */ }
+const arrow = (/* none */) => {};
+new Foo(/* nothing */);
+type T = { /* empty */ };
+interface I { /* empty */ }
+enum E { /* empty */ }
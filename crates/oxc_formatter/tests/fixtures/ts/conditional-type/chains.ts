@@ -0,0 +1,14 @@
+// Chains in the false branch
+type A<T> = T extends string ? "string" : T extends number ? "number" : T extends boolean ? "boolean" : "object";
+type B<T> = T extends (infer U)[] ? U : T extends Promise<infer V> ? V : T extends Set<infer W> ? W : never;
+type C<T> = T extends 0 ? "0" : T extends 1 ? "1" : T extends 2 ? "2" : T extends 3 ? "3" : T extends 4 ? "4" : T extends 5 ? "5" : T extends 6 ? "6" : T extends 7 ? "7" : T extends 8 ? "8" : T extends 9 ? "9" : never;
+
+// A conditional type in the true branch
+type D<T> = T extends string ? T extends "a" ? "aaaaaaaaaaaaaaa" : "bbbbbbbbbbbbbbbbbbbbbb" : "cccccccccccccccc";
+
+// Comments on branches
+type E<T> = T extends string
+  // is string
+  ? "string"
+  // not string
+  : T extends number ? "number" : never;
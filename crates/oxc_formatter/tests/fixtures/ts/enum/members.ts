@@ -0,0 +1,29 @@
+enum A { X, Y }
+const enum B { X = 1, Y = 2 }
+declare enum C {}
+enum D {
+  A = 'a',
+  B = "b",
+  'C' = 'c',
+  ['D'] = `d`,
+
+
+  E = 1 << 2,
+}
+enum E {
+  VeryLongMemberName = someVeryLongFunctionName(argumentOne) + anotherVeryLongFunctionName(argumentTwo),
+  Other = "some very long string value that goes beyond the print width of eighty chars",
+}
+enum F {
+  // comment
+}
+enum G { /* block */ }
+enum H {
+  /* a */
+  // b
+}
+enum I {
+  A, // trailing
+  // own line
+  B,
+}
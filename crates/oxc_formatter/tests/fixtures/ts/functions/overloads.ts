@@ -0,0 +1,15 @@
+export function parse(input: string): Result;
+// Parses a buffer
+export function parse(input: Buffer, options: ParseOptionsWithAVeryLongName, extra: boolean): Result;
+/** The implementation */
+export function parse(input: string | Buffer, options?: ParseOptionsWithAVeryLongName): Result {
+  return doParse(input, options);
+}
+declare function declared(a: string): void; // trailing
+declare function declared(a: number): void;
+class A {
+  method(a: string): void;
+  // comment before the second overload
+  method(a: number): void;
+  method(a: any) {}
+}
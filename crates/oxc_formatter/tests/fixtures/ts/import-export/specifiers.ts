@@ -0,0 +1,15 @@
+import { aaaaaaaaaaaa, bbbbbbbbbbbbbbb, ccccccccccccccc, ddddddddddddddd, eeeeeeeee } from "module";
+import { aVeryLongSpecifierNameThatCannotPossiblyFitOnOneLineWithTheSourceModule } from "some/long/module/path";
+import type { TypeNumberOne, TypeNumberTwo, TypeNumberThree, TypeNumberFour } from "types";
+import DefaultExport, { namedOne, namedTwo, namedThree, namedFour, namedFive } from "mixed";
+import "side-effect-import-with-a-very-long-module-specifier-that-goes-past-the-width.css";
+export { x as default };
+export * as ns from "m";
+export { aaaaaaaaaaaa, bbbbbbbbbbbbbbb, ccccccccccccccc, ddddddddddddddd, eeeeeeeee } from "m";
+import {
+  a, // comment on a
+  /* before b */ b,
+  c,
+} from "commented";
+import {} from "empty";
+import { type A, B } from "inline-type";
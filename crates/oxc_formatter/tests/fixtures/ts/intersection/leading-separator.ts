@@ -0,0 +1,23 @@
+// Type alias
+type A = Aaaaaaaaaaaaaaaaaa & Bbbbbbbbbbbbbbbbbbbbbb & Cccccccccccccccccccccc & Dddddddddddddddddd;
+
+// Parameter annotation
+function f(x: Aaaaaaaaaaaaaaaaaa & Bbbbbbbbbbbbbbbbbbbbbb & Cccccccccccccccccccccc & Ddddddddddddddd) {}
+
+// Nested in generics and unions
+type B = Map<string, Aaaaaaaaaaaaaaaaaa & Bbbbbbbbbbbbbbbbbbbbbb & Cccccccccccccccccccccc & Dddddddd>;
+type C = Aaaaaaaaaaaaaaaaaaaaaaaaa | (Bbbbbbbbbbbbbbbbbbbbbbbbb & Cccccccccccccccccccccc & Dddddddddddddd);
+
+// Function types
+type D = ((a: string) => void) & ((b: number) => void) & ((c: boolean) => void) & Ddddddddddddd;
+
+// Object-like members keep hugging
+type E = Aaaaaaaaaaaaaaaaaa & { a: string; bbbbbbbbbbbbbbbbbb: number; cccccccccccccc: boolean } & D;
+
+// Comments on members
+type F =
+  // comment a
+  & Aaaaaaaaaaaaaaaaaa
+  // comment b
+  & Bbbbbbbbbbbbbbbbbbbbbb
+  & Cccccccccccccccccccccc; // comment c
@@ -0,0 +1,19 @@
+class A {
+        /**
+            * Method doc   
+         * @example
+         *   foo(  1 )
+              */
+  method() {}
+}
+      /**
+  not a gutter line
+   */
+function f() {}
+@Component({})
+export class Foo {
+        /**
+         * Decorated class member doc
+         */
+    @Input() value: string;
+}
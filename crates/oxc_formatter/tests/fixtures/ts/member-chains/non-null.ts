@@ -0,0 +1,5 @@
+const node = tree!.root!.children!.find((child) => child.key === key)!.value!.toString();
+
+const element = document.getElementById("app")!.querySelector<HTMLElement>(".item")!.closest("section")!.dataset;
+
+this.store!.getState().entities.filter((entity): entity is User => entity.type === "user").map((user) => user.id);
@@ -0,0 +1,4 @@
+x = [0XAB, 0B11, 0O17, .5, 1., 1E5, 1e+5, 1e02, 1_000_000, 0xab_cd, 1.50, 1.0, 0.0, 1.5e-05, 5.e10, 0.50e+010, 10n, 0XABn, 1_0.0_0];
+enum E { A = 0XAB, B = 1.50 }
+function f(a = 1E+02) {}
+const o = { 1.50: a, 0XA: b };
@@ -0,0 +1,7 @@
+useEffect(({ a, b, c }: Props) => { doSomething(a); doSomethingElse(b); });
+useEffect(function ({ a, b, c }: Props) { doSomething(a); doSomethingElse(b); });
+useEffect(({ a, b, c }: { a: string; b: number; c: boolean }) => { doSomething(a); });
+useEffect(function ({ a, b, c }: { a: string; b: number }): void { doSomething(a); });
+
+useEffect(({ aaaaaaaaaaaa, bbbbbbbbbbbbbbb, cccccccccccccccc, dddddddddddd }: SomeProps) => { doSomething(a); });
+useEffect(function ({ aaaaaaaaaaaa, bbbbbbbbbbbbbbb, cccccccccccccccc }: SomeProps) { doSomething(a); });
@@ -0,0 +1,6 @@
+const a = (value as string).length;
+const b = (value!) + (other as number);
+const c = (input satisfies Config);
+const d = <string>(value);
+type T = (string | number)[];
+const e = (someVeryLongFunctionName(argumentNumberOne) as SomeVeryLongTypeName<WithGeneric>) ?? fallbackValue;
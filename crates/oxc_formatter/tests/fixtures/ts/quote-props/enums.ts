@@ -0,0 +1,31 @@
+// Enum with no quotes needed
+enum A {
+  a = 1,
+  b = 2,
+}
+
+// Enum with quotes preserved
+enum B {
+  "b" = 1,
+  c,
+}
+
+// Enum with required quotes - consistent should quote all
+enum C {
+  "a-b" = 1,
+  c = 2,
+  d,
+}
+
+// Initializers are untouched
+const enum D {
+  "a b" = "a b",
+  c = 1 << 2,
+  d = c + 1,
+}
+
+// Computed members don't require quotes
+enum E {
+  [`a-b`] = 1,
+  c = 2,
+}
@@ -26,3 +26,20 @@ interface E extends D {
   e1: string;
   'e-2': boolean;
 }
+
+// Index, call, and construct signatures don't count and are never quoted
+interface F {
+  [key: string]: unknown;
+  (): void;
+  new (): F;
+  f1: string;
+}
+
+// Signatures alongside a key that requires quotes
+interface G {
+  [key: string]: unknown;
+  <T>(arg: T): T;
+  new (): G;
+  g1: string;
+  'g-2'(): void;
+}
@@ -0,0 +1,8 @@
+// No special case for type literals and interfaces
+type A = {
+  "__proto__": string;
+};
+
+interface B {
+  "__proto__": string;
+}
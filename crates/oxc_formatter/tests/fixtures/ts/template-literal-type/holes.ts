@@ -0,0 +1,9 @@
+// Holes are formatted like other types
+type A<T extends string> = `prefix-${   Uppercase< T >  }-suffix`;
+type B<T> = `${T|"a"|"b"}-${keyof   T & string}`;
+
+// Long holes break the surrounding construct, never the literal
+type C = SomeVeryLongGenericTypeName<`prefix-${Uppercase<SomeVeryLongTypeParameterName>}-suffix`, Other>;
+function f(x: `prefix-${Uppercase<SomeVeryLongTypeParameterName>}-${Lowercase<AnotherLongName>}`) {}
+type D = { key: `prefix-${Uppercase<SomeVeryLongTypeParameterName>}-${Lowercase<AnotherLongName>}` };
+type E = `prefix-${"aaaaaaaaaaaaaaa" | "bbbbbbbbbbbbbbbbbbbb" | "ccccccccccccccccccccc" | "dddddddddd"}`;
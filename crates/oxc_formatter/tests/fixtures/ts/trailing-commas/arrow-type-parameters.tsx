@@ -0,0 +1,3 @@
+const single = <T,>(value: T) => value;
+const constrained = <T extends object>(value: T) => value;
+const multiple = <FirstTypeParameter, SecondTypeParameter, ThirdTypeParameterName>() => {};
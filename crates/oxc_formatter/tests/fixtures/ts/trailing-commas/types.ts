@@ -0,0 +1,13 @@
+function generic<FirstTypeParameter, SecondTypeParameter, ThirdTypeParameter>() {}
+const arrow = <FirstTypeParameter, SecondTypeParameter, ThirdTypeParameterName>() => {};
+const single = <T,>(value: T) => value;
+
+type Tuple = [firstElementOfTheTuple: string, secondElementOfTheTuple: number, third: boolean];
+type RestTuple = [firstElementOfTheTuple: string, secondElementOfTheTuple: number, ...rest: boolean[]];
+
+interface Shape { firstMember: string; secondMember: number }
+type Literal = { firstMember: string, secondMember: number, thirdMember: boolean, fourth: null };
+
+enum Direction { UpDirection = "UP", DownDirection = "DOWN", LeftDirection = "LEFT", Right = "R" }
+
+function typed(firstParameter: FirstType, secondParameter: SecondType, third: ThirdType) {}
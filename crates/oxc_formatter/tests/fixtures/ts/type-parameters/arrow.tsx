@@ -0,0 +1,3 @@
+const single = <T,>(value: T) => value;
+const constrained = <T extends unknown>(value: T) => value;
+const long = <TFirst extends SomethingLong = DefaultValue, TSecond extends Other = Another>(a: TFirst) => <div />;
@@ -0,0 +1,6 @@
+function f<TFirst extends SomethingLong = DefaultValue, TSecond extends Other = Another>(a: TFirst, b: TSecond) {}
+function g<T>(parameterNumberOne: string, parameterNumberTwo: number, parameterNumberThree: boolean) {}
+class Container<TValue extends Record<string, unknown> = {}, TKey extends keyof TValue = keyof TValue> {}
+const arrow = <TFirst extends SomethingLong = DefaultValue, TSecond extends Other = Another>(a: TFirst) => a;
+type Mapper<TInput extends Record<string, unknown>, TOutput extends Record<string, unknown> = TInput> = (input: TInput) => TOutput;
+const result = useQuery<SomeVeryLongResponseType, SomeVeryLongErrorType, SomeVeryLongSelectedType>(key);
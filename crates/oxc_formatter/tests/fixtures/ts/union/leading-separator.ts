@@ -0,0 +1,21 @@
+// Type alias
+type A = "aaaaaaaaaaaaaa" | "bbbbbbbbbbbbbbbbbb" | "cccccccccccccccccc" | "dddddddddddddddd";
+
+// Parameter annotation
+function f(x: "aaaaaaaaaaaaaa" | "bbbbbbbbbbbbbbbbbb" | "cccccccccccccccccc" | "ddddddddddd") {}
+
+// Nested in generics
+type B = Map<string, "aaaaaaaaaaaaaa" | "bbbbbbbbbbbbbbbbbb" | "cccccccccccccccccc" | "ddddddddddddd">;
+type C = Promise<Array<"aaaaaaaaaaaaaa" | "bbbbbbbbbbbbbbbbbb" | "cccccccccccccccccc" | "dddddddddd">>;
+
+// Function types and conditional types
+type D = ((a: string) => void) | ((b: number) => void) | ((c: boolean) => void) | null;
+type E = (T extends string ? "aaaaaaaaaaaaaaaa" : "bbbbbbbbbbbbbbbbb") | "cccccccccccc" | "dd";
+
+// Comments on members
+type F =
+  // comment a
+  | "aaaaaaaaaaaaaa"
+  // comment b
+  | "bbbbbbbbbbbbbbbbbb"
+  | "cccccccccccccccccc"; // comment c
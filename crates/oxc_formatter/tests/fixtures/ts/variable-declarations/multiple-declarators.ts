@@ -0,0 +1,20 @@
+const aaaaaaaaaaaa = 1, bbbbbbbbbbbbbbbb = 2, cccccccccccccccccc = 3, dddddddddddddddddd = 4;
+const a = 1, b = 2;
+let x = { a: 1, b: 2 }, y = 2;
+let p = {
+  a: 1,
+}, q = 2;
+var r = [
+  1, 2,
+], s;
+let t, u, v;
+for (let i = 0, j = someVeryLongArrayName.length - 1, k = anotherVeryLongName.length; i < j; i++) {}
+for (let i = 0, j = n; i < j; i++) {}
+const first: SomeLongTypeName<WithGeneric> = createFirst(), second: AnotherType = createSecond(), third: number = 3;
+let m: string, n: number;
+export const exportedFirst = createFirst(), exportedSecond = createSecond();
+declare const declaredFirst: SomeLongTypeName, declaredSecond: AnotherLongTypeName<WithGeneric>;
+export declare let exportedDeclaredFirst: SomeLongTypeName, exportedDeclaredSecond: AnotherType;
+function scoped() {
+  const nestedFirst = createFirst(), nestedSecond = createSecond(), nestedThird = 3;
+}
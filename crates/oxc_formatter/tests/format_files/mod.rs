@@ -0,0 +1,38 @@
+use std::path::PathBuf;
+
+use oxc_formatter::{FileInput, FormatOptions, format_files};
+
+fn input(path: &str, source_text: &str) -> FileInput {
+    FileInput { path: PathBuf::from(path), source_text: source_text.to_string() }
+}
+
+#[test]
+fn results_keep_input_order() {
+    let inputs = (0..200)
+        .map(|i| input(&format!("file{i}.js"), &format!("const a{i} = {{b: {i}}}")))
+        .collect::<Vec<_>>();
+    let results = format_files(inputs, &FormatOptions::default());
+
+    assert_eq!(results.len(), 200);
+    for (i, result) in results.iter().enumerate() {
+        assert_eq!(result.path, PathBuf::from(format!("file{i}.js")));
+        let code = &result.result.as_ref().unwrap().code;
+        assert_eq!(code, &format!("const a{i} = {{ b: {i} }};\n"));
+    }
+}
+
+#[test]
+fn errors_do_not_abort_the_batch() {
+    let inputs = vec![
+        input("a.ts", "let a: number = 1"),
+        input("b.ts", "let = ;"),
+        input("c.css", "a {}"),
+        input("d.json", "{\"d\": [1,2]}"),
+    ];
+    let results = format_files(inputs, &FormatOptions::default());
+
+    assert_eq!(results[0].result.as_ref().unwrap().code, "let a: number = 1;\n");
+    assert!(results[1].result.is_err());
+    assert!(results[2].result.as_ref().unwrap_err().starts_with("Unsupported file extension"));
+    assert_eq!(results[3].result.as_ref().unwrap().code, "{ \"d\": [1, 2] }\n");
+}
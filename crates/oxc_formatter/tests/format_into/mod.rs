@@ -0,0 +1,115 @@
+use std::{
+    fmt::{self, Write},
+    hash::{DefaultHasher, Hasher},
+};
+
+use oxc_allocator::Allocator;
+use oxc_formatter::{FormatIntoResult, FormatOptions, Formatter, get_parse_options};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+/// Hashes the written code without keeping it, counting the writes and the largest chunk.
+#[derive(Default)]
+struct CountingWriter {
+    hasher: DefaultHasher,
+    bytes: usize,
+    writes: usize,
+    largest_write: usize,
+}
+
+impl fmt::Write for CountingWriter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.hasher.write(s.as_bytes());
+        self.bytes += s.len();
+        self.writes += 1;
+        self.largest_write = self.largest_write.max(s.len());
+        Ok(())
+    }
+}
+
+struct FailingWriter;
+
+impl fmt::Write for FailingWriter {
+    fn write_str(&mut self, _s: &str) -> fmt::Result {
+        Err(fmt::Error)
+    }
+}
+
+fn format_into(code: &str, writer: &mut impl fmt::Write) -> Result<FormatIntoResult, fmt::Error> {
+    let allocator = Allocator::new();
+    let source_type = SourceType::from_path("dummy.js").unwrap();
+    let ret = Parser::new(&allocator, code, source_type).with_options(get_parse_options()).parse();
+    if let Some(error) = ret.errors.first() {
+        panic!("💥 Parser error: {}", error.message);
+    }
+
+    Formatter::new(&allocator, FormatOptions::default()).format_into(&ret.program, writer)
+}
+
+fn build(code: &str) -> String {
+    let allocator = Allocator::new();
+    let source_type = SourceType::from_path("dummy.js").unwrap();
+    let ret = Parser::new(&allocator, code, source_type).with_options(get_parse_options()).parse();
+    Formatter::new(&allocator, FormatOptions::default()).build(&ret.program)
+}
+
+fn large_source() -> String {
+    let mut code = String::new();
+    for index in 0..1000 {
+        writeln!(
+            code,
+            "function handler{index} (event,options){{ const {{ value, other }} = event; if(value>{index}){{ return other.map(item=>item*2) }} return 'done' }}"
+        )
+        .unwrap();
+    }
+    code
+}
+
+#[test]
+fn streams_large_file_in_chunks() {
+    let code = large_source();
+    let formatted = build(&code);
+
+    let mut writer = CountingWriter::default();
+    let result = format_into(&code, &mut writer).unwrap();
+
+    assert_eq!(result, FormatIntoResult { written: formatted.len(), changed: true });
+    assert_eq!(writer.bytes, formatted.len());
+    assert!(writer.writes > 1);
+    assert!(writer.largest_write < formatted.len() / 2);
+
+    let mut hasher = DefaultHasher::new();
+    hasher.write(formatted.as_bytes());
+    assert_eq!(writer.hasher.finish(), hasher.finish());
+}
+
+#[test]
+fn detects_unchanged_code() {
+    let code = build(&large_source());
+
+    let mut writer = String::new();
+    let result = format_into(&code, &mut writer).unwrap();
+
+    assert_eq!(result, FormatIntoResult { written: code.len(), changed: false });
+    assert_eq!(writer, code);
+}
+
+#[test]
+fn detects_changed_code() {
+    for (code, expected) in [
+        ("const a = 'b';\n", "const a = \"b\";\n"),
+        ("const a = 1;", "const a = 1;\n"),
+        ("const a = 1;\n\n", "const a = 1;\n"),
+    ] {
+        let mut writer = String::new();
+        let result = format_into(code, &mut writer).unwrap();
+        assert_eq!(result, FormatIntoResult { written: expected.len(), changed: true });
+        assert_eq!(writer, expected);
+    }
+}
+
+#[test]
+fn returns_writer_errors() {
+    assert_eq!(format_into(&large_source(), &mut FailingWriter), Err(fmt::Error));
+    assert_eq!(format_into("const a = 1;\n", &mut FailingWriter), Err(fmt::Error));
+}
@@ -0,0 +1,117 @@
+use oxc_allocator::Allocator;
+use oxc_formatter::{FormatOptions, Formatter, get_parse_options};
+use oxc_parser::Parser;
+use oxc_span::{SourceType, Span};
+
+/// Formats the range marked with `<<` and `>>` in `code`,
+/// and compares the code with the formatted range applied against `expected`.
+/// `expected` is `None` when the range must not be formatted.
+fn assert_format_range(code: &str, expected: Option<&str>) {
+    // NOTE: Strip leading single `\n` for better test case readability.
+    let code = code.strip_prefix('\n').expect("Test code should start with a newline");
+    let mut code = code.to_string();
+    let start = code.find("<<").expect("Test code should mark the range start with `<<`");
+    code.replace_range(start..start + 2, "");
+    let end = code.find(">>").expect("Test code should mark the range end with `>>`");
+    code.replace_range(end..end + 2, "");
+
+    let allocator = Allocator::new();
+    let source_type = SourceType::from_path("dummy.tsx").unwrap();
+    let ret = Parser::new(&allocator, &code, source_type).with_options(get_parse_options()).parse();
+    if let Some(error) = ret.errors.first() {
+        panic!("💥 Parser error: {}", error.message);
+    }
+
+    let range = Span::new(u32::try_from(start).unwrap(), u32::try_from(end).unwrap());
+    let printed =
+        Formatter::new(&allocator, FormatOptions::default()).format_range(&ret.program, range);
+
+    let actual = printed.map(|printed| {
+        let replaced = printed.range().expect("Formatted range should have a source range");
+        let mut actual = code.clone();
+        actual.replace_range(replaced.start as usize..replaced.end as usize, printed.as_code());
+        actual
+    });
+    let expected = expected.map(|expected| {
+        expected.strip_prefix('\n').expect("Expected code should start with a newline")
+    });
+
+    assert_eq!(actual.as_deref(), expected, "\n============== input ==============\n{code}");
+}
+
+#[test]
+fn formats_class_members_in_range() {
+    assert_format_range(
+        r"
+class A {
+  a  =  1;
+  <<b( )  {   return this.a }>>
+  c  =  2;
+}
+",
+        Some(
+            r"
+class A {
+  a  =  1;
+  b() {
+    return this.a;
+  }
+  c  =  2;
+}
+",
+        ),
+    );
+}
+
+#[test]
+fn expands_range_to_whole_statements() {
+    assert_format_range(
+        r"
+function f( ) {
+  const x = 1 +
+    <<2;
+  foo(  a,b )>>;   // trailing
+  bar( )
+}
+",
+        Some(
+            r"
+function f( ) {
+  const x = 1 + 2;
+  foo(a, b); // trailing
+  bar( )
+}
+",
+        ),
+    );
+}
+
+#[test]
+fn keeps_leading_comments_and_indentation() {
+    assert_format_range(
+        r"
+if (a) {
+    // comment
+    <<b( )>>
+}
+",
+        Some(
+            r"
+if (a) {
+    // comment
+    b();
+}
+",
+        ),
+    );
+}
+
+#[test]
+fn ignores_range_inside_template_literal() {
+    assert_format_range(
+        r"
+const s = `hello <<world>> ${ a+b }`;
+",
+        None,
+    );
+}
@@ -0,0 +1,92 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_formatter::{
+    FormatOptions, FormatSourceResult, format_source, format_source_with_allocator,
+};
+
+fn format(path: &str, source_text: &str) -> FormatSourceResult {
+    format_source(Path::new(path), source_text, FormatOptions::default()).unwrap()
+}
+
+#[test]
+fn bom() {
+    let result = format("a.js", "\u{feff}foo( )");
+    assert_eq!(result.code, "\u{feff}foo();\n");
+    assert!(result.changed);
+
+    let result = format("a.js", "\u{feff}foo();\n");
+    assert_eq!(result.code, "\u{feff}foo();\n");
+    assert!(!result.changed);
+}
+
+#[test]
+fn bom_before_hashbang() {
+    let result = format("cli.mjs", "\u{feff}#!/usr/bin/env node\nmain( )\n");
+    assert_eq!(result.code, "\u{feff}#!/usr/bin/env node\nmain();\n");
+}
+
+#[test]
+fn final_newline() {
+    assert_eq!(format("a.ts", "let a: number = 1").code, "let a: number = 1;\n");
+    assert_eq!(format("a.ts", "let a: number = 1;\n\n\n").code, "let a: number = 1;\n");
+
+    let options = FormatOptions { insert_final_newline: false, ..FormatOptions::default() };
+    let result = format_source(Path::new("a.ts"), "let a = 1;\n\n", options.clone()).unwrap();
+    assert_eq!(result.code, "let a = 1;");
+    let result = format_source(Path::new("a.ts"), "let a = 1; // comment\n", options).unwrap();
+    assert_eq!(result.code, "let a = 1; // comment");
+}
+
+#[test]
+fn unchanged() {
+    let source_text = "export const a = <div />;\n";
+    let result = format("App.tsx", source_text);
+    assert_eq!(result.code, source_text);
+    assert!(!result.changed);
+}
+
+#[test]
+fn json() {
+    let result = format("tsconfig.jsonc", "\u{feff}{\"a\": 1, // comment\n}");
+    assert_eq!(result.code, "\u{feff}{\n  \"a\": 1, // comment\n}\n");
+}
+
+#[test]
+fn errors() {
+    let options = FormatOptions::default();
+    let error = format_source(Path::new("style.css"), "a {}", options.clone()).unwrap_err();
+    assert!(error.starts_with("Unsupported file extension for style.css"));
+    assert!(format_source(Path::new("a.js"), "let = ;", options).is_err());
+}
+
+#[test]
+fn reused_allocator() {
+    let mut allocator = Allocator::default();
+    let path = Path::new("a.tsx");
+    let source_text = "export const App = () => <div className='app'>{items.map(item => <Item key={item.id} {...item} />)}</div>";
+    let expected = format_source(path, source_text, FormatOptions::default()).unwrap();
+
+    // The allocator is reset, but keeps its largest chunk of memory for the next calls,
+    // so it stops growing once the chunk fits the whole file
+    let mut capacities = vec![];
+    for _ in 0..10 {
+        let result = format_source_with_allocator(
+            &mut allocator,
+            path,
+            source_text,
+            FormatOptions::default(),
+        );
+        assert_eq!(result.unwrap(), expected);
+        assert_eq!(allocator.used_bytes(), 0);
+        capacities.push(allocator.capacity());
+    }
+    assert!(capacities[0] > 0);
+    assert!(capacities[2..].iter().all(|capacity| *capacity == capacities[2]));
+
+    // Also reset if formatting fails
+    let result =
+        format_source_with_allocator(&mut allocator, path, "let = ;", FormatOptions::default());
+    assert!(result.is_err());
+    assert_eq!(allocator.used_bytes(), 0);
+}
@@ -0,0 +1,70 @@
+use oxc_allocator::Allocator;
+use oxc_formatter::{FormatOptions, Formatter, IndentStyle, IndentWidth, get_parse_options};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+const CODE: &str = r#"
+class Service extends Base {
+  async fetchEverything(firstArgument, secondArgument) {
+    if (firstArgument) {
+      for (const item of someVeryLongCollectionName.filter((element) => element.isActive)) {
+        await this.client.request(item).then((response) => response.json()).catch(handleTheError);
+      }
+    }
+    return condition ? someValueThatIsLongEnoughToBreak : anotherValueThatIsLongEnoughToBreak;
+  }
+}
+
+const nested = {
+  first: { second: { third: [firstElementOfTheArray, secondElementOfTheArray, thirdElement] } },
+};
+
+type Union = { kind: "first"; value: FirstValueType } | { kind: "second"; value: SecondType };
+
+const message = `first line
+  ${someCondition ? someValueThatIsLongEnoughToBreak : anotherValueThatIsLongEnoughToBreak}
+`;
+
+const chain = longConditionNumberOne ? longResultNumberOne : longConditionNumberTwo ? longResultNumberTwo : fallback;
+"#;
+
+fn format(indent_style: IndentStyle, indent_width: u8) -> String {
+    let allocator = Allocator::new();
+    let source_type = SourceType::from_path("dummy.ts").unwrap();
+    let ret = Parser::new(&allocator, CODE, source_type).with_options(get_parse_options()).parse();
+    if let Some(error) = ret.errors.first() {
+        panic!("💥 Parser error: {}", error.message);
+    }
+
+    let options = FormatOptions {
+        indent_style,
+        indent_width: IndentWidth::try_from(indent_width).unwrap(),
+        ..FormatOptions::default()
+    };
+    Formatter::new(&allocator, options).build(&ret.program)
+}
+
+#[test]
+fn tabs_and_spaces_produce_the_same_lines() {
+    for indent_width in [2, 4, 8] {
+        let tabs = format(IndentStyle::Tab, indent_width);
+        let spaces = format(IndentStyle::Space, indent_width);
+
+        let tab_lines = tabs.lines().map(str::trim_start).collect::<Vec<_>>();
+        let space_lines = spaces.lines().map(str::trim_start).collect::<Vec<_>>();
+        assert_eq!(tab_lines, space_lines, "indent width {indent_width}:\n{tabs}\n{spaces}");
+    }
+}
+
+#[test]
+fn tabs_are_only_followed_by_alignment_spaces() {
+    let tabs = format(IndentStyle::Tab, 2);
+    for line in tabs.lines() {
+        let content = line.trim_start_matches('\t');
+        assert!(!content.trim_start_matches(' ').starts_with('\t'), "mixed indentation: {line:?}");
+    }
+    assert!(tabs.lines().any(|line| line.starts_with('\t')));
+
+    let spaces = format(IndentStyle::Space, 2);
+    assert!(!spaces.contains('\t'));
+}
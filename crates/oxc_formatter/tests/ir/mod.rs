@@ -0,0 +1,29 @@
+use oxc_allocator::Allocator;
+use oxc_formatter::{FormatOptions, Formatter, get_parse_options};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+fn format_ir(source_text: &str) -> String {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs())
+        .with_options(get_parse_options())
+        .parse();
+    assert!(ret.errors.is_empty());
+    Formatter::new(&allocator, FormatOptions::default()).format_ir(&ret.program)
+}
+
+#[test]
+fn statements() {
+    let ir = format_ir("let a = 1;\n\n// comment\nfoo(a, b);\n");
+    insta::with_settings!({ prepend_module_to_snapshot => false }, {
+        insta::assert_snapshot!("ir_statements", ir);
+    });
+}
+
+#[test]
+fn expanded_groups() {
+    let ir = format_ir("const value = condition ? {\n  key: 'value' } : [1, 2, 3];\n");
+    insta::with_settings!({ prepend_module_to_snapshot => false }, {
+        insta::assert_snapshot!("ir_expanded_groups", ir);
+    });
+}
@@ -0,0 +1,84 @@
+use oxc_allocator::Allocator;
+use oxc_formatter::{FormatOptions, Formatter, JsonKind, LineWidth, QuoteStyle};
+
+fn format_json(source_text: &str, kind: JsonKind) -> Result<String, String> {
+    let allocator = Allocator::default();
+    let options =
+        FormatOptions { line_width: LineWidth::try_from(80).unwrap(), ..FormatOptions::default() };
+    Formatter::new(&allocator, options).format_json(source_text, kind)
+}
+
+#[test]
+fn package_json() {
+    let source_text = r#"{
+  "name": "my-package", "version": "1.0.0",
+  "private": true,
+  "keywords": ["formatter", "javascript", "typescript"],
+  "scripts": {"build": "tsc -b", "test": "vitest run"},
+  "files": [ "dist", ],
+  "engines": { "node": ">=18" },
+  "numbers": [1.50, 1E5, -0.0],
+  "escaped": "a\/b \"quoted\"",
+  "empty": {}, "emptyArray": [],
+  "nothing": null
+}"#;
+    let formatted = format_json(source_text, JsonKind::Json).unwrap();
+    insta::with_settings!({ prepend_module_to_snapshot => false }, {
+        insta::assert_snapshot!("json_package_json", formatted);
+    });
+}
+
+#[test]
+fn tsconfig_with_comments() {
+    let source_text = r#"// Base config
+{
+  /* Visit https://aka.ms/tsconfig */
+  "compilerOptions": {
+    "target": "es2022", // modern
+    "strict": true,
+
+    "paths": { "@/*": ["./src/*"] },
+  },
+  "include": ["src"], // sources
+}
+"#;
+    let formatted = format_json(source_text, JsonKind::Jsonc).unwrap();
+    insta::with_settings!({ prepend_module_to_snapshot => false }, {
+        insta::assert_snapshot!("json_tsconfig_with_comments", formatted);
+    });
+}
+
+#[test]
+fn nested_arrays_expand() {
+    let source_text = r#"{"matrix": [[[1, 2], [3, 4]], [[5, 6], [7, 8]]], "deep": [[["aaaaaaaaaaaaaaaaaaaa", "bbbbbbbbbbbbbbbbbbbbbbbbb"], ["cccccccccccccccccccccccc", "dddddddddddddddddddddddddd"]]], "objects": [{"a": 1}, {"b": 2}]}"#;
+    let formatted = format_json(source_text, JsonKind::Json).unwrap();
+    insta::with_settings!({ prepend_module_to_snapshot => false }, {
+        insta::assert_snapshot!("json_nested_arrays_expand", formatted);
+    });
+}
+
+#[test]
+fn keys_stay_double_quoted() {
+    let allocator = Allocator::default();
+    let options = FormatOptions { quote_style: QuoteStyle::Single, ..FormatOptions::default() };
+    let formatted = Formatter::new(&allocator, options)
+        .format_json(r#"{"a": "b", "c-d": ["e"]}"#, JsonKind::Json)
+        .unwrap();
+    assert_eq!(formatted, "{ \"a\": \"b\", \"c-d\": [\"e\"] }\n");
+}
+
+#[test]
+fn trailing_commas() {
+    let source_text = "{\n  \"a\": [1, 2,],\n}";
+    assert_eq!(format_json(source_text, JsonKind::Json).unwrap(), "{\n  \"a\": [1, 2]\n}\n");
+    assert_eq!(format_json(source_text, JsonKind::Jsonc).unwrap(), "{\n  \"a\": [1, 2],\n}\n");
+}
+
+#[test]
+fn invalid_json() {
+    assert!(format_json("{", JsonKind::Json).is_err());
+    assert!(format_json("{ a: 1 }", JsonKind::Json).is_err());
+    assert!(format_json("'a'", JsonKind::Json).is_err());
+    assert!(format_json("[1, foo()]", JsonKind::Jsonc).is_err());
+    assert!(format_json("1; 2", JsonKind::Json).is_err());
+}
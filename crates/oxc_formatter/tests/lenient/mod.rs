@@ -0,0 +1,77 @@
+use oxc_allocator::Allocator;
+use oxc_formatter::{FormatOptions, Formatter, LenientResult};
+use oxc_span::{SourceType, Span};
+
+fn format_lenient(code: &str) -> LenientResult {
+    let allocator = Allocator::new();
+    let source_type = SourceType::from_path("dummy.js").unwrap();
+    Formatter::new(&allocator, FormatOptions::default()).format_lenient(code, source_type)
+}
+
+fn skipped_text<'a>(code: &'a str, result: &LenientResult) -> Vec<&'a str> {
+    result.skipped.iter().map(|span| &code[span.start as usize..span.end as usize]).collect()
+}
+
+#[test]
+fn formats_code_without_errors() {
+    let result = format_lenient("const  a=1\nfoo(  a )\n");
+    assert_eq!(
+        result,
+        LenientResult { code: "const a = 1;\nfoo(a);\n".to_string(), skipped: vec![] }
+    );
+}
+
+#[test]
+fn unterminated_string() {
+    let code = "const  a=1\nconst s = 'abc\nlet  b =  2\nfunction f( x ){ return x*2 }\n";
+    let result = format_lenient(code);
+
+    assert_eq!(skipped_text(code, &result), ["const s = 'abc"]);
+    assert_eq!(
+        result.code,
+        "const a = 1;\nconst s = 'abc\nlet b = 2;\nfunction f(x) {\n  return x * 2;\n}\n"
+    );
+}
+
+#[test]
+fn broken_class_member() {
+    let code = "import {b,a} from 'x'\nclass A {\n  foo( {\n  bar(){return 1}\n}\nfoo(  1 )\n";
+    let result = format_lenient(code);
+
+    assert_eq!(skipped_text(code, &result), ["  foo( {"]);
+    assert_eq!(
+        result.code,
+        "import { b, a } from \"x\";\nclass A {\n  foo( {\n  bar() {\n    return 1;\n  }\n}\nfoo(1);\n"
+    );
+}
+
+#[test]
+fn multiple_errors() {
+    let code = "let  a = 'x\nlet b = [1,2\n\nlet  c = 3\nlet d = )\n";
+    let result = format_lenient(code);
+
+    assert_eq!(skipped_text(code, &result), ["let  a = 'x", "let b = [1,2", "let d = )"]);
+    assert_eq!(result.code, "let  a = 'x\nlet b = [1,2\n\nlet c = 3;\nlet d = )\n");
+}
+
+#[test]
+fn unterminated_template() {
+    let code = "const  a=1\nconst t = `abc\nlet  b = 2\n";
+    let result = format_lenient(code);
+
+    assert_eq!(result.skipped, [Span::new(11, 25)]);
+    assert_eq!(result.code, "const a = 1;\nconst t = `abc\nlet b = 2;\n");
+}
+
+#[test]
+fn unlocalized_error_in_large_file() {
+    // The extra `)` closes the call opened on the first line, so only masking from the first
+    // line to the `)` would fix the error. The search gives up before growing that far.
+    let arguments = "a,\n".repeat(1000);
+    let code = format!("foo(\n{arguments})))\n{arguments}");
+    let result = format_lenient(&code);
+
+    #[expect(clippy::cast_possible_truncation)]
+    let whole_file = Span::new(0, code.len() as u32);
+    assert_eq!(result.skipped, [whole_file]);
+}
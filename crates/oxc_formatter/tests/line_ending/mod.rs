@@ -0,0 +1,84 @@
+use oxc_allocator::Allocator;
+use oxc_formatter::{FormatOptions, Formatter, LineEnding, get_parse_options};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+fn format(code: &str, line_ending: LineEnding) -> String {
+    format_with_options(code, FormatOptions { line_ending, ..FormatOptions::default() })
+}
+
+fn format_with_options(code: &str, options: FormatOptions) -> String {
+    let allocator = Allocator::new();
+    let source_type = SourceType::from_path("dummy.js").unwrap();
+    let ret = Parser::new(&allocator, code, source_type).with_options(get_parse_options()).parse();
+    if let Some(error) = ret.errors.first() {
+        panic!("💥 Parser error: {}", error.message);
+    }
+
+    Formatter::new(&allocator, options).build(&ret.program)
+}
+
+const FORMATTED_CRLF: &str = "/**\r\n * Documentation.\r\n */\r\nfunction foo() {\r\n  // comment\r\n  const template = `first line\r\n    second line`;\r\n  return template;\r\n}\r\n\r\nconst string =\r\n  \"a\\\r\nb\";\r\n";
+const FORMATTED_LF: &str = "/**\n * Documentation.\n */\nfunction foo() {\n  // comment\n  const template = `first line\n    second line`;\n  return template;\n}\n\nconst string =\n  \"a\\\nb\";\n";
+
+#[test]
+fn detect() {
+    assert_eq!(LineEnding::detect(""), LineEnding::Lf);
+    assert_eq!(LineEnding::detect("a\nb\n"), LineEnding::Lf);
+    assert_eq!(LineEnding::detect("a\r\nb\r\n"), LineEnding::Crlf);
+    assert_eq!(LineEnding::detect("a\rb\r"), LineEnding::Cr);
+    assert_eq!(LineEnding::detect("a\r\nb\r\nc\n"), LineEnding::Crlf);
+    assert_eq!(LineEnding::detect("a\r\nb\nc\n"), LineEnding::Lf);
+    assert_eq!(LineEnding::detect("a\r\nb\n"), LineEnding::Lf);
+}
+
+#[test]
+fn auto_keeps_crlf() {
+    assert_eq!(format(FORMATTED_CRLF, LineEnding::Auto), FORMATTED_CRLF);
+
+    let unformatted = "function foo ( ) {\r\n  return 1\r\n}\r\n";
+    assert_eq!(format(unformatted, LineEnding::Auto), "function foo() {\r\n  return 1;\r\n}\r\n");
+}
+
+#[test]
+fn auto_keeps_lf() {
+    assert_eq!(format(FORMATTED_LF, LineEnding::Auto), FORMATTED_LF);
+}
+
+#[test]
+fn lf_converts_crlf() {
+    // Line breaks inside the template literal and the string are kept as they are
+    assert_eq!(
+        format(FORMATTED_CRLF, LineEnding::Lf),
+        "/**\n * Documentation.\n */\nfunction foo() {\n  // comment\n  const template = `first line\r\n    second line`;\n  return template;\n}\n\nconst string =\n  \"a\\\r\nb\";\n"
+    );
+}
+
+#[test]
+fn crlf_converts_lf() {
+    // Line breaks inside the template literal and the string are kept as they are
+    assert_eq!(
+        format(FORMATTED_LF, LineEnding::Crlf),
+        "/**\r\n * Documentation.\r\n */\r\nfunction foo() {\r\n  // comment\r\n  const template = `first line\n    second line`;\r\n  return template;\r\n}\r\n\r\nconst string =\r\n  \"a\\\nb\";\r\n"
+    );
+}
+
+#[test]
+fn crlf_round_trip() {
+    assert_eq!(format(FORMATTED_CRLF, LineEnding::Crlf), FORMATTED_CRLF);
+}
+
+#[test]
+fn crlf_doc_comment() {
+    let code = "class A {\r\n      /**\r\n       * Doc  \r\n       */\r\n  method() {}\r\n}\r\n";
+    let options = FormatOptions { line_ending: LineEnding::Crlf, ..FormatOptions::default() };
+
+    assert_eq!(
+        format_with_options(code, options.clone()),
+        "class A {\r\n  /**\r\n       * Doc  \r\n       */\r\n  method() {}\r\n}\r\n"
+    );
+    assert_eq!(
+        format_with_options(code, FormatOptions { format_doc_comments: true, ..options }),
+        "class A {\r\n  /**\r\n   * Doc\r\n   */\r\n  method() {}\r\n}\r\n"
+    );
+}
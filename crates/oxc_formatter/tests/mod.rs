@@ -1,2 +1,22 @@
+mod builders;
+mod check;
+mod embedded;
 mod fixtures;
+#[cfg(feature = "rayon")]
+mod format_files;
+mod format_into;
+mod format_range;
+mod format_source;
+mod indent_style;
+mod ir;
 mod ir_transform;
+mod json;
+mod lenient;
+mod line_ending;
+mod options;
+mod pragma;
+mod source_map;
+mod stability;
+mod synthetic;
+mod text_edits;
+mod unsupported;
@@ -0,0 +1,79 @@
+use oxc_formatter::{
+    FormatOptions, IndentStyle, LineWidth, QuoteProperties, SortImportsOptions, SortOrder,
+};
+
+#[test]
+fn default_options_round_trip() {
+    let options = FormatOptions::default();
+    let json = serde_json::to_string(&options).unwrap();
+    assert_eq!(serde_json::from_str::<FormatOptions>(&json).unwrap(), options);
+}
+
+#[test]
+fn all_options_round_trip() {
+    let options = FormatOptions {
+        indent_style: IndentStyle::Tab,
+        line_width: LineWidth::try_from(80).unwrap(),
+        quote_properties: QuoteProperties::Consistent,
+        max_empty_lines: 2,
+        member_chain_min_calls: 2,
+        experimental_sort_imports: Some(SortImportsOptions {
+            order: SortOrder::Desc,
+            ..SortImportsOptions::default()
+        }),
+        ..FormatOptions::default()
+    };
+    let json = serde_json::to_value(&options).unwrap();
+    assert_eq!(json["indentStyle"], "tab");
+    assert_eq!(json["lineWidth"], 80);
+    assert_eq!(json["quoteProperties"], "consistent");
+    assert_eq!(json["memberChainMinCalls"], 2);
+    assert_eq!(json["experimentalSortImports"]["order"], "desc");
+    assert_eq!(serde_json::from_value::<FormatOptions>(json).unwrap(), options);
+}
+
+#[test]
+fn partial_options_use_defaults() {
+    let options: FormatOptions =
+        serde_json::from_str(r#"{ "quoteProperties": "as-needed", "lineWidth": 80 }"#).unwrap();
+    assert_eq!(
+        options,
+        FormatOptions {
+            quote_properties: QuoteProperties::AsNeeded,
+            line_width: LineWidth::try_from(80).unwrap(),
+            ..FormatOptions::default()
+        }
+    );
+
+    let options: FormatOptions =
+        serde_json::from_str(r#"{ "experimentalSortImports": { "ignoreCase": false } }"#).unwrap();
+    let sort_imports = options.experimental_sort_imports.unwrap();
+    assert!(!sort_imports.ignore_case);
+    assert!(sort_imports.newlines_between);
+}
+
+#[test]
+fn unknown_option_names_closest_key() {
+    let error = serde_json::from_str::<FormatOptions>(r#"{ "lineWdith": 80 }"#).unwrap_err();
+    assert_eq!(error.to_string(), "unknown option `lineWdith`, did you mean `lineWidth`?");
+
+    let error = serde_json::from_str::<FormatOptions>(r#"{ "semi": false }"#).unwrap_err();
+    assert_eq!(error.to_string(), "unknown option `semi`, did you mean `semicolons`?");
+}
+
+#[test]
+fn invalid_values_are_rejected() {
+    let error = serde_json::from_str::<FormatOptions>(r#"{ "lineWidth": 0 }"#).unwrap_err();
+    assert!(error.to_string().contains("The line width should be between 1 and 320"));
+
+    let error = serde_json::from_str::<FormatOptions>(r#"{ "semicolons": "never" }"#).unwrap_err();
+    assert!(error.to_string().contains("unknown variant `never`"));
+}
+
+#[cfg(feature = "json_schema")]
+#[test]
+fn json_schema() {
+    insta::with_settings!({ prepend_module_to_snapshot => false }, {
+        insta::assert_snapshot!("format_options_schema", FormatOptions::json_schema());
+    });
+}
@@ -0,0 +1,39 @@
+use oxc_allocator::Allocator;
+use oxc_formatter::{CheckResult, FormatOptions, Formatter, LineEnding, get_parse_options};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+fn options() -> FormatOptions {
+    FormatOptions {
+        require_pragma: true,
+        line_ending: LineEnding::Crlf,
+        ..FormatOptions::default()
+    }
+}
+
+#[test]
+fn unchanged_without_pragma() {
+    let code = "const  a = 1\r\nfoo( a )\n\n\tbar()";
+    let allocator = Allocator::new();
+    let source_type = SourceType::from_path("dummy.js").unwrap();
+    let ret = Parser::new(&allocator, code, source_type).with_options(get_parse_options()).parse();
+
+    assert_eq!(Formatter::new(&allocator, options()).build(&ret.program), code);
+    assert_eq!(
+        Formatter::new(&allocator, options()).check(&ret.program).unwrap(),
+        CheckResult::Formatted
+    );
+}
+
+#[test]
+fn formatted_with_pragma() {
+    let code = "/** @format */\r\nconst  a = 1\r\n";
+    let allocator = Allocator::new();
+    let source_type = SourceType::from_path("dummy.js").unwrap();
+    let ret = Parser::new(&allocator, code, source_type).with_options(get_parse_options()).parse();
+
+    assert_eq!(
+        Formatter::new(&allocator, options()).build(&ret.program),
+        "/** @format */\r\nconst a = 1;\r\n"
+    );
+}
@@ -0,0 +1,78 @@
+use oxc_allocator::Allocator;
+use oxc_formatter::{FormatOptions, Formatter, SourceMarker, get_parse_options};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+fn format_with_source_map(code: &str, source_map: bool) -> (String, Vec<SourceMarker>) {
+    let allocator = Allocator::new();
+    let source_type = SourceType::from_path("dummy.jsx").unwrap();
+    let ret = Parser::new(&allocator, code, source_type).with_options(get_parse_options()).parse();
+    if let Some(error) = ret.errors.first() {
+        panic!("💥 Parser error: {}", error.message);
+    }
+
+    let formatter = Formatter::new(&allocator, FormatOptions::default());
+    let formatter = if source_map { formatter.with_source_map() } else { formatter };
+    let printed = formatter.format(&ret.program).print().unwrap();
+    (printed.as_code().to_string(), printed.source_markers().to_vec())
+}
+
+/// Returns the printed offset that the first occurrence of `needle` in `code` maps to.
+fn dest_of(code: &str, markers: &[SourceMarker], needle: &str) -> usize {
+    let source = u32::try_from(code.find(needle).unwrap()).unwrap();
+    let marker = markers
+        .iter()
+        .find(|marker| marker.source == source)
+        .unwrap_or_else(|| panic!("no marker for {needle:?} at {source}"));
+    marker.dest as usize
+}
+
+#[test]
+fn identifiers_moved_to_another_line() {
+    let code = "const { first, second } = object; call(alphaArgument, betaArgument, gammaArgument, deltaArgument)\n";
+    let (formatted, markers) = format_with_source_map(code, true);
+    assert_eq!(
+        formatted,
+        "const { first, second } = object;\ncall(alphaArgument, betaArgument, gammaArgument, deltaArgument);\n"
+    );
+
+    for needle in ["first", "object", "call", "alphaArgument", "gammaArgument", "deltaArgument"] {
+        let dest = dest_of(code, &markers, needle);
+        assert_eq!(&formatted[dest..dest + needle.len()], needle);
+    }
+    assert_eq!(dest_of(code, &markers, "call"), 34);
+}
+
+#[test]
+fn rewritten_string_quotes() {
+    let code = "let a = 'single', b = 'it\"s';\n";
+    let (formatted, markers) = format_with_source_map(code, true);
+    assert_eq!(formatted, "let a = \"single\",\n    b = 'it\"s';\n");
+
+    let dest = dest_of(code, &markers, "'single'");
+    assert_eq!(&formatted[dest..], "\"single\",\n    b = 'it\"s';\n");
+    let dest = dest_of(code, &markers, "'it");
+    assert_eq!(&formatted[dest..], "'it\"s';\n");
+}
+
+#[test]
+fn markers_point_to_the_printed_tokens() {
+    let code = "class A extends B { #x = 1n; m() { return this.#x ?? super.m(/a/g, `t${null}`, true) } }\nconst el = <div id='a'>text</div>;\n";
+    let (formatted, markers) = format_with_source_map(code, true);
+
+    assert!(markers.windows(2).all(|pair| pair[0].dest < pair[1].dest));
+    for marker in &markers {
+        let source = &code[marker.source as usize..];
+        let printed = &formatted[marker.dest as usize..];
+        let token = source.split(|c: char| !c.is_alphanumeric() && c != '#').next().unwrap();
+        assert!(printed.starts_with(token), "{source:?} is printed at {printed:?}");
+    }
+}
+
+#[test]
+fn no_markers_without_source_map() {
+    let code = "const a = 'b';\n";
+    let (formatted, markers) = format_with_source_map(code, false);
+    assert_eq!(formatted, "const a = \"b\";\n");
+    assert!(markers.is_empty());
+}
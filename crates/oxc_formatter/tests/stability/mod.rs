@@ -0,0 +1,65 @@
+use std::{fs, path::Path};
+
+use oxc_formatter::{
+    FormatOptions,
+    testing::{assert_semantics_preserved, assert_stable, check_semantics_preserved, check_stable},
+};
+use oxc_span::SourceType;
+
+use crate::fixtures::{format_options_display, parse_format_options, resolve_options};
+
+fn collect_fixtures(dir: &Path, fixtures: &mut Vec<std::path::PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_fixtures(&path, fixtures);
+        } else if SourceType::from_path(&path).is_ok() {
+            fixtures.push(path);
+        }
+    }
+}
+
+/// Formats every fixture with each of its option sets and checks that the result is stable
+/// and preserves the program. The snapshot lists the failures that predate this check.
+/// New fixtures must pass it rather than be added to that list.
+#[test]
+fn fixtures_are_stable() {
+    let mut fixtures = Vec::new();
+    collect_fixtures(Path::new("tests/fixtures"), &mut fixtures);
+    fixtures.sort();
+
+    let mut failures = Vec::new();
+    for path in fixtures {
+        let source_text = fs::read_to_string(&path).unwrap();
+        let source_type = SourceType::from_path(&path).unwrap();
+        for option_json in resolve_options(&path) {
+            let options = parse_format_options(&option_json);
+            for result in [
+                check_stable(&source_text, source_type, &options),
+                check_semantics_preserved(&source_text, source_type, &options),
+            ] {
+                if let Err(report) = result {
+                    let reason = report.lines().next().unwrap();
+                    let options = format_options_display(&option_json);
+                    failures.push(format!("{} {options}: {reason}", path.display()));
+                }
+            }
+        }
+    }
+
+    insta::with_settings!({ prepend_module_to_snapshot => false }, {
+        insta::assert_snapshot!("stability", failures.join("\n"));
+    });
+}
+
+#[test]
+fn helpers() {
+    let source_type = SourceType::mjs();
+    let options = FormatOptions::default();
+    let code = "const a = { 'b': 1, c };\n<div>\n  text   {' '}\n</div>\n";
+    assert_stable(code, SourceType::jsx(), &options);
+    assert_semantics_preserved(code, SourceType::jsx(), &options);
+
+    let report = check_stable("const = 1", source_type, &options).unwrap_err();
+    assert!(report.starts_with("Failed to parse the source text:"), "{report}");
+}
@@ -0,0 +1,87 @@
+//! Formats ASTs built with the [`AstBuilder`] instead of parsed source, which have neither source
+//! text nor parenthesized expressions, and all spans are empty.
+
+use oxc_allocator::Allocator;
+use oxc_ast::{
+    AstBuilder,
+    ast::{BinaryOperator, Expression, LogicalOperator, UnaryOperator},
+};
+use oxc_formatter::{FormatOptions, Formatter};
+use oxc_span::{SPAN, SourceType};
+
+fn format_expression<'a>(
+    allocator: &'a Allocator,
+    build: impl FnOnce(AstBuilder<'a>) -> Expression<'a>,
+) -> String {
+    let ast = AstBuilder::new(allocator);
+    let statement = ast.statement_expression(SPAN, build(ast));
+    let program =
+        ast.program(SPAN, SourceType::mjs(), "", ast.vec(), None, ast.vec(), ast.vec1(statement));
+    let program = allocator.alloc(program);
+    Formatter::new(allocator, FormatOptions::default()).build(program)
+}
+
+fn ident<'a>(ast: AstBuilder<'a>, name: &'static str) -> Expression<'a> {
+    ast.expression_identifier(SPAN, name)
+}
+
+#[test]
+fn mixed_nullish_and_logical() {
+    let allocator = Allocator::default();
+    let code = format_expression(&allocator, |ast| {
+        let coalesce = ast.expression_logical(
+            SPAN,
+            ident(ast, "a"),
+            LogicalOperator::Coalesce,
+            ident(ast, "b"),
+        );
+        ast.expression_logical(SPAN, coalesce, LogicalOperator::Or, ident(ast, "c"))
+    });
+    assert_eq!(code, "(a ?? b) || c;\n");
+
+    let code = format_expression(&allocator, |ast| {
+        let and =
+            ast.expression_logical(SPAN, ident(ast, "b"), LogicalOperator::And, ident(ast, "c"));
+        ast.expression_logical(SPAN, ident(ast, "a"), LogicalOperator::Coalesce, and)
+    });
+    assert_eq!(code, "a ?? (b && c);\n");
+}
+
+#[test]
+fn exponentiation() {
+    let allocator = Allocator::default();
+    let code = format_expression(&allocator, |ast| {
+        let negated = ast.expression_unary(SPAN, UnaryOperator::UnaryNegation, ident(ast, "a"));
+        ast.expression_binary(SPAN, negated, BinaryOperator::Exponential, ident(ast, "b"))
+    });
+    assert_eq!(code, "(-a) ** b;\n");
+
+    let code = format_expression(&allocator, |ast| {
+        let power = ast.expression_binary(
+            SPAN,
+            ident(ast, "a"),
+            BinaryOperator::Exponential,
+            ident(ast, "b"),
+        );
+        ast.expression_binary(SPAN, power, BinaryOperator::Exponential, ident(ast, "c"))
+    });
+    assert_eq!(code, "(a ** b) ** c;\n");
+}
+
+#[test]
+fn precedence() {
+    let allocator = Allocator::default();
+    let code = format_expression(&allocator, |ast| {
+        let sum =
+            ast.expression_binary(SPAN, ident(ast, "a"), BinaryOperator::Addition, ident(ast, "b"));
+        ast.expression_binary(SPAN, sum, BinaryOperator::Multiplication, ident(ast, "c"))
+    });
+    assert_eq!(code, "(a + b) * c;\n");
+
+    let code = format_expression(&allocator, |ast| {
+        let or =
+            ast.expression_logical(SPAN, ident(ast, "a"), LogicalOperator::Or, ident(ast, "b"));
+        ast.expression_conditional(SPAN, or, ident(ast, "c"), ident(ast, "d"))
+    });
+    assert_eq!(code, "a || b ? c : d;\n");
+}
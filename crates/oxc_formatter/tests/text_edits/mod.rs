@@ -0,0 +1,87 @@
+use oxc_allocator::Allocator;
+use oxc_formatter::{FormatOptions, Formatter, TextEdit, get_parse_options};
+use oxc_parser::Parser;
+use oxc_span::{SourceType, Span};
+
+const CORPUS: &[&str] = &[
+    "",
+    "const a = 1;\n",
+    "const a  = 1;\nconst b = 2;\nconst c = 'x';\n",
+    "function foo ( a,b ) {\n  return a+b\n}\n\n\n\nfoo(1,2)\n",
+    "import {b,a} from 'module'\nexport default class A extends B {\n  method(){ return this.value }\n}\n",
+    "const object = { firstProperty: firstValue, secondProperty: secondValue, thirdProperty: third };\n",
+    "if (a) b(); else { c() }\n// comment\nwhile(true){}\n",
+    "const emoji = '😀';   const other = \"é\";\nconst template = `a\n  ${ b }`;\n",
+    "const a = 1;",
+    "\n\n\nconst a = 1;\n\n\n",
+];
+
+fn format_to_edits(code: &str) -> (Vec<TextEdit>, String) {
+    let allocator = Allocator::new();
+    let source_type = SourceType::from_path("dummy.js").unwrap();
+    let ret = Parser::new(&allocator, code, source_type).with_options(get_parse_options()).parse();
+    if let Some(error) = ret.errors.first() {
+        panic!("💥 Parser error: {}", error.message);
+    }
+
+    let edits = Formatter::new(&allocator, FormatOptions::default()).format_to_edits(&ret.program);
+    let formatted = Formatter::new(&allocator, FormatOptions::default()).build(&ret.program);
+    (edits, formatted)
+}
+
+fn apply(code: &str, edits: &[TextEdit]) -> String {
+    let mut result = code.to_string();
+    for edit in edits.iter().rev() {
+        result.replace_range(edit.span.start as usize..edit.span.end as usize, &edit.text);
+    }
+    result
+}
+
+#[test]
+fn edits_reproduce_formatted_code() {
+    for code in CORPUS {
+        let (edits, formatted) = format_to_edits(code);
+        assert_eq!(apply(code, &edits), formatted, "{code:?}");
+
+        for pair in edits.windows(2) {
+            assert!(pair[0].span.end < pair[1].span.start, "{code:?}: {pair:?}");
+        }
+    }
+}
+
+#[test]
+fn formatted_code_has_no_edits() {
+    let (edits, _) = format_to_edits("const a = 1;\n\nfoo(a, \"b\");\n");
+    assert!(edits.is_empty());
+}
+
+#[test]
+fn edits_are_minimal() {
+    let (edits, _) = format_to_edits("const a  = 1;\nconst b = 2;\nconst c = 'x';\n");
+    assert_eq!(
+        edits,
+        [
+            TextEdit { span: Span::new(8, 9), text: String::new() },
+            TextEdit { span: Span::new(37, 38), text: "\"".to_string() },
+            TextEdit { span: Span::new(39, 40), text: "\"".to_string() },
+        ]
+    );
+
+    let (edits, _) = format_to_edits("foo(1,2)\n");
+    assert_eq!(
+        edits,
+        [
+            TextEdit { span: Span::new(6, 6), text: " ".to_string() },
+            TextEdit { span: Span::new(8, 8), text: ";".to_string() },
+        ]
+    );
+}
+
+#[test]
+fn large_minified_code() {
+    let code = "function f(a,b){if(a){return b+1}else{return[a,b,{c:a,d:b}]}}".repeat(5000);
+    let start = std::time::Instant::now();
+    let (edits, formatted) = format_to_edits(&code);
+    assert_eq!(apply(&code, &edits), formatted);
+    assert!(start.elapsed().as_secs() < 10, "took {:?}", start.elapsed());
+}
@@ -0,0 +1,111 @@
+use std::{
+    fs,
+    panic::{AssertUnwindSafe, catch_unwind},
+    path::Path,
+};
+
+use oxc_allocator::Allocator;
+use oxc_ast::{AstBuilder, ast::*};
+use oxc_formatter::{FormatError, FormatOptions, Formatter, get_parse_options};
+use oxc_parser::{ParseOptions, Parser};
+use oxc_span::{SPAN, SourceType, Span};
+
+fn preserve_parens() -> ParseOptions {
+    ParseOptions { preserve_parens: true, ..get_parse_options() }
+}
+
+fn try_build(code: &str, source_type: SourceType) -> (Result<String, FormatError>, String) {
+    let allocator = Allocator::new();
+    let ret = Parser::new(&allocator, code, source_type).with_options(preserve_parens()).parse();
+    if let Some(error) = ret.errors.first() {
+        panic!("💥 Parser error: {}", error.message);
+    }
+
+    let formatter = || Formatter::new(&allocator, FormatOptions::default());
+    (formatter().try_build(&ret.program), formatter().build(&ret.program))
+}
+
+#[test]
+fn parenthesized_expression() {
+    let code = "const  a = (b  +c) * d;\nfoo(  1 )\n";
+    let (result, code) = try_build(code, SourceType::mjs());
+
+    assert_eq!(
+        result,
+        Err(FormatError::UnsupportedSyntax {
+            span: Span::new(11, 18),
+            kind: "ParenthesizedExpression"
+        })
+    );
+    assert_eq!(code, "const a = (b  +c) * d;\nfoo(1);\n");
+}
+
+#[test]
+fn parenthesized_expression_with_preserve_parens_option() {
+    let allocator = Allocator::new();
+    let code = "const  a = (b  +c) * (d);\nfoo(  (1) )\n";
+    let ret =
+        Parser::new(&allocator, code, SourceType::mjs()).with_options(preserve_parens()).parse();
+    let options = FormatOptions { preserve_parens: true, ..FormatOptions::default() };
+    let result = Formatter::new(&allocator, options).try_build(&ret.program);
+    assert_eq!(result, Ok("const a = (b + c) * (d);\nfoo((1));\n".to_string()));
+}
+
+#[test]
+fn without_unsupported_syntax() {
+    let (result, code) = try_build("const  a = b;\n", SourceType::mjs());
+    assert_eq!(result, Ok(code));
+}
+
+#[test]
+fn literals_without_raw() {
+    let allocator = Allocator::new();
+    let ast = AstBuilder::new(&allocator);
+    let bigint = ast.expression_big_int_literal(SPAN, "12", None, BigintBase::Decimal);
+    let regex = RegExp {
+        pattern: RegExpPattern { text: ast.atom("a+"), pattern: None },
+        flags: RegExpFlags::G | RegExpFlags::I,
+    };
+    let regex = ast.expression_reg_exp_literal(SPAN, regex, None);
+    let elements = ast.vec_from_iter([bigint, regex].map(ArrayExpressionElement::from));
+    let statement = ast.statement_expression(SPAN, ast.expression_array(SPAN, elements));
+    let program =
+        ast.program(SPAN, SourceType::mjs(), "", ast.vec(), None, ast.vec(), ast.vec1(statement));
+
+    let code = Formatter::new(&allocator, FormatOptions::default()).try_build(&program);
+    assert_eq!(code, Ok("[12n, /a+/gi];\n".to_string()));
+}
+
+fn collect_fixtures(dir: &Path, fixtures: &mut Vec<std::path::PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_fixtures(&path, fixtures);
+        } else if SourceType::from_path(&path).is_ok() {
+            fixtures.push(path);
+        }
+    }
+}
+
+/// Formats every fixture parsed with `preserve_parens`, which must never panic.
+#[test]
+fn fixtures_with_preserved_parens() {
+    let mut fixtures = Vec::new();
+    collect_fixtures(Path::new("tests/fixtures"), &mut fixtures);
+
+    for path in fixtures {
+        let source_text = fs::read_to_string(&path).unwrap();
+        let source_type = SourceType::from_path(&path).unwrap();
+        let allocator = Allocator::new();
+        let ret = Parser::new(&allocator, &source_text, source_type)
+            .with_options(preserve_parens())
+            .parse();
+        if !ret.errors.is_empty() {
+            continue;
+        }
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            Formatter::new(&allocator, FormatOptions::default()).build(&ret.program)
+        }));
+        assert!(result.is_ok(), "Formatting {} panicked", path.display());
+    }
+}
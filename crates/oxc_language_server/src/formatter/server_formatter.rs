@@ -5,7 +5,7 @@ use log::{debug, warn};
 use oxc_allocator::Allocator;
 use oxc_data_structures::rope::{Rope, get_line_column};
 use oxc_formatter::{
-    FormatOptions, Formatter, OxfmtOptions, Oxfmtrc, enable_jsx_source_type, get_parse_options,
+    FormatOptions, Formatter, OxfmtOptions, Oxfmtrc, enable_jsx_source_type, get_parse_options_with,
     get_supported_source_type,
 };
 use oxc_parser::Parser;
@@ -262,7 +262,7 @@ impl Tool for ServerFormatter {
 
         let allocator = Allocator::new();
         let ret = Parser::new(&allocator, source_text, source_type)
-            .with_options(get_parse_options())
+            .with_options(get_parse_options_with(&self.options))
             .parse();
 
         if !ret.errors.is_empty() {
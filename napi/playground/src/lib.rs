@@ -32,7 +32,7 @@ use oxc_formatter::{
     ArrowParentheses, AttributePosition, BracketSameLine, BracketSpacing, Expand, FormatOptions,
     Formatter, IndentStyle, IndentWidth, LineEnding, LineWidth, QuoteProperties, QuoteStyle,
     Semicolons, SortImportsOptions, SortOrder, TrailingCommas, default_groups,
-    default_internal_patterns, get_parse_options,
+    default_internal_patterns, get_parse_options_with,
 };
 use oxc_linter::{
     ConfigStore, ConfigStoreBuilder, ContextSubHost, ExternalPluginStore, LintOptions, Linter,
@@ -531,11 +531,11 @@ impl Oxc {
     ) {
         let allocator = Allocator::default();
         if run_options.formatter {
+            let format_options = Self::convert_formatter_options(formatter_options);
             let ret = Parser::new(&allocator, source_text, source_type)
-                .with_options(get_parse_options())
+                .with_options(get_parse_options_with(&format_options))
                 .parse();
 
-            let format_options = Self::convert_formatter_options(formatter_options);
             let formatter = Formatter::new(&allocator, format_options);
             let formatted = formatter.format(&ret.program);
             if run_options.formatter {
@@ -1,8 +1,14 @@
+use std::{fmt::Write, fs, path::Path};
+
 use oxc_allocator::Allocator;
 use oxc_benchmark::{BenchmarkId, Criterion, criterion_group, criterion_main};
-use oxc_formatter::{FormatOptions, Formatter, SortImportsOptions, get_parse_options};
+use oxc_formatter::{
+    FileInput, FormatOptions, Formatter, QuoteProperties, SortImportsOptions, format_files,
+    format_source, format_source_with_allocator, get_parse_options,
+};
 use oxc_parser::Parser;
-use oxc_tasks_common::TestFiles;
+use oxc_span::SourceType;
+use oxc_tasks_common::{TestFiles, project_root};
 
 fn bench_formatter(criterion: &mut Criterion) {
     let mut group = criterion.benchmark_group("formatter");
@@ -33,5 +39,182 @@ fn bench_formatter(criterion: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(formatter, bench_formatter);
+/// Collects the JavaScript and TypeScript files of the formatter's fixtures.
+fn fixture_files(dir: &Path, files: &mut Vec<FileInput>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            fixture_files(&path, files);
+        } else if path
+            .extension()
+            .is_some_and(|extension| ["js", "jsx", "ts", "tsx"].iter().any(|e| extension == *e))
+        {
+            let source_text = fs::read_to_string(&path).unwrap();
+            files.push(FileInput { path, source_text });
+        }
+    }
+}
+
+fn bench_formatter_files(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("formatter_files");
+
+    let mut files = vec![];
+    fixture_files(&project_root().join("crates/oxc_formatter/tests/fixtures"), &mut files);
+    files.sort_unstable_by(|a, b| a.path.cmp(&b.path));
+    let options = FormatOptions::default();
+
+    group.bench_function("sequential", |b| {
+        b.iter(|| {
+            for file in &files {
+                let _ = format_source(&file.path, &file.source_text, options.clone());
+            }
+        });
+    });
+    group.bench_function("parallel", |b| {
+        b.iter_with_setup(|| files.clone(), |files| format_files(files, &options));
+    });
+
+    group.finish();
+}
+
+/// Formats the same small file 1,000 times, with a new allocator for every run or a reused one.
+fn bench_formatter_allocator(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("formatter_allocator");
+
+    let files = TestFiles::formatter();
+    let file = files.files().iter().min_by_key(|file| file.source_text.len()).unwrap();
+    let path = Path::new(&file.file_name);
+    let source_text = &file.source_text;
+    let options = FormatOptions::default();
+
+    group.bench_function("fresh", |b| {
+        b.iter(|| {
+            for _ in 0..1000 {
+                let _ = format_source(path, source_text, options.clone());
+            }
+        });
+    });
+    group.bench_function("reused", |b| {
+        let mut allocator = Allocator::default();
+        b.iter(|| {
+            for _ in 0..1000 {
+                let _ = format_source_with_allocator(
+                    &mut allocator,
+                    path,
+                    source_text,
+                    options.clone(),
+                );
+            }
+        });
+    });
+
+    group.finish();
+}
+
+/// Formats a generated file with 5,000 comments, like a license header per function.
+fn bench_formatter_comments(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("formatter_comments");
+
+    let mut source_text = String::new();
+    for i in 0..2500 {
+        writeln!(
+            source_text,
+            "/**\n * Copyright (c) Example, Inc.\n * Licensed under the MIT license.\n */\nexport function f{i}(a, b) {{\n  return a + b; // sum\n}}"
+        )
+        .unwrap();
+    }
+    let source_type = SourceType::mjs();
+    let mut allocator = Allocator::default();
+    group.bench_function("5k_comments", |b| {
+        b.iter_with_setup_wrapper(|runner| {
+            allocator.reset();
+            let program = Parser::new(&allocator, &source_text, source_type)
+                .with_options(get_parse_options())
+                .parse()
+                .program;
+            runner.run(|| {
+                Formatter::new(&allocator, FormatOptions::default()).build(&program);
+            });
+        });
+    });
+
+    group.finish();
+}
+
+/// Formats a generated file that is mostly string literals and quoted property keys.
+fn bench_formatter_strings(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("formatter_strings");
+
+    let mut source_text = String::from("export const messages = {\n");
+    for i in 0..5000 {
+        writeln!(
+            source_text,
+            "  \"key{i}\": [\"hello\", \"world {i}\", \"it's\", 'single', \"escaped \\n line\"],"
+        )
+        .unwrap();
+    }
+    source_text.push_str("};\n");
+    let source_type = SourceType::mjs();
+    let mut allocator = Allocator::default();
+    group.bench_function("string_literals", |b| {
+        b.iter_with_setup_wrapper(|runner| {
+            allocator.reset();
+            let program = Parser::new(&allocator, &source_text, source_type)
+                .with_options(get_parse_options())
+                .parse()
+                .program;
+            runner.run(|| {
+                Formatter::new(&allocator, FormatOptions::default()).build(&program);
+            });
+        });
+    });
+
+    group.finish();
+}
+
+/// Formats a generated file of objects whose identifier and numeric keys all get quoted because
+/// another key requires quotes, with `quoteProps: "consistent"`.
+fn bench_formatter_quote_props(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("formatter_quote_props");
+
+    let mut source_text = String::from("export const entries = [\n");
+    for i in 0..5000 {
+        writeln!(
+            source_text,
+            "  {{ id: {i}, name: \"entry {i}\", \"data-{i}\": true, 10: null }},"
+        )
+        .unwrap();
+    }
+    source_text.push_str("];\n");
+    let source_type = SourceType::mjs();
+    let mut allocator = Allocator::default();
+    group.bench_function("consistent", |b| {
+        b.iter_with_setup_wrapper(|runner| {
+            allocator.reset();
+            let program = Parser::new(&allocator, &source_text, source_type)
+                .with_options(get_parse_options())
+                .parse()
+                .program;
+            let format_options = FormatOptions {
+                quote_properties: QuoteProperties::Consistent,
+                ..Default::default()
+            };
+            runner.run(|| {
+                Formatter::new(&allocator, format_options).build(&program);
+            });
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    formatter,
+    bench_formatter,
+    bench_formatter_files,
+    bench_formatter_allocator,
+    bench_formatter_comments,
+    bench_formatter_strings,
+    bench_formatter_quote_props
+);
 criterion_main!(formatter);